@@ -3,13 +3,20 @@ use serde::Deserialize;
 #[cfg(feature = "mssql")]
 use async_std::net::TcpStream;
 #[cfg(feature = "mysql")]
-use mysql_async::Pool;
+use mysql_async::{Opts, Pool, Statement};
 #[cfg(feature = "mssql")]
 use tiberius::{AuthMethod, Config};
 #[cfg(feature = "postgres")]
 use tokio_postgres::{Client, NoTls};
 
 use crate::datasources::{Auth, DatasourceConfig};
+#[cfg(feature = "mysql")]
+use crate::statement_cache::PooledStatementCache;
+#[cfg(feature = "postgres")]
+use crate::statement_cache::StatementCache;
+use crate::statement_cache::StatementCacheStats;
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use crate::statement_cache::DEFAULT_STATEMENT_CACHE_SIZE;
 
 /// Represents the current supported databases by Canyon
 #[derive(Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
@@ -42,6 +49,7 @@ impl From<&Auth> for DatabaseType {
 #[cfg(feature = "postgres")]
 pub struct PostgreSqlConnection {
     pub client: Client,
+    pub statements: Box<StatementCache<tokio_postgres::Statement>>,
     // pub connection: Connection<Socket, NoTlsStream>, // TODO Hold it, or not to hold it... that's the question!
 }
 
@@ -55,6 +63,9 @@ pub struct SqlServerConnection {
 #[cfg(feature = "mysql")]
 pub struct MysqlConnection {
     pub client: Pool,
+    /// The statements are only valid for the physical connection of the
+    /// pool that prepared them
+    pub statements: Box<PooledStatementCache<Statement>>,
 }
 
 /// The Canyon database connection handler. When the client's program
@@ -110,12 +121,21 @@ impl DatabaseConnection {
 
                 tokio::spawn(async move {
                     if let Err(e) = new_connection.await {
-                        eprintln!("An error occurred while trying to connect to the PostgreSQL database: {e}");
+                        tracing::error!(
+                            error = %e,
+                            "An error occurred while trying to connect to the PostgreSQL database"
+                        );
                     }
                 });
 
                 Ok(DatabaseConnection::Postgres(PostgreSqlConnection {
                     client: new_client,
+                    statements: Box::new(StatementCache::new(
+                        datasource
+                            .properties
+                            .statement_cache_size
+                            .unwrap_or(DEFAULT_STATEMENT_CACHE_SIZE),
+                    )),
                     // connection: new_connection,
                 }))
             }
@@ -191,18 +211,32 @@ impl DatabaseConnection {
 
                 //TODO add options to optionals params in url
 
+                // `client_found_rows` makes MySQL report the rows matched by an
+                // UPDATE instead of only the ones whose values really changed,
+                // like the other supported databases does.
+                // The cache of statements of the driver is disabled, since Canyon
+                // caches the prepared statements of every connection by itself
                 let url = format!(
-                    "mysql://{}:{}@{}:{}/{}",
+                    "mysql://{}:{}@{}:{}/{}?client_found_rows=true&stmt_cache_size=0",
                     user,
                     password,
                     datasource.properties.host,
                     datasource.properties.port.unwrap_or_default(),
                     datasource.properties.db_name
                 );
-                let mysql_connection = Pool::from_url(url)?;
+                let opts = Opts::from_url(&url)?;
+                let max_connections = opts.pool_opts().constraints().max();
+                let mysql_connection = Pool::new(opts);
 
                 Ok(DatabaseConnection::MySQL(MysqlConnection {
                     client: { mysql_connection },
+                    statements: Box::new(PooledStatementCache::new(
+                        datasource
+                            .properties
+                            .statement_cache_size
+                            .unwrap_or(DEFAULT_STATEMENT_CACHE_SIZE),
+                        max_connections,
+                    )),
                 }))
             }
         }
@@ -234,6 +268,20 @@ impl DatabaseConnection {
             _ => panic!(),
         }
     }
+
+    /// The usage of the prepared statements cache of the connection, summing the ones
+    /// of every connection of the pool for `MySQL`. `SqlServer` connections doesn't
+    /// cache any statement, so they haven't stats.
+    pub fn statement_cache_stats(&self) -> Option<StatementCacheStats> {
+        match self {
+            #[cfg(feature = "postgres")]
+            DatabaseConnection::Postgres(conn) => Some(conn.statements.stats()),
+            #[cfg(feature = "mssql")]
+            DatabaseConnection::SqlServer(_) => None,
+            #[cfg(feature = "mysql")]
+            DatabaseConnection::MySQL(conn) => Some(conn.statements.stats()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -10,7 +10,7 @@ fn load_ds_config_from_array() {
         const CONFIG_FILE_MOCK_ALT_PG: &str = r#"
         [canyon_sql]
         datasources = [
-            {name = 'PostgresDS', auth = { postgresql = { basic = { username = "postgres", password = "postgres" } } }, properties.host = 'localhost', properties.db_name = 'triforce', properties.migrations='enabled' },
+            {name = 'PostgresDS', auth = { postgresql = { basic = { username = "postgres", password = "postgres" } } }, properties.host = 'localhost', properties.db_name = 'triforce', properties.migrations='enabled', properties.statement_cache_size = 128, properties.log_level = 'info', properties.log_params = 'redacted', properties.slow_query_ms = 250, properties.explain_slow_queries = true },
         ]
         "#;
         let config: CanyonSqlConfig = toml::from_str(CONFIG_FILE_MOCK_ALT_PG)
@@ -31,6 +31,11 @@ fn load_ds_config_from_array() {
         assert_eq!(ds_0.properties.port, None);
         assert_eq!(ds_0.properties.db_name, "triforce");
         assert_eq!(ds_0.properties.migrations, Some(Migrations::Enabled));
+        assert_eq!(ds_0.properties.statement_cache_size, Some(128));
+        assert_eq!(ds_0.properties.log_level, Some(LogLevel::Info));
+        assert_eq!(ds_0.properties.log_params, Some(ParamsLogging::Redacted));
+        assert_eq!(ds_0.properties.slow_query_ms, Some(250));
+        assert_eq!(ds_0.properties.explain_slow_queries, Some(true));
     }
 
     #[cfg(feature = "mssql")]
@@ -165,6 +170,18 @@ pub struct DatasourceProperties {
     pub port: Option<u16>,
     pub db_name: String,
     pub migrations: Option<Migrations>,
+    /// The number of prepared statements cached by every connection of the
+    /// datasource. Zero disables the cache.
+    pub statement_cache_size: Option<usize>,
+    /// The level of the `tracing` events emitted for every statement launched
+    /// against the datasource. `debug` by default.
+    pub log_level: Option<LogLevel>,
+    /// Whether the values of the parameters of the statements are logged
+    pub log_params: Option<ParamsLogging>,
+    /// The duration (in milliseconds) from which a statement is reported as slow
+    pub slow_query_ms: Option<u64>,
+    /// Whether the execution plan of the slow statements is retrieved
+    pub explain_slow_queries: Option<bool>,
 }
 
 /// Represents the enabled or disabled migrations for a whole datasource
@@ -175,3 +192,51 @@ pub enum Migrations {
     #[serde(alias = "Disabled", alias = "disabled")]
     Disabled,
 }
+
+/// The level of the `tracing` spans and events of the statements launched against
+/// a datasource. The failed statements are always logged with the `error` level,
+/// unless the logging is `off`
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub enum LogLevel {
+    #[serde(alias = "Off", alias = "off")]
+    Off,
+    #[serde(alias = "Error", alias = "error")]
+    Error,
+    #[serde(alias = "Warn", alias = "warn")]
+    Warn,
+    #[serde(alias = "Info", alias = "info")]
+    Info,
+    #[serde(alias = "Debug", alias = "debug")]
+    #[default]
+    Debug,
+    #[serde(alias = "Trace", alias = "trace")]
+    Trace,
+}
+
+impl LogLevel {
+    /// The equivalent [`tracing::Level`], being [`None`] when the logging is off
+    pub fn as_tracing_level(&self) -> Option<tracing::Level> {
+        match self {
+            LogLevel::Off => None,
+            LogLevel::Error => Some(tracing::Level::ERROR),
+            LogLevel::Warn => Some(tracing::Level::WARN),
+            LogLevel::Info => Some(tracing::Level::INFO),
+            LogLevel::Debug => Some(tracing::Level::DEBUG),
+            LogLevel::Trace => Some(tracing::Level::TRACE),
+        }
+    }
+}
+
+/// How the values of the parameters of the statements are logged. Only the
+/// number of parameters is logged by default
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub enum ParamsLogging {
+    #[serde(alias = "Hidden", alias = "hidden")]
+    #[default]
+    Hidden,
+    /// Every value is replaced by `***`, except the `NULL` ones
+    #[serde(alias = "Redacted", alias = "redacted")]
+    Redacted,
+    #[serde(alias = "Values", alias = "values")]
+    Values,
+}
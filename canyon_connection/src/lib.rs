@@ -10,9 +10,11 @@ pub extern crate tokio;
 #[cfg(feature = "postgres")]
 pub extern crate tokio_postgres;
 pub extern crate tokio_util;
+pub extern crate tracing;
 
 pub mod canyon_database_connector;
 pub mod datasources;
+pub mod statement_cache;
 
 use std::fs;
 use std::path::PathBuf;
@@ -21,6 +23,7 @@ use crate::datasources::{CanyonSqlConfig, DatasourceConfig};
 use canyon_database_connector::DatabaseConnection;
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
+use statement_cache::StatementCacheStats;
 use tokio::sync::{Mutex, MutexGuard};
 use walkdir::WalkDir;
 
@@ -121,3 +124,12 @@ pub fn get_database_config<'a>(
             .unwrap_or_else(|| panic!("Not found datasource expected {datasource_name}"))
     }
 }
+
+/// Retrieves the hits and misses of the prepared statements cache of the connection
+/// of the given datasource (or the default one, if the name is empty).
+///
+/// Returns [`None`] for the datasources whose database doesn't cache statements
+pub async fn statement_cache_stats(datasource_name: &str) -> Option<StatementCacheStats> {
+    let mut guarded_cache = CACHED_DATABASE_CONN.lock().await;
+    get_database_connection(datasource_name, &mut guarded_cache).statement_cache_stats()
+}
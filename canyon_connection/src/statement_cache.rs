@@ -0,0 +1,255 @@
+//! A per connection cache of prepared statements, keyed by the SQL text of the
+//! statement, that avoids preparing again the same statement on every query.
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use indexmap::IndexMap;
+
+/// The number of prepared statements that every connection keeps when the
+/// `statement_cache_size` property of the datasource isn't set
+pub const DEFAULT_STATEMENT_CACHE_SIZE: usize = 64;
+
+/// A snapshot of the usage of the prepared statements cache of a connection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatementCacheStats {
+    /// The times that a statement was already prepared
+    pub hits: u64,
+    /// The times that a statement had to be prepared
+    pub misses: u64,
+    /// The number of statements currently cached
+    pub size: usize,
+    /// The maximum number of statements that the cache holds
+    pub capacity: usize,
+}
+
+/// An LRU cache of prepared statements. When it's full, the least recently used
+/// statement is evicted to make room for the new one. A capacity of zero disables it.
+#[derive(Debug)]
+pub struct StatementCache<S> {
+    capacity: usize,
+    // The most recently used statements are kept at the end
+    statements: Mutex<IndexMap<String, S>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<S: Clone> StatementCache<S> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            statements: Mutex::new(IndexMap::with_capacity(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Retrieves the prepared statement for the given SQL text (if any),
+    /// marking it as the most recently used one
+    pub fn get(&self, sql: &str) -> Option<S> {
+        let mut statements = self.lock();
+        let statement = statements.shift_remove(sql);
+
+        match statement {
+            Some(statement) => {
+                statements.insert(sql.to_string(), statement.clone());
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(statement)
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Caches the prepared statement of the given SQL text, evicting the least
+    /// recently used statement if the cache is full.
+    ///
+    /// Returns the statement that is no longer cached (if any), the evicted one, or the
+    /// given one when the cache is disabled, for the databases that must close them
+    pub fn insert(&self, sql: String, statement: S) -> Option<S> {
+        if self.capacity == 0 {
+            return Some(statement);
+        }
+
+        let mut statements = self.lock();
+        let replaced = statements.shift_remove(&sql);
+        let evicted = if statements.len() >= self.capacity {
+            statements
+                .shift_remove_index(0)
+                .map(|(_sql, evicted)| evicted)
+        } else {
+            None
+        };
+        statements.insert(sql, statement);
+        replaced.or(evicted)
+    }
+
+    pub fn stats(&self) -> StatementCacheStats {
+        StatementCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            size: self.lock().len(),
+            capacity: self.capacity,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, IndexMap<String, S>> {
+        self.statements
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// The caches of prepared statements of the connections of a pool, given that every
+/// statement is only valid on the physical connection that prepared it.
+///
+/// Only the caches of the connections used most recently are kept, up to the maximum
+/// number of connections of the pool, since the pool closes the idle ones
+#[derive(Debug)]
+pub struct PooledStatementCache<S> {
+    capacity: usize,
+    max_connections: usize,
+    // The most recently used connections are kept at the end
+    connections: Mutex<IndexMap<u32, Arc<StatementCache<S>>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<S: Clone> PooledStatementCache<S> {
+    /// A cache of up to `capacity` statements for every one of the `max_connections`
+    pub fn new(capacity: usize, max_connections: usize) -> Self {
+        Self {
+            capacity,
+            max_connections,
+            connections: Mutex::new(IndexMap::with_capacity(max_connections)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Retrieves the statement for the given SQL text prepared by the connection (if any)
+    pub fn get(&self, connection_id: u32, sql: &str) -> Option<S> {
+        let statement = self.connection(connection_id).get(sql);
+        let counter = match statement {
+            Some(_) => &self.hits,
+            None => &self.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        statement
+    }
+
+    /// Caches the statement prepared by the connection, returning the one that must be
+    /// closed on it (if any), as [`StatementCache::insert`] does
+    pub fn insert(&self, connection_id: u32, sql: String, statement: S) -> Option<S> {
+        self.connection(connection_id).insert(sql, statement)
+    }
+
+    /// The usage of the caches of every connection. Its capacity is the one of
+    /// every connection times the maximum number of connections of the pool
+    pub fn stats(&self) -> StatementCacheStats {
+        StatementCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            size: self
+                .lock()
+                .values()
+                .map(|statements| statements.lock().len())
+                .sum(),
+            capacity: self.capacity * self.max_connections,
+        }
+    }
+
+    /// The cache of the connection, marked as the most recently used one
+    fn connection(&self, connection_id: u32) -> Arc<StatementCache<S>> {
+        let mut connections = self.lock();
+        let statements = connections
+            .shift_remove(&connection_id)
+            .unwrap_or_else(|| Arc::new(StatementCache::new(self.capacity)));
+        if connections.len() >= self.max_connections {
+            connections.shift_remove_index(0);
+        }
+        connections.insert(connection_id, statements.clone());
+        statements
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, IndexMap<u32, Arc<StatementCache<S>>>> {
+        self.connections
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod statement_cache_tests {
+    use super::*;
+
+    /// The least recently used statement is the one evicted
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = StatementCache::new(2);
+        cache.insert("SELECT 1".to_string(), 1);
+        cache.insert("SELECT 2".to_string(), 2);
+
+        assert_eq!(cache.get("SELECT 1"), Some(1));
+        cache.insert("SELECT 3".to_string(), 3);
+
+        assert_eq!(cache.get("SELECT 2"), None);
+        assert_eq!(cache.get("SELECT 1"), Some(1));
+        assert_eq!(cache.get("SELECT 3"), Some(3));
+        assert_eq!(
+            cache.stats(),
+            StatementCacheStats {
+                hits: 3,
+                misses: 1,
+                size: 2,
+                capacity: 2
+            }
+        );
+    }
+
+    /// A disabled cache never holds any statement, handing back the given ones
+    #[test]
+    fn disabled() {
+        let disabled = StatementCache::new(0);
+        assert_eq!(disabled.insert("SELECT 1".to_string(), 1), Some(1));
+        assert_eq!(disabled.get("SELECT 1"), None);
+        assert_eq!(disabled.stats().misses, 1);
+    }
+
+    /// The evicted statements are handed back, so they can be closed
+    #[test]
+    fn hands_back_the_evicted_statements() {
+        let cache = StatementCache::new(1);
+        assert_eq!(cache.insert("SELECT 1".to_string(), 1), None);
+        assert_eq!(cache.insert("SELECT 2".to_string(), 2), Some(1));
+        assert_eq!(cache.insert("SELECT 2".to_string(), 3), Some(2));
+    }
+
+    /// Every connection of a pool only reuses its own statements, and only the caches
+    /// of the most recently used connections are kept
+    #[test]
+    fn pooled_statements_belong_to_their_connection() {
+        let cache = PooledStatementCache::new(2, 2);
+        cache.insert(1, "SELECT 1".to_string(), 10);
+
+        assert_eq!(cache.get(1, "SELECT 1"), Some(10));
+        assert_eq!(cache.get(2, "SELECT 1"), None);
+        cache.insert(2, "SELECT 1".to_string(), 20);
+        assert_eq!(cache.get(3, "SELECT 1"), None);
+
+        // The cache of the first connection was discarded by the third one
+        assert_eq!(cache.get(1, "SELECT 1"), None);
+        assert_eq!(
+            cache.stats(),
+            StatementCacheStats {
+                hits: 1,
+                misses: 3,
+                size: 0,
+                capacity: 4
+            }
+        );
+    }
+}
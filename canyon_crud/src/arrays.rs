@@ -0,0 +1,284 @@
+//! Support for the `Vec<T>` fields, for `T` among the scalar types supported by Canyon.
+//!
+//! They're stored as arrays on `PostgreSQL` (like `integer[]` or `text[]`). `SqlServer` and
+//! `MySQL` has no arrays, so they're stored there as JSON arrays, in a `nvarchar(max)` and in
+//! a `JSON` column. The query builders filters them with the
+//! [`crate::query_elements::operators::ArrayOp`] operators.
+//!
+//! The `Vec<u8>` fields aren't arrays, but the binary columns of [`crate::blob`].
+use std::error::Error;
+
+#[cfg(feature = "mysql")]
+use canyon_connection::mysql_async::{self, prelude::ToValue};
+#[cfg(feature = "mssql")]
+use canyon_connection::tiberius::{self, ColumnData};
+#[cfg(feature = "postgres")]
+use canyon_connection::tokio_postgres::{self, types::ToSql};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+use serde_json::Value;
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+
+use crate::bounds::{QueryParameter, SqlLiteral};
+#[cfg(feature = "postgres")]
+use crate::mapper::from_postgres_row;
+use crate::mapper::FromColumn;
+
+/// A scalar type that can be the element of an array column
+pub trait ArrayElement: Sized {
+    /// Reads the element from the JSON value it's stored as on `SqlServer` and `MySQL`
+    fn from_json(value: &Value) -> Option<Self>;
+}
+
+impl ArrayElement for bool {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl ArrayElement for i16 {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_i64().and_then(|value| i16::try_from(value).ok())
+    }
+}
+
+impl ArrayElement for i32 {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_i64().and_then(|value| i32::try_from(value).ok())
+    }
+}
+
+impl ArrayElement for i64 {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_i64()
+    }
+}
+
+impl ArrayElement for f32 {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_f64().map(|value| value as f32)
+    }
+}
+
+impl ArrayElement for f64 {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+impl ArrayElement for String {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_str().map(str::to_owned)
+    }
+}
+
+impl ArrayElement for NaiveDate {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_str()?.parse().ok()
+    }
+}
+
+impl ArrayElement for NaiveTime {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_str()?.parse().ok()
+    }
+}
+
+impl ArrayElement for NaiveDateTime {
+    fn from_json(value: &Value) -> Option<Self> {
+        NaiveDateTime::parse_from_str(value.as_str()?, "%Y-%m-%d %H:%M:%S%.f").ok()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ArrayElement for Uuid {
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_str()?.parse().ok()
+    }
+}
+
+/// The decimals are stored as strings, so they keep all their digits
+#[cfg(feature = "decimal")]
+impl ArrayElement for Decimal {
+    fn from_json(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(decimal) => decimal.parse().ok(),
+            Value::Number(decimal) => decimal.to_string().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the elements as a JSON array, with the JSON values of their literals
+#[cfg(any(feature = "mssql", feature = "mysql"))]
+fn to_json_array<T: for<'a> QueryParameter<'a>>(elements: &[T]) -> String {
+    SqlLiteral::Array(
+        elements
+            .iter()
+            .map(|element| element.as_sql_literal())
+            .collect(),
+    )
+    .to_json()
+    .to_string()
+}
+
+/// Reads the elements of a JSON array
+#[cfg(any(feature = "mssql", feature = "mysql"))]
+fn from_json_array<T: ArrayElement>(
+    document: &str,
+) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+    serde_json::from_str::<Vec<Value>>(document)?
+        .iter()
+        .map(|value| {
+            T::from_json(value).ok_or_else(|| {
+                format!(
+                    "`{value}` isn't a valid element of an array of `{}`",
+                    std::any::type_name::<T>()
+                )
+                .into()
+            })
+        })
+        .collect()
+}
+
+/// The `MySQL` value of an array, which is its JSON document
+#[cfg(feature = "mysql")]
+#[repr(transparent)]
+struct MySqlArray<T>(Vec<T>);
+
+#[cfg(feature = "mysql")]
+impl<T> MySqlArray<T> {
+    fn from_vec(elements: &Vec<T>) -> &Self {
+        // SAFETY: `MySqlArray` is a transparent wrapper over the `Vec`, so both has the
+        // same layout, and the reference keeps the lifetime of the original one
+        unsafe { &*(elements as *const Vec<T>).cast::<Self>() }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<T: for<'a> QueryParameter<'a>> ToValue for MySqlArray<T> {
+    fn to_value(&self) -> mysql_async::Value {
+        mysql_async::Value::Bytes(to_json_array(&self.0).into_bytes())
+    }
+}
+
+/// Implements [`QueryParameter`] and [`FromColumn`] for the arrays of the given elements,
+/// and [`QueryParameter`] for the optional ones
+macro_rules! impl_array {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<'a> QueryParameter<'a> for Vec<$ty> {
+                #[cfg(feature = "postgres")]
+                fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+                    self
+                }
+                #[cfg(feature = "mssql")]
+                fn as_sqlserver_param(&self) -> ColumnData<'_> {
+                    ColumnData::String(Some(to_json_array(self).into()))
+                }
+                #[cfg(feature = "mysql")]
+                fn as_mysql_param(&self) -> &dyn ToValue {
+                    MySqlArray::from_vec(self)
+                }
+                fn as_sql_literal(&self) -> SqlLiteral<'_> {
+                    SqlLiteral::Array(self.iter().map(|element| element.as_sql_literal()).collect())
+                }
+            }
+
+            impl<'a> QueryParameter<'a> for Option<Vec<$ty>> {
+                #[cfg(feature = "postgres")]
+                fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+                    self
+                }
+                #[cfg(feature = "mssql")]
+                fn as_sqlserver_param(&self) -> ColumnData<'_> {
+                    ColumnData::String(self.as_deref().map(|elements| to_json_array(elements).into()))
+                }
+                #[cfg(feature = "mysql")]
+                fn as_mysql_param(&self) -> &dyn ToValue {
+                    match self {
+                        Some(elements) => MySqlArray::from_vec(elements),
+                        None => &None::<i32>,
+                    }
+                }
+                fn as_sql_literal(&self) -> SqlLiteral<'_> {
+                    self.as_ref()
+                        .map_or(SqlLiteral::Null, |elements| elements.as_sql_literal())
+                }
+            }
+
+            impl FromColumn for Vec<$ty> {
+                #[cfg(feature = "postgres")]
+                fn from_postgres(
+                    row: &tokio_postgres::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    from_postgres_row(row, column)
+                }
+                #[cfg(feature = "mssql")]
+                fn from_sqlserver(
+                    row: &tiberius::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    String::from_sqlserver(row, column)?
+                        .map(|document| from_json_array(&document))
+                        .transpose()
+                }
+                #[cfg(feature = "mysql")]
+                fn from_mysql(
+                    row: &mysql_async::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    String::from_mysql(row, column)?
+                        .map(|document| from_json_array(&document))
+                        .transpose()
+                }
+            }
+        )*
+    };
+}
+
+impl_array!(
+    bool,
+    i16,
+    i32,
+    i64,
+    f32,
+    f64,
+    String,
+    NaiveDate,
+    NaiveTime,
+    NaiveDateTime
+);
+#[cfg(feature = "uuid")]
+impl_array!(Uuid);
+#[cfg(feature = "decimal")]
+impl_array!(Decimal);
+
+#[cfg(all(test, any(feature = "mssql", feature = "mysql")))]
+mod arrays_tests {
+    use super::*;
+
+    /// The arrays are written as JSON arrays, and read back from them
+    #[test]
+    fn json_arrays_round_trip() {
+        let tags = vec![String::from("mid"), String::from("it's")];
+        let dates = vec![NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()];
+
+        assert_eq!(to_json_array(&tags), r#"["mid","it's"]"#);
+        assert_eq!(to_json_array(&[1_i32, -2]), "[1,-2]");
+        assert_eq!(to_json_array(&dates), r#"["2024-02-29"]"#);
+
+        assert_eq!(
+            from_json_array::<String>(r#"["mid","it's"]"#).unwrap(),
+            tags
+        );
+        assert_eq!(
+            from_json_array::<NaiveDate>(r#"["2024-02-29"]"#).unwrap(),
+            dates
+        );
+        assert!(from_json_array::<i16>("[1, 70000]").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+//! Support of the operations that looks for many primary keys with a single statement,
+//! like `find_by_pks`, which splits the keys in chunks that stay under the limit of
+//! parameters of the targeted database.
+use crate::crud::datasource_dialect;
+use crate::query_elements::sql_lexer::SqlDialect;
+
+/// The most parameters bound to a statement on SqlServer, which refuses the ones with
+/// more than 2100
+const SQL_SERVER_MAX_PARAMS: usize = 2_000;
+/// The most parameters bound to a statement on PostgreSQL and MySQL, which counts them
+/// with an unsigned 16 bits integer
+const MAX_PARAMS: usize = 65_535;
+
+/// How many primary keys made of `columns` columns are bound to a single statement
+/// launched against the given datasource
+pub fn chunk_len(datasource_name: &str, columns: usize) -> usize {
+    max_params(datasource_dialect(datasource_name)) / columns.max(1)
+}
+
+/// The most parameters bound to a statement launched against the given database
+pub(crate) fn max_params(dialect: SqlDialect) -> usize {
+    match dialect {
+        SqlDialect::SqlServer => SQL_SERVER_MAX_PARAMS,
+        SqlDialect::PostgreSql | SqlDialect::MySql => MAX_PARAMS,
+    }
+}
+
+/// Builds the condition that matches the rows whose primary key `columns` holds any of
+/// the `keys` bound values, starting on `$1`.
+///
+/// The single column keys are looked up with an `IN` list, and the composite ones with
+/// a disjunction of the conditions over every column of each key
+pub fn primary_keys_condition(columns: &[&str], keys: usize) -> String {
+    if let [column] = columns {
+        let placeholders = (1..=keys)
+            .map(|idx| format!("${idx}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return format!("\"{column}\" IN ({placeholders})");
+    }
+
+    let key_conditions = (0..keys)
+        .map(|key| {
+            let condition = columns
+                .iter()
+                .enumerate()
+                .map(|(idx, column)| format!("\"{column}\" = ${}", key * columns.len() + idx + 1))
+                .collect::<Vec<_>>()
+                .join(" AND ");
+            format!("({condition})")
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    format!("({key_conditions})")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_column_keys_are_an_in_list() {
+        assert_eq!(primary_keys_condition(&["id"], 3), "\"id\" IN ($1, $2, $3)");
+    }
+
+    #[test]
+    fn test_composite_keys_matches_every_column() {
+        assert_eq!(
+            primary_keys_condition(&["league", "season"], 2),
+            "((\"league\" = $1 AND \"season\" = $2) OR (\"league\" = $3 AND \"season\" = $4))"
+        );
+    }
+}
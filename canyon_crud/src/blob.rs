@@ -0,0 +1,337 @@
+//! Streaming access to the binary columns (`bytea`, `varbinary(max)` or `longblob`) of a row.
+//!
+//! The content is read and written in chunks of a bounded size, so large blobs, like file
+//! attachments, never have to be held entirely in memory, neither on the client nor on the
+//! statements sent to the database. A [`Blob`] is obtained from an entity instance with
+//! [`crate::crud::CrudOperations::blob`], given the field that maps the binary column.
+//!
+//! SqlServer appends the chunks in place, with the `.WRITE` clause. PostgreSQL and MySQL
+//! have no way to append to a binary column, so every appended chunk rewrites the whole
+//! content stored until then, and writing a content of `n` bytes costs `O(n²)` in chunks
+//! of a fixed size. Use chunks as large as possible over those databases, like the
+//! [`DEFAULT_CHUNK_SIZE`], to keep the number of rewrites low.
+use std::marker::PhantomData;
+
+use canyon_connection::tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use canyon_connection::{get_database_connection, CACHED_DATABASE_CONN};
+
+use crate::bounds::QueryParameter;
+use crate::crud::{datasource_dialect, Transaction, TransactionScope};
+use crate::query_elements::sql_lexer::SqlDialect;
+use crate::rows::CanyonRows;
+
+/// A size for the chunks of [`Blob::read_to`] and [`Blob::write_from`] that keeps
+/// both the memory used and the number of statements launched low
+pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// The binary column of a single row of a `T` entity, located by its primary key
+pub struct Blob<'a, T> {
+    table: &'static str,
+    column: &'static str,
+    primary_key: &'static str,
+    pk_value: &'a dyn for<'q> QueryParameter<'q>,
+    datasource_name: &'a str,
+    _entity: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: Transaction<T>> Blob<'a, T> {
+    /// Locates the binary column of the row. Used by the [`crate::crud::CrudOperations`]
+    /// implementations, that knows the table and the primary key of the entity
+    #[doc(hidden)]
+    pub fn new(
+        table: &'static str,
+        column: &'static str,
+        primary_key: &'static str,
+        pk_value: &'a dyn for<'q> QueryParameter<'q>,
+        datasource_name: &'a str,
+    ) -> Self {
+        Self {
+            table,
+            column,
+            primary_key,
+            pk_value,
+            datasource_name,
+            _entity: PhantomData,
+        }
+    }
+
+    /// The length, in bytes, of the content stored in the column, or [`None`] if it's `NULL`
+    pub async fn length(&self) -> Result<Option<u64>, Box<dyn std::error::Error + Send + Sync>> {
+        let length = match datasource_dialect(self.datasource_name) {
+            SqlDialect::PostgreSql => format!("CAST(octet_length({}) AS bigint)", self.column),
+            SqlDialect::SqlServer => format!("CAST(DATALENGTH({}) AS BIGINT)", self.column),
+            SqlDialect::MySql => format!("LENGTH({})", self.column),
+        };
+        let stmt = format!(
+            "SELECT {length} FROM {} WHERE {:?} = $1",
+            self.table, self.primary_key
+        );
+
+        let rows = T::query(stmt, [self.pk_value as _], self.datasource_name).await?;
+        let length: Option<Result<_, Box<dyn std::error::Error + Send + Sync>>> = match rows {
+            #[cfg(feature = "postgres")]
+            CanyonRows::Postgres(rows) => rows
+                .first()
+                .map(|row| row.try_get::<_, Option<i64>>(0).map_err(Into::into)),
+            #[cfg(feature = "mssql")]
+            CanyonRows::Tiberius(rows) => rows
+                .first()
+                .map(|row| row.try_get::<i64, _>(0).map_err(Into::into)),
+            #[cfg(feature = "mysql")]
+            CanyonRows::MySQL(rows) => rows
+                .first()
+                .map(|row| Ok(row.get::<Option<i64>, _>(0).flatten())),
+            _ => None,
+        };
+
+        match length {
+            Some(length) => Ok(length?.map(|length| length as u64)),
+            None => Err(self.not_found()),
+        }
+    }
+
+    /// Reads up to `size` bytes of the content, starting at the given `offset`. The returned
+    /// chunk is shorter than `size` (or empty) when the end of the content is reached
+    pub async fn read_chunk(
+        &self,
+        offset: u64,
+        size: usize,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        // The positions of the SQL substrings starts at one
+        let start = i64::try_from(offset + 1)?;
+        let size = i64::try_from(size)?;
+        let chunk = match datasource_dialect(self.datasource_name) {
+            // PostgreSQL has no `substring` over `bigint` positions, but the `bytea` values
+            // are limited to 1GB anyway
+            SqlDialect::PostgreSql => format!(
+                "substring({} FROM CAST(CAST($1 AS bigint) AS integer) \
+                FOR CAST(CAST($2 AS bigint) AS integer))",
+                self.column
+            ),
+            SqlDialect::SqlServer | SqlDialect::MySql => {
+                format!("SUBSTRING({}, $1, $2)", self.column)
+            }
+        };
+        let stmt = format!(
+            "SELECT {chunk} FROM {} WHERE {:?} = $3",
+            self.table, self.primary_key
+        );
+
+        let rows = T::query(
+            stmt,
+            [&start as _, &size as _, self.pk_value as _],
+            self.datasource_name,
+        )
+        .await?;
+        let chunk: Option<Result<_, Box<dyn std::error::Error + Send + Sync>>> = match rows {
+            #[cfg(feature = "postgres")]
+            CanyonRows::Postgres(rows) => rows
+                .first()
+                .map(|row| row.try_get::<_, Option<Vec<u8>>>(0).map_err(Into::into)),
+            #[cfg(feature = "mssql")]
+            CanyonRows::Tiberius(rows) => rows.first().map(|row| {
+                row.try_get::<&[u8], _>(0)
+                    .map(|chunk| chunk.map(<[u8]>::to_vec))
+                    .map_err(Into::into)
+            }),
+            #[cfg(feature = "mysql")]
+            CanyonRows::MySQL(rows) => rows
+                .first()
+                .map(|row| Ok(row.get::<Option<Vec<u8>>, _>(0).flatten())),
+            _ => None,
+        };
+
+        match chunk {
+            Some(chunk) => chunk.map(Option::unwrap_or_default),
+            None => Err(self.not_found()),
+        }
+    }
+
+    /// Copies the whole content into the given writer, reading it in chunks of
+    /// `chunk_size` bytes, and returns the number of bytes copied.
+    ///
+    /// Fails with an [`std::io::ErrorKind::InvalidInput`] error if the `chunk_size` is zero
+    pub async fn read_to<W>(
+        &self,
+        writer: &mut W,
+        chunk_size: usize,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        check_chunk_size(chunk_size)?;
+
+        let mut copied = 0;
+        loop {
+            let chunk = self.read_chunk(copied, chunk_size).await?;
+            writer.write_all(&chunk).await?;
+            copied += chunk.len() as u64;
+
+            if chunk.len() < chunk_size {
+                writer.flush().await?;
+                return Ok(copied);
+            }
+        }
+    }
+
+    /// Replaces the content stored in the column with an empty one
+    pub async fn truncate(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        T::execute(
+            self.truncate_stmt(),
+            [self.pk_value as _],
+            self.datasource_name,
+        )
+        .await?;
+        Ok(())
+    }
+
+    fn truncate_stmt(&self) -> String {
+        let empty = match datasource_dialect(self.datasource_name) {
+            SqlDialect::PostgreSql => "''::bytea",
+            SqlDialect::SqlServer => "0x",
+            SqlDialect::MySql => "''",
+        };
+        format!(
+            "UPDATE {} SET {} = {empty} WHERE {:?} = $1",
+            self.table, self.column, self.primary_key
+        )
+    }
+
+    /// Appends the chunk at the end of the content stored in the column, that's
+    /// considered empty if it's `NULL`.
+    ///
+    /// On PostgreSQL and MySQL, the whole content is rewritten with the chunk at its end
+    pub async fn append(
+        &self,
+        chunk: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        T::execute(
+            self.append_stmt(),
+            [&chunk as _, self.pk_value as _],
+            self.datasource_name,
+        )
+        .await?;
+        Ok(())
+    }
+
+    fn append_stmt(&self) -> String {
+        match datasource_dialect(self.datasource_name) {
+            SqlDialect::PostgreSql => format!(
+                "UPDATE {} SET {col} = COALESCE({col}, ''::bytea) || $1 WHERE {:?} = $2",
+                self.table,
+                self.primary_key,
+                col = self.column
+            ),
+            // The `.WRITE` clause appends in place, without rewriting the whole value,
+            // but it can't be used over the `NULL` values
+            SqlDialect::SqlServer => format!(
+                "UPDATE {table} SET {col} = 0x WHERE {pk:?} = $2 AND {col} IS NULL; \
+                UPDATE {table} SET {col}.WRITE($1, NULL, NULL) WHERE {pk:?} = $2",
+                table = self.table,
+                col = self.column,
+                pk = self.primary_key
+            ),
+            SqlDialect::MySql => format!(
+                "UPDATE {} SET {col} = CONCAT(COALESCE({col}, ''), $1) WHERE {:?} = $2",
+                self.table,
+                self.primary_key,
+                col = self.column
+            ),
+        }
+    }
+
+    /// Replaces the content stored in the column with the one given by the reader,
+    /// writing it in chunks of `chunk_size` bytes, and returns the number of bytes written.
+    ///
+    /// The content is replaced within a transaction, so the stored one is kept when any
+    /// chunk fails to be read or written. The connections of PostgreSQL and SqlServer are
+    /// shared by the whole datasource, so they aren't available to any other operation
+    /// until the whole content is written.
+    ///
+    /// Fails with an [`std::io::ErrorKind::InvalidInput`] error if the `chunk_size` is zero
+    pub async fn write_from<R>(
+        &self,
+        reader: &mut R,
+        chunk_size: usize,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        check_chunk_size(chunk_size)?;
+
+        let mut guarded_cache = CACHED_DATABASE_CONN.lock().await;
+        let database_conn = get_database_connection(self.datasource_name, &mut guarded_cache);
+        let mut transaction = TransactionScope::begin(self.datasource_name, database_conn).await?;
+
+        match self
+            .write_chunks(&mut transaction, reader, chunk_size)
+            .await
+        {
+            Ok(written) => {
+                transaction.commit().await?;
+                Ok(written)
+            }
+            Err(error) => {
+                transaction.rollback().await?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Truncates the content, and appends the chunks read from the reader within the transaction
+    async fn write_chunks<R>(
+        &self,
+        transaction: &mut TransactionScope<'_>,
+        reader: &mut R,
+        chunk_size: usize,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        transaction
+            .execute(self.truncate_stmt(), &[self.pk_value as _])
+            .await?;
+
+        let mut written = 0;
+        let mut chunk = Vec::with_capacity(chunk_size);
+        loop {
+            chunk.clear();
+            (&mut *reader)
+                .take(chunk_size as u64)
+                .read_to_end(&mut chunk)
+                .await?;
+            if chunk.is_empty() {
+                return Ok(written);
+            }
+
+            transaction
+                .execute(
+                    self.append_stmt(),
+                    &[&chunk.as_slice() as _, self.pk_value as _],
+                )
+                .await?;
+            written += chunk.len() as u64;
+        }
+    }
+
+    fn not_found(&self) -> Box<dyn std::error::Error + Send + Sync> {
+        Box::new(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!(
+                "There's no record on {} with the primary key of the instance",
+                self.table
+            ),
+        ))
+    }
+}
+
+/// Rejects the chunks of zero bytes, that would never reach the end of the content
+fn check_chunk_size(chunk_size: usize) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if chunk_size == 0 {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "The chunks of a blob must have at least one byte",
+        )));
+    }
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use crate::{
     crud::{CrudOperations, Transaction},
+    enums::EnumLabel,
     mapper::RowMapper,
+    query_elements::sql_lexer::SqlDialect,
+    types::CanyonType,
 };
 #[cfg(feature = "mysql")]
 use canyon_connection::mysql_async::{self, prelude::ToValue};
@@ -9,9 +12,17 @@ use canyon_connection::tiberius::{self, ColumnData, IntoSql};
 #[cfg(feature = "postgres")]
 use canyon_connection::tokio_postgres::{self, types::ToSql};
 
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SubsecRound, Utc};
 
+#[cfg(feature = "json")]
+use crate::json::{to_document, Json};
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "json")]
+use serde::{de::DeserializeOwned, Serialize};
 use std::{any::Any, borrow::Cow};
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
 
 /// Created for retrieve the field's name of a field of a struct, giving
 /// the Canyon's autogenerated enum with the variants that maps this
@@ -79,6 +90,89 @@ where
 pub trait ForeignKeyable<T> {
     /// Retrieves the field related to the column passed in
     fn get_fk_column(&self, column: &str) -> Option<&dyn QueryParameter<'_>>;
+
+    /// Retrieves the fields related to every column passed in, in the same order,
+    /// as the composite foreign keys references them
+    fn get_fk_columns(&self, columns: &[&str]) -> Option<Vec<&dyn QueryParameter<'_>>> {
+        columns
+            .iter()
+            .map(|column| self.get_fk_column(column))
+            .collect()
+    }
+}
+
+/// The value of the primary key of an entity, received by the `find_by_pk` operations.
+///
+/// It's the value itself for the entities with a single `#[primary_key]` field, and a
+/// tuple with the values of every `#[primary_key]` field, in the order of declaration,
+/// for the ones with a composite primary key.
+///
+/// ```ignore
+/// let league = League::find_by_pk(&1).await?;
+/// let membership = TeamMembership::find_by_pk(&(team_id, player_id)).await?;
+/// ```
+pub trait PrimaryKeyValue<'a>: Sync + Send {
+    /// The values of the columns of the primary key
+    fn primary_key_values(&self) -> Vec<&dyn QueryParameter<'a>>;
+}
+
+impl<'a, T: QueryParameter<'a>> PrimaryKeyValue<'a> for T {
+    fn primary_key_values(&self) -> Vec<&dyn QueryParameter<'a>> {
+        vec![self]
+    }
+}
+
+/// Implements [`PrimaryKeyValue`] for the tuples of the values of a composite primary key
+macro_rules! impl_primary_key_value {
+    ($(($($value:ident),+)),* $(,)?) => {
+        $(
+            impl<'a, $($value: QueryParameter<'a>),+> PrimaryKeyValue<'a> for ($($value,)+) {
+                #[allow(non_snake_case)]
+                fn primary_key_values(&self) -> Vec<&dyn QueryParameter<'a>> {
+                    let ($($value,)+) = self;
+                    vec![$($value),+]
+                }
+            }
+        )*
+    };
+}
+
+impl_primary_key_value!((A, B), (A, B, C), (A, B, C, D));
+
+/// The entities that can give the value of their primary key, implemented by the
+/// `CanyonCrud` derive for the ones with some `#[primary_key]` field.
+///
+/// The [`PrimaryKey::Key`] is the type of the field, or a tuple with the types of
+/// every field, in the order of declaration, for the composite primary keys
+pub trait PrimaryKey {
+    type Key: Send + Sync;
+
+    /// A copy of the value of the primary key of the instance
+    fn primary_key(&self) -> Self::Key;
+}
+
+/// Defines the types that can hold the moments managed by `Canyon` over the fields
+/// annotated with `#[created_at]` or `#[updated_at]`, which are the `NaiveDateTime`
+/// ones (optional or not), holding the moments in UTC
+///
+/// The current moment is truncated to microseconds, which is the greatest precision
+/// shared by the supported databases, so the value written back into the entities
+/// is the same that gets stored
+pub trait Timestamp {
+    /// Returns the current moment, in UTC
+    fn now() -> Self;
+}
+
+impl Timestamp for NaiveDateTime {
+    fn now() -> Self {
+        Utc::now().naive_utc().trunc_subsecs(6)
+    }
+}
+
+impl Timestamp for Option<NaiveDateTime> {
+    fn now() -> Self {
+        Some(<NaiveDateTime as Timestamp>::now())
+    }
 }
 
 /// Generic abstraction to represent any of the Row types
@@ -318,6 +412,138 @@ pub trait QueryParameter<'a>: std::fmt::Debug + Sync + Send {
     fn as_sqlserver_param(&self) -> ColumnData<'_>;
     #[cfg(feature = "mysql")]
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue;
+    /// The value of the parameter, to be written as a literal within the statement
+    /// when it's rendered for debugging purposes
+    ///
+    /// Defaults to its [`Debug`](std::fmt::Debug) representation, written as a text literal
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Text(format!("{self:?}").into())
+    }
+}
+
+/// The value of a [`QueryParameter`], as it's written as a literal within an SQL statement
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlLiteral<'a> {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Text(Cow<'a, str>),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    DateTime(NaiveDateTime),
+    DateTimeTz(DateTime<FixedOffset>),
+    Bytes(&'a [u8]),
+    #[cfg(feature = "uuid")]
+    Uuid(Uuid),
+    #[cfg(feature = "decimal")]
+    Decimal(Decimal),
+    Array(Vec<SqlLiteral<'a>>),
+}
+
+impl SqlLiteral<'_> {
+    /// Writes the literal with the syntax of the given dialect, quoting and escaping
+    /// the text values
+    pub fn to_sql(&self, dialect: SqlDialect) -> String {
+        match self {
+            SqlLiteral::Null => String::from("NULL"),
+            SqlLiteral::Bool(value) if dialect == SqlDialect::SqlServer => {
+                String::from(if *value { "1" } else { "0" })
+            }
+            SqlLiteral::Bool(value) => String::from(if *value { "TRUE" } else { "FALSE" }),
+            SqlLiteral::Int(value) => value.to_string(),
+            SqlLiteral::UInt(value) => value.to_string(),
+            SqlLiteral::Float(value) if value.is_finite() => value.to_string(),
+            SqlLiteral::Float(value) => quote(&value.to_string(), dialect),
+            SqlLiteral::Text(value) => quote(value, dialect),
+            SqlLiteral::Date(value) => quote(&value.format("%Y-%m-%d").to_string(), dialect),
+            SqlLiteral::Time(value) => quote(&value.format("%H:%M:%S%.f").to_string(), dialect),
+            SqlLiteral::DateTime(value) => {
+                quote(&value.format("%Y-%m-%d %H:%M:%S%.f").to_string(), dialect)
+            }
+            SqlLiteral::DateTimeTz(value) => quote(
+                &value.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string(),
+                dialect,
+            ),
+            SqlLiteral::Bytes(value) => {
+                let hex = value
+                    .iter()
+                    .map(|byte| format!("{byte:02X}"))
+                    .collect::<String>();
+                match dialect {
+                    SqlDialect::PostgreSql => format!("'\\x{hex}'"),
+                    SqlDialect::SqlServer => format!("0x{hex}"),
+                    SqlDialect::MySql => format!("X'{hex}'"),
+                }
+            }
+            // MySQL stores the UUIDs as `BINARY(16)`
+            #[cfg(feature = "uuid")]
+            SqlLiteral::Uuid(value) if dialect == SqlDialect::MySql => {
+                format!("UUID_TO_BIN('{value}')")
+            }
+            #[cfg(feature = "uuid")]
+            SqlLiteral::Uuid(value) => format!("'{value}'"),
+            #[cfg(feature = "decimal")]
+            SqlLiteral::Decimal(value) => value.to_string(),
+            // The arrays are stored as JSON documents out of PostgreSQL
+            SqlLiteral::Array(_) if dialect != SqlDialect::PostgreSql => {
+                quote(&self.to_json().to_string(), dialect)
+            }
+            SqlLiteral::Array(elements) if elements.is_empty() => String::from("'{}'"),
+            SqlLiteral::Array(elements) => format!(
+                "ARRAY[{}]",
+                elements
+                    .iter()
+                    .map(|element| element.to_sql(dialect))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    /// The literal as a JSON value, used to store the arrays as JSON documents
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value;
+
+        match self {
+            SqlLiteral::Null => Value::Null,
+            SqlLiteral::Bool(value) => Value::Bool(*value),
+            SqlLiteral::Int(value) => Value::from(*value),
+            SqlLiteral::UInt(value) => Value::from(*value),
+            SqlLiteral::Float(value) => Value::from(*value),
+            SqlLiteral::Text(value) => Value::from(value.as_ref()),
+            SqlLiteral::Date(value) => Value::from(value.format("%Y-%m-%d").to_string()),
+            SqlLiteral::Time(value) => Value::from(value.format("%H:%M:%S%.f").to_string()),
+            SqlLiteral::DateTime(value) => {
+                Value::from(value.format("%Y-%m-%d %H:%M:%S%.f").to_string())
+            }
+            SqlLiteral::DateTimeTz(value) => {
+                Value::from(value.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string())
+            }
+            SqlLiteral::Bytes(value) => Value::from(value.to_vec()),
+            #[cfg(feature = "uuid")]
+            SqlLiteral::Uuid(value) => Value::from(value.to_string()),
+            #[cfg(feature = "decimal")]
+            SqlLiteral::Decimal(value) => Value::from(value.to_string()),
+            SqlLiteral::Array(elements) => elements.iter().map(SqlLiteral::to_json).collect(),
+        }
+    }
+}
+
+/// Writes the text as a string literal of the given dialect
+fn quote(text: &str, dialect: SqlDialect) -> String {
+    match dialect {
+        SqlDialect::PostgreSql => format!("'{}'", text.replace('\'', "''")),
+        SqlDialect::SqlServer => format!("N'{}'", text.replace('\'', "''")),
+        SqlDialect::MySql => format!("'{}'", text.replace('\\', "\\\\").replace('\'', "''")),
+    }
+}
+
+/// The `f32` values are widened through their shortest representation, so `0.1`
+/// isn't written as `0.10000000149011612`
+fn float_literal(value: f32) -> SqlLiteral<'static> {
+    SqlLiteral::Float(value.to_string().parse().unwrap_or(f64::from(value)))
 }
 
 /// The implementation of the [`canyon_connection::tiberius`] [`IntoSql`] for the
@@ -350,6 +576,9 @@ impl<'a> QueryParameter<'a> for bool {
     fn as_mysql_param(&self) -> &dyn ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Bool(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for i16 {
     #[cfg(feature = "postgres")]
@@ -364,6 +593,9 @@ impl<'a> QueryParameter<'a> for i16 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Int((*self).into())
+    }
 }
 impl<'a> QueryParameter<'a> for &i16 {
     #[cfg(feature = "postgres")]
@@ -378,6 +610,9 @@ impl<'a> QueryParameter<'a> for &i16 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Int((**self).into())
+    }
 }
 impl<'a> QueryParameter<'a> for Option<i16> {
     #[cfg(feature = "postgres")]
@@ -392,6 +627,9 @@ impl<'a> QueryParameter<'a> for Option<i16> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| SqlLiteral::Int(value.into()))
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&i16> {
     #[cfg(feature = "postgres")]
@@ -406,6 +644,9 @@ impl<'a> QueryParameter<'a> for Option<&i16> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| SqlLiteral::Int((*value).into()))
+    }
 }
 impl<'a> QueryParameter<'a> for i32 {
     #[cfg(feature = "postgres")]
@@ -420,6 +661,9 @@ impl<'a> QueryParameter<'a> for i32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Int((*self).into())
+    }
 }
 impl<'a> QueryParameter<'a> for &i32 {
     #[cfg(feature = "postgres")]
@@ -434,6 +678,9 @@ impl<'a> QueryParameter<'a> for &i32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Int((**self).into())
+    }
 }
 impl<'a> QueryParameter<'a> for Option<i32> {
     #[cfg(feature = "postgres")]
@@ -448,6 +695,9 @@ impl<'a> QueryParameter<'a> for Option<i32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| SqlLiteral::Int(value.into()))
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&i32> {
     #[cfg(feature = "postgres")]
@@ -462,6 +712,9 @@ impl<'a> QueryParameter<'a> for Option<&i32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| SqlLiteral::Int((*value).into()))
+    }
 }
 impl<'a> QueryParameter<'a> for f32 {
     #[cfg(feature = "postgres")]
@@ -476,6 +729,9 @@ impl<'a> QueryParameter<'a> for f32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        float_literal(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &f32 {
     #[cfg(feature = "postgres")]
@@ -490,6 +746,9 @@ impl<'a> QueryParameter<'a> for &f32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        float_literal(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<f32> {
     #[cfg(feature = "postgres")]
@@ -504,6 +763,9 @@ impl<'a> QueryParameter<'a> for Option<f32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, float_literal)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&f32> {
     #[cfg(feature = "postgres")]
@@ -520,6 +782,9 @@ impl<'a> QueryParameter<'a> for Option<&f32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| float_literal(*value))
+    }
 }
 impl<'a> QueryParameter<'a> for f64 {
     #[cfg(feature = "postgres")]
@@ -534,6 +799,9 @@ impl<'a> QueryParameter<'a> for f64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Float(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &f64 {
     #[cfg(feature = "postgres")]
@@ -548,6 +816,9 @@ impl<'a> QueryParameter<'a> for &f64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Float(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<f64> {
     #[cfg(feature = "postgres")]
@@ -562,6 +833,9 @@ impl<'a> QueryParameter<'a> for Option<f64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::Float)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&f64> {
     #[cfg(feature = "postgres")]
@@ -578,6 +852,9 @@ impl<'a> QueryParameter<'a> for Option<&f64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| SqlLiteral::Float(*value))
+    }
 }
 impl<'a> QueryParameter<'a> for i64 {
     #[cfg(feature = "postgres")]
@@ -592,6 +869,9 @@ impl<'a> QueryParameter<'a> for i64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Int(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &i64 {
     #[cfg(feature = "postgres")]
@@ -606,6 +886,9 @@ impl<'a> QueryParameter<'a> for &i64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Int(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<i64> {
     #[cfg(feature = "postgres")]
@@ -620,6 +903,9 @@ impl<'a> QueryParameter<'a> for Option<i64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::Int)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&i64> {
     #[cfg(feature = "postgres")]
@@ -634,6 +920,9 @@ impl<'a> QueryParameter<'a> for Option<&i64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| SqlLiteral::Int(*value))
+    }
 }
 impl<'a> QueryParameter<'a> for String {
     #[cfg(feature = "postgres")]
@@ -648,6 +937,9 @@ impl<'a> QueryParameter<'a> for String {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Text(Cow::Borrowed(self))
+    }
 }
 impl<'a> QueryParameter<'a> for &String {
     #[cfg(feature = "postgres")]
@@ -662,6 +954,9 @@ impl<'a> QueryParameter<'a> for &String {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Text(Cow::Borrowed(self.as_str()))
+    }
 }
 impl<'a> QueryParameter<'a> for Option<String> {
     #[cfg(feature = "postgres")]
@@ -679,6 +974,11 @@ impl<'a> QueryParameter<'a> for Option<String> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.as_deref().map_or(SqlLiteral::Null, |value| {
+            SqlLiteral::Text(Cow::Borrowed(value))
+        })
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&String> {
     #[cfg(feature = "postgres")]
@@ -696,6 +996,11 @@ impl<'a> QueryParameter<'a> for Option<&String> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| {
+            SqlLiteral::Text(Cow::Borrowed(value))
+        })
+    }
 }
 impl<'a> QueryParameter<'a> for &'_ str {
     #[cfg(feature = "postgres")]
@@ -710,6 +1015,9 @@ impl<'a> QueryParameter<'a> for &'_ str {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Text(Cow::Borrowed(self))
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&'_ str> {
     #[cfg(feature = "postgres")]
@@ -727,6 +1035,11 @@ impl<'a> QueryParameter<'a> for Option<&'_ str> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| {
+            SqlLiteral::Text(Cow::Borrowed(value))
+        })
+    }
 }
 impl<'a> QueryParameter<'a> for NaiveDate {
     #[cfg(feature = "postgres")]
@@ -741,6 +1054,9 @@ impl<'a> QueryParameter<'a> for NaiveDate {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Date(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<NaiveDate> {
     #[cfg(feature = "postgres")]
@@ -755,6 +1071,9 @@ impl<'a> QueryParameter<'a> for Option<NaiveDate> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::Date)
+    }
 }
 impl<'a> QueryParameter<'a> for NaiveTime {
     #[cfg(feature = "postgres")]
@@ -769,6 +1088,9 @@ impl<'a> QueryParameter<'a> for NaiveTime {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Time(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<NaiveTime> {
     #[cfg(feature = "postgres")]
@@ -783,6 +1105,9 @@ impl<'a> QueryParameter<'a> for Option<NaiveTime> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::Time)
+    }
 }
 impl<'a> QueryParameter<'a> for NaiveDateTime {
     #[cfg(feature = "postgres")]
@@ -797,6 +1122,9 @@ impl<'a> QueryParameter<'a> for NaiveDateTime {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::DateTime(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<NaiveDateTime> {
     #[cfg(feature = "postgres")]
@@ -811,6 +1139,9 @@ impl<'a> QueryParameter<'a> for Option<NaiveDateTime> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::DateTime)
+    }
 }
 
 //TODO pending
@@ -827,6 +1158,9 @@ impl<'a> QueryParameter<'a> for DateTime<FixedOffset> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::DateTimeTz(*self)
+    }
 }
 
 impl<'a> QueryParameter<'a> for Option<DateTime<FixedOffset>> {
@@ -842,6 +1176,9 @@ impl<'a> QueryParameter<'a> for Option<DateTime<FixedOffset>> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::DateTimeTz)
+    }
 }
 
 impl<'a> QueryParameter<'a> for DateTime<Utc> {
@@ -857,6 +1194,9 @@ impl<'a> QueryParameter<'a> for DateTime<Utc> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::DateTimeTz((*self).into())
+    }
 }
 
 impl<'a> QueryParameter<'a> for Option<DateTime<Utc>> {
@@ -872,4 +1212,386 @@ impl<'a> QueryParameter<'a> for Option<DateTime<Utc>> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, |value| {
+            SqlLiteral::DateTimeTz(value.into())
+        })
+    }
+}
+
+impl<'a> QueryParameter<'a> for Vec<u8> {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Binary(Some(Cow::Borrowed(self)))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Bytes(self)
+    }
+}
+
+impl<'a> QueryParameter<'a> for Option<Vec<u8>> {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Binary(self.as_deref().map(Cow::Borrowed))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.as_deref().map_or(SqlLiteral::Null, SqlLiteral::Bytes)
+    }
+}
+
+impl<'a> QueryParameter<'a> for &'_ [u8] {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Binary(Some(Cow::Borrowed(*self)))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Bytes(self)
+    }
+}
+
+impl<'a> QueryParameter<'a> for Option<&'_ [u8]> {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Binary(self.map(Cow::Borrowed))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::Bytes)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<'a> QueryParameter<'a> for Uuid {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Guid(Some(*self))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Uuid(*self)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<'a> QueryParameter<'a> for Option<Uuid> {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Guid(*self)
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::Uuid)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<'a> QueryParameter<'a> for Decimal {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        tiberius::ToSql::to_sql(self)
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Decimal(*self)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<'a> QueryParameter<'a> for Option<Decimal> {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        tiberius::ToSql::to_sql(self)
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.map_or(SqlLiteral::Null, SqlLiteral::Decimal)
+    }
+}
+
+/// The JSON documents are bound as text on `SqlServer`, that stores them as `nvarchar(max)`
+#[cfg(feature = "json")]
+impl<'a> QueryParameter<'a> for serde_json::Value {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(Some(Cow::Owned(to_document(self))))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Text(Cow::Owned(to_document(self)))
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a> QueryParameter<'a> for Option<serde_json::Value> {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(self.as_ref().map(|value| Cow::Owned(to_document(value))))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.as_ref().map_or(SqlLiteral::Null, |value| {
+            SqlLiteral::Text(Cow::Owned(to_document(value)))
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a, T> QueryParameter<'a> for Json<T>
+where
+    T: Serialize + DeserializeOwned + Clone + std::fmt::Debug + Send + Sync,
+{
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(Some(Cow::Owned(to_document(&self.0))))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Text(Cow::Owned(to_document(&self.0)))
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a, T> QueryParameter<'a> for Option<Json<T>>
+where
+    T: Serialize + DeserializeOwned + Clone + std::fmt::Debug + Send + Sync,
+{
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(
+            self.as_ref()
+                .map(|document| Cow::Owned(to_document(&document.0))),
+        )
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.as_ref().map_or(SqlLiteral::Null, |document| {
+            SqlLiteral::Text(Cow::Owned(to_document(&document.0)))
+        })
+    }
+}
+
+/// The labels of the enums are bound as text, or as the values of the native
+/// enumerated types on `PostgreSQL`
+impl<'a> QueryParameter<'a> for EnumLabel {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(Some(Cow::Borrowed(&self.0)))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        SqlLiteral::Text(Cow::Borrowed(&self.0))
+    }
+}
+
+impl<'a> QueryParameter<'a> for Option<EnumLabel> {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(self.as_ref().map(|label| Cow::Borrowed(&*label.0)))
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.as_ref().map_or(SqlLiteral::Null, |label| {
+            SqlLiteral::Text(Cow::Borrowed(&label.0))
+        })
+    }
+}
+
+/// The custom types are bound as their representation
+impl<'a, T: CanyonType> QueryParameter<'a> for T {
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self.as_repr().as_postgres_param()
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        self.as_repr().as_sqlserver_param()
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        self.as_repr().as_mysql_param()
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.as_repr().as_sql_literal()
+    }
+}
+
+impl<'a, T> QueryParameter<'a> for Option<T>
+where
+    T: CanyonType,
+    Option<T::Repr>: for<'b> QueryParameter<'b>,
+{
+    #[cfg(feature = "postgres")]
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        match self {
+            Some(value) => value.as_postgres_param(),
+            None => &PostgresNull,
+        }
+    }
+    #[cfg(feature = "mssql")]
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        match self {
+            Some(value) => value.as_sqlserver_param(),
+            None => sqlserver_null(None::<T::Repr>.as_sqlserver_param()),
+        }
+    }
+    #[cfg(feature = "mysql")]
+    fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
+        match self {
+            Some(value) => value.as_mysql_param(),
+            None => &None::<i32>,
+        }
+    }
+    fn as_sql_literal(&self) -> SqlLiteral<'_> {
+        self.as_ref()
+            .map_or(SqlLiteral::Null, |value| value.as_sql_literal())
+    }
+}
+
+/// A `NULL` that can be bound to a parameter of any `PostgreSQL` type
+#[cfg(feature = "postgres")]
+#[derive(Debug)]
+struct PostgresNull;
+
+#[cfg(feature = "postgres")]
+impl ToSql for PostgresNull {
+    fn to_sql(
+        &self,
+        _ty: &tokio_postgres::types::Type,
+        _out: &mut bytes::BytesMut,
+    ) -> Result<tokio_postgres::types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(tokio_postgres::types::IsNull::Yes)
+    }
+
+    fn accepts(_ty: &tokio_postgres::types::Type) -> bool {
+        true
+    }
+
+    tokio_postgres::types::to_sql_checked!();
+}
+
+/// The `NULL` of the same `SqlServer` type as the given value, given that the database
+/// doesn't convert the `NULL` of some types, like the integers, into some others
+#[cfg(feature = "mssql")]
+fn sqlserver_null(value: ColumnData<'_>) -> ColumnData<'static> {
+    match value {
+        ColumnData::U8(_) => ColumnData::U8(None),
+        ColumnData::I16(_) => ColumnData::I16(None),
+        ColumnData::I32(_) => ColumnData::I32(None),
+        ColumnData::I64(_) => ColumnData::I64(None),
+        ColumnData::F32(_) => ColumnData::F32(None),
+        ColumnData::F64(_) => ColumnData::F64(None),
+        ColumnData::Bit(_) => ColumnData::Bit(None),
+        ColumnData::String(_) => ColumnData::String(None),
+        ColumnData::Guid(_) => ColumnData::Guid(None),
+        ColumnData::Binary(_) => ColumnData::Binary(None),
+        ColumnData::Numeric(_) => ColumnData::Numeric(None),
+        ColumnData::Xml(_) => ColumnData::Xml(None),
+        ColumnData::DateTime(_) => ColumnData::DateTime(None),
+        ColumnData::SmallDateTime(_) => ColumnData::SmallDateTime(None),
+        ColumnData::Time(_) => ColumnData::Time(None),
+        ColumnData::Date(_) => ColumnData::Date(None),
+        ColumnData::DateTime2(_) => ColumnData::DateTime2(None),
+        ColumnData::DateTimeOffset(_) => ColumnData::DateTimeOffset(None),
+    }
 }
@@ -1,21 +1,25 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::hash::Hash;
 
-use canyon_connection::canyon_database_connector::DatabaseConnection;
-use canyon_connection::{get_database_connection, CACHED_DATABASE_CONN};
+use canyon_connection::canyon_database_connector::{DatabaseConnection, DatabaseType};
+use canyon_connection::{
+    get_database_config, get_database_connection, CACHED_DATABASE_CONN, DATASOURCES,
+};
 
-use crate::bounds::QueryParameter;
+use crate::blob::Blob;
+use crate::bounds::{FieldIdentifier, PrimaryKey, PrimaryKeyValue, QueryParameter};
 use crate::mapper::RowMapper;
+use crate::query_elements::named_params::NamedParams;
 use crate::query_elements::query_builder::{
     DeleteQueryBuilder, SelectQueryBuilder, UpdateQueryBuilder,
 };
+use crate::query_elements::query_plan::QueryPlan;
+use crate::query_elements::sql_lexer::SqlDialect;
+use crate::query_log::StatementLog;
 use crate::rows::CanyonRows;
 
-#[cfg(feature = "mysql")]
-pub const DETECT_PARAMS_IN_QUERY: &str = r"\$([\d])+";
-#[cfg(feature = "mysql")]
-pub const DETECT_QUOTE_IN_QUERY: &str = r#"\"|\\"#;
-
 /// This traits defines and implements a query against a database given
 /// an statement `stmt` and the params to pass the to the client.
 ///
@@ -30,42 +34,356 @@ pub trait Transaction<T> {
         stmt: S,
         params: Z,
         datasource_name: &'a str,
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>>
+    ) -> Result<CanyonRows<T>, Box<dyn std::error::Error + Sync + Send + 'static>>
     where
         S: AsRef<str> + Display + Sync + Send + 'a,
         Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
     {
+        let params = params.as_ref();
+        let mut log = StatementLog::new(datasource_name, stmt.as_ref(), params);
         let mut guarded_cache = CACHED_DATABASE_CONN.lock().await;
         let database_conn = get_database_connection(datasource_name, &mut guarded_cache);
 
+        let statement = async {
+            match *database_conn {
+                #[cfg(feature = "postgres")]
+                DatabaseConnection::Postgres(_) => {
+                    postgres_query_launcher::launch::<T>(database_conn, stmt.to_string(), params)
+                        .await
+                }
+                #[cfg(feature = "mssql")]
+                DatabaseConnection::SqlServer(_) => {
+                    sqlserver_query_launcher::launch::<T>(
+                        database_conn,
+                        &mut stmt.to_string(),
+                        params,
+                    )
+                    .await
+                }
+                #[cfg(feature = "mysql")]
+                DatabaseConnection::MySQL(_) => {
+                    mysql_query_launcher::launch::<T>(database_conn, stmt.to_string(), params).await
+                }
+            }
+        };
+
+        let result = log
+            .run(|rows: &CanyonRows<T>| rows.len() as u64, statement)
+            .await;
+        report_if_slow(&log, database_conn, stmt.as_ref(), params).await;
+        result
+    }
+
+    /// Executes a statement against the targeted database by the selected or
+    /// the defaulted datasource, discarding any resultant row and returning
+    /// the number of rows affected by the statement
+    async fn execute<'a, S, Z>(
+        stmt: S,
+        params: Z,
+        datasource_name: &'a str,
+    ) -> Result<u64, Box<dyn std::error::Error + Sync + Send + 'static>>
+    where
+        S: AsRef<str> + Display + Sync + Send + 'a,
+        Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
+    {
+        let params = params.as_ref();
+        let mut log = StatementLog::new(datasource_name, stmt.as_ref(), params);
+        let mut guarded_cache = CACHED_DATABASE_CONN.lock().await;
+        let database_conn = get_database_connection(datasource_name, &mut guarded_cache);
+
+        let statement = async {
+            match *database_conn {
+                #[cfg(feature = "postgres")]
+                DatabaseConnection::Postgres(_) => {
+                    postgres_query_launcher::execute(database_conn, stmt.to_string(), params).await
+                }
+                #[cfg(feature = "mssql")]
+                DatabaseConnection::SqlServer(_) => {
+                    sqlserver_query_launcher::execute(database_conn, stmt.to_string(), params).await
+                }
+                #[cfg(feature = "mysql")]
+                DatabaseConnection::MySQL(_) => {
+                    mysql_query_launcher::execute(database_conn, stmt.to_string(), params).await
+                }
+            }
+        };
+
+        let result = log
+            .run(|affected_rows: &u64| *affected_rows, statement)
+            .await;
+        report_if_slow(&log, database_conn, stmt.as_ref(), params).await;
+        result
+    }
+
+    /// Same as [`Transaction::query`], but with a statement that uses `:name`
+    /// placeholders, whose values are taken from the given [`NamedParams`],
+    /// usually created with the [`crate::params`] macro.
+    ///
+    /// The placeholders found inside string literals, quoted identifiers or
+    /// comments are left untouched, as well as the PostgreSQL `::` casts
+    async fn query_named<'a, S>(
+        stmt: S,
+        params: NamedParams<'a>,
+        datasource_name: &'a str,
+    ) -> Result<CanyonRows<T>, Box<dyn std::error::Error + Sync + Send>>
+    where
+        S: AsRef<str> + Display + Sync + Send + 'a,
+    {
+        let (stmt, params) = params.bind(stmt.as_ref(), datasource_dialect(datasource_name))?;
+        Self::query(stmt, params, datasource_name).await
+    }
+
+    /// Same as [`Transaction::execute`], but with a statement that uses `:name`
+    /// placeholders, whose values are taken from the given [`NamedParams`]
+    async fn execute_named<'a, S>(
+        stmt: S,
+        params: NamedParams<'a>,
+        datasource_name: &'a str,
+    ) -> Result<u64, Box<dyn std::error::Error + Sync + Send>>
+    where
+        S: AsRef<str> + Display + Sync + Send + 'a,
+    {
+        let (stmt, params) = params.bind(stmt.as_ref(), datasource_dialect(datasource_name))?;
+        Self::execute(stmt, params, datasource_name).await
+    }
+}
+
+/// The [`SqlDialect`] of the database targeted by the given datasource
+pub(crate) fn datasource_dialect(datasource_name: &str) -> SqlDialect {
+    DatabaseType::from(&get_database_config(datasource_name, &DATASOURCES).auth).into()
+}
+
+/// Reports the statement if it has exceeded the `slow_query_ms` of the datasource,
+/// along with its execution plan when the `explain_slow_queries` property is enabled
+pub(crate) async fn report_if_slow<'a>(
+    log: &StatementLog,
+    database_conn: &mut DatabaseConnection,
+    stmt: &str,
+    params: &[&'a dyn QueryParameter<'a>],
+) {
+    if !log.is_slow() {
+        return;
+    }
+
+    let plan = if log.explains_slow_queries() {
+        let plan = match *database_conn {
+            #[cfg(feature = "postgres")]
+            DatabaseConnection::Postgres(_) => {
+                postgres_query_launcher::explain(database_conn, stmt, params, false).await
+            }
+            #[cfg(feature = "mssql")]
+            DatabaseConnection::SqlServer(_) => {
+                sqlserver_query_launcher::explain(database_conn, stmt, params, false).await
+            }
+            #[cfg(feature = "mysql")]
+            DatabaseConnection::MySQL(_) => {
+                mysql_query_launcher::explain(database_conn, stmt, params, false).await
+            }
+        };
+        plan.map_err(|error| tracing::debug!(%error, "The slow statement couldn't be explained"))
+            .ok()
+    } else {
+        None
+    };
+
+    log.report_slow(stmt, params, plan);
+}
+
+/// Retrieves the execution plan of the statement from the database targeted by the
+/// given datasource, executing it when it's `analyze`d
+pub(crate) async fn explain_statement<'a>(
+    datasource_name: &str,
+    stmt: &str,
+    params: &[&'a dyn QueryParameter<'a>],
+    analyze: bool,
+) -> Result<QueryPlan, Box<dyn std::error::Error + Send + Sync>> {
+    let mut guarded_cache = CACHED_DATABASE_CONN.lock().await;
+    let database_conn = get_database_connection(datasource_name, &mut guarded_cache);
+
+    match *database_conn {
+        #[cfg(feature = "postgres")]
+        DatabaseConnection::Postgres(_) => QueryPlan::from_postgres(
+            postgres_query_launcher::explain(database_conn, stmt, params, analyze).await?,
+        ),
+        #[cfg(feature = "mssql")]
+        DatabaseConnection::SqlServer(_) => QueryPlan::from_sqlserver(
+            sqlserver_query_launcher::explain(database_conn, stmt, params, analyze).await?,
+        ),
+        #[cfg(feature = "mysql")]
+        DatabaseConnection::MySQL(_) => {
+            let plan = mysql_query_launcher::explain(database_conn, stmt, params, analyze).await?;
+            if analyze {
+                QueryPlan::from_mysql_tree(plan)
+            } else {
+                QueryPlan::from_mysql_json(plan)
+            }
+        }
+    }
+}
+
+/// A transaction over a single connection of a datasource, where the statements given
+/// to [`TransactionScope::execute`] are committed or rolled back together.
+///
+/// The connections of PostgreSQL and SqlServer are shared by the whole datasource, so the
+/// caller keeps the cache of connections locked until the transaction ends. MySQL runs the
+/// transaction over its own connection of the pool
+pub(crate) struct TransactionScope<'c> {
+    datasource_name: &'c str,
+    database_conn: &'c mut DatabaseConnection,
+    #[cfg(feature = "mysql")]
+    mysql_conn: Option<mysql_async::Conn>,
+}
+
+impl<'c> TransactionScope<'c> {
+    /// Starts the transaction on the given connection of the datasource
+    pub(crate) async fn begin(
+        datasource_name: &'c str,
+        database_conn: &'c mut DatabaseConnection,
+    ) -> Result<TransactionScope<'c>, Box<dyn std::error::Error + Send + Sync>> {
         match *database_conn {
             #[cfg(feature = "postgres")]
             DatabaseConnection::Postgres(_) => {
-                postgres_query_launcher::launch::<T>(
-                    database_conn,
-                    stmt.to_string(),
-                    params.as_ref(),
-                )
-                .await
+                database_conn
+                    .postgres_connection()
+                    .client
+                    .batch_execute("BEGIN")
+                    .await?
             }
             #[cfg(feature = "mssql")]
             DatabaseConnection::SqlServer(_) => {
-                sqlserver_query_launcher::launch::<T, Z>(
-                    database_conn,
-                    &mut stmt.to_string(),
-                    params,
-                )
-                .await
+                database_conn
+                    .sqlserver_connection()
+                    .client
+                    .simple_query("BEGIN TRAN")
+                    .await?
+                    .into_results()
+                    .await?;
+            }
+            // It starts the transaction on its own connection
+            #[cfg(feature = "mysql")]
+            DatabaseConnection::MySQL(_) => {}
+        }
+
+        Ok(Self {
+            datasource_name,
+            #[cfg(feature = "mysql")]
+            mysql_conn: Self::begin_mysql(database_conn).await?,
+            database_conn,
+        })
+    }
+
+    /// Takes a connection from the pool of a MySQL datasource, and starts the transaction on it
+    #[cfg(feature = "mysql")]
+    async fn begin_mysql(
+        database_conn: &DatabaseConnection,
+    ) -> Result<Option<mysql_async::Conn>, Box<dyn std::error::Error + Send + Sync>> {
+        if !matches!(database_conn, DatabaseConnection::MySQL(_)) {
+            return Ok(None);
+        }
+
+        let mut conn = database_conn.mysql_connection().client.get_conn().await?;
+        mysql_async::prelude::Queryable::query_drop(&mut conn, "START TRANSACTION").await?;
+        Ok(Some(conn))
+    }
+
+    /// Executes a statement within the transaction, returning the number of rows affected
+    pub(crate) async fn execute<'a>(
+        &mut self,
+        stmt: String,
+        params: &[&'a dyn QueryParameter<'a>],
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let mut log = StatementLog::new(self.datasource_name, &stmt, params);
+        let statement = async {
+            match *self.database_conn {
+                #[cfg(feature = "postgres")]
+                DatabaseConnection::Postgres(_) => {
+                    postgres_query_launcher::execute(self.database_conn, stmt, params).await
+                }
+                #[cfg(feature = "mssql")]
+                DatabaseConnection::SqlServer(_) => {
+                    sqlserver_query_launcher::execute(self.database_conn, stmt, params).await
+                }
+                #[cfg(feature = "mysql")]
+                DatabaseConnection::MySQL(_) => {
+                    let conn = self
+                        .mysql_conn
+                        .as_mut()
+                        .expect("The MySQL transactions holds their own connection");
+                    let statements = &self.database_conn.mysql_connection().statements;
+                    mysql_query_launcher::execute_on(statements, conn, stmt, params).await
+                }
+            }
+        };
+
+        log.run(|affected_rows: &u64| *affected_rows, statement)
+            .await
+    }
+
+    /// Makes the changes of the statements executed within the transaction permanent
+    pub(crate) async fn commit(self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.end("COMMIT").await
+    }
+
+    /// Discards the changes of the statements executed within the transaction
+    pub(crate) async fn rollback(self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.end("ROLLBACK").await
+    }
+
+    async fn end(self, command: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        match *self.database_conn {
+            #[cfg(feature = "postgres")]
+            DatabaseConnection::Postgres(_) => {
+                self.database_conn
+                    .postgres_connection()
+                    .client
+                    .batch_execute(command)
+                    .await?
+            }
+            #[cfg(feature = "mssql")]
+            DatabaseConnection::SqlServer(_) => {
+                self.database_conn
+                    .sqlserver_connection()
+                    .client
+                    .simple_query(format!("{command} TRAN"))
+                    .await?
+                    .into_results()
+                    .await?;
             }
             #[cfg(feature = "mysql")]
             DatabaseConnection::MySQL(_) => {
-                mysql_query_launcher::launch::<T>(database_conn, stmt.to_string(), params.as_ref())
-                    .await
+                if let Some(mut conn) = self.mysql_conn {
+                    mysql_async::prelude::Queryable::query_drop(&mut conn, command).await?;
+                }
             }
         }
+        Ok(())
     }
 }
 
+/// Error returned by the `update` operations of the entities that contains a field
+/// annotated with `#[version]`, when the record that matches the primary key of the
+/// instance has been modified (or deleted) since the instance was retrieved.
+///
+/// Saving the changes anyway would overwrite the ones made by someone else, so the
+/// caller should retrieve the record again and decide how to proceed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionConflictError {
+    /// The table (with the schema, if any) where the conflict was detected
+    pub table: String,
+}
+
+impl Display for VersionConflictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The record on {} has been modified or deleted since the instance to update was retrieved",
+            self.table
+        )
+    }
+}
+
+impl std::error::Error for VersionConflictError {}
+
 /// *CrudOperations* it's the core part of Canyon-SQL.
 ///
 /// Here it's defined and implemented every CRUD operation
@@ -86,11 +404,11 @@ pub trait CrudOperations<T>: Transaction<T>
 where
     T: CrudOperations<T> + RowMapper<T>,
 {
-    async fn find_all<'a>() -> Result<Vec<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn find_all<'a>() -> Result<Vec<T>, Box<dyn std::error::Error + Send + Sync + 'static>>;
 
     async fn find_all_datasource<'a>(
         datasource_name: &'a str,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Send + Sync + 'static>>;
 
     async fn find_all_unchecked<'a>() -> Vec<T>;
 
@@ -100,20 +418,58 @@ where
 
     fn select_query_datasource(datasource_name: &str) -> SelectQueryBuilder<'_, T>;
 
-    async fn count() -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn count() -> Result<i64, Box<dyn std::error::Error + Send + Sync + 'static>>;
 
     async fn count_datasource<'a>(
         datasource_name: &'a str,
-    ) -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    ) -> Result<i64, Box<dyn std::error::Error + Send + Sync + 'static>>;
 
     async fn find_by_pk<'a>(
-        value: &'a dyn QueryParameter<'a>,
-    ) -> Result<Option<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+        value: &'a dyn PrimaryKeyValue<'a>,
+    ) -> Result<Option<T>, Box<dyn std::error::Error + Send + Sync + 'static>>;
 
     async fn find_by_pk_datasource<'a>(
-        value: &'a dyn QueryParameter<'a>,
+        value: &'a dyn PrimaryKeyValue<'a>,
         datasource_name: &'a str,
-    ) -> Result<Option<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    ) -> Result<Option<T>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+    async fn find_by_pks<'a, P: PrimaryKeyValue<'a>>(
+        values: &'a [P],
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn find_by_pks_datasource<'a, P: PrimaryKeyValue<'a>>(
+        values: &'a [P],
+        datasource_name: &'a str,
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Same as [`CrudOperations::find_by_pks`], but with the found entities
+    /// indexed by the value of their primary key
+    async fn find_by_pks_map<'a, P: PrimaryKeyValue<'a>>(
+        values: &'a [P],
+    ) -> Result<HashMap<T::Key, T>, Box<dyn std::error::Error + Send + Sync>>
+    where
+        T: PrimaryKey + Send,
+        T::Key: Eq + Hash,
+    {
+        Self::find_by_pks_map_datasource(values, "").await
+    }
+
+    /// Same as [`CrudOperations::find_by_pks_datasource`], but with the found
+    /// entities indexed by the value of their primary key
+    async fn find_by_pks_map_datasource<'a, P: PrimaryKeyValue<'a>>(
+        values: &'a [P],
+        datasource_name: &'a str,
+    ) -> Result<HashMap<T::Key, T>, Box<dyn std::error::Error + Send + Sync>>
+    where
+        T: PrimaryKey + Send,
+        T::Key: Eq + Hash,
+    {
+        let entities = Self::find_by_pks_datasource(values, datasource_name).await?;
+        Ok(entities
+            .into_iter()
+            .map(|entity| (entity.primary_key(), entity))
+            .collect())
+    }
 
     async fn insert<'a>(&mut self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
 
@@ -124,17 +480,17 @@ where
 
     async fn multi_insert<'a>(
         instances: &'a mut [&'a mut T],
-    ) -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
 
     async fn multi_insert_datasource<'a>(
         instances: &'a mut [&'a mut T],
         datasource_name: &'a str,
-    ) -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
 
-    async fn update(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+    async fn update(&mut self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
 
     async fn update_datasource<'a>(
-        &self,
+        &mut self,
         datasource_name: &'a str,
     ) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
 
@@ -152,11 +508,38 @@ where
     fn delete_query<'a>() -> DeleteQueryBuilder<'a, T>;
 
     fn delete_query_datasource(datasource_name: &str) -> DeleteQueryBuilder<'_, T>;
+
+    async fn force_delete(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+
+    async fn force_delete_datasource<'a>(
+        &self,
+        datasource_name: &'a str,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+
+    async fn restore(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+
+    async fn restore_datasource<'a>(
+        &self,
+        datasource_name: &'a str,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+
+    fn blob<'a, Z: FieldIdentifier<T>>(
+        &'a self,
+        column: Z,
+    ) -> Result<Blob<'a, T>, Box<dyn std::error::Error + Sync + Send>>;
+
+    fn blob_datasource<'a, Z: FieldIdentifier<T>>(
+        &'a self,
+        column: Z,
+        datasource_name: &'a str,
+    ) -> Result<Blob<'a, T>, Box<dyn std::error::Error + Sync + Send>>;
 }
 
 #[cfg(feature = "postgres")]
 mod postgres_query_launcher {
-    use canyon_connection::canyon_database_connector::DatabaseConnection;
+    use canyon_connection::canyon_database_connector::{DatabaseConnection, PostgreSqlConnection};
+    use canyon_connection::tokio_postgres::types::{FromSql, Type};
+    use canyon_connection::tokio_postgres::Statement;
 
     use crate::bounds::QueryParameter;
     use crate::rows::CanyonRows;
@@ -165,38 +548,125 @@ mod postgres_query_launcher {
         db_conn: &DatabaseConnection,
         stmt: String,
         params: &'a [&'_ dyn QueryParameter<'_>],
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
+    ) -> Result<CanyonRows<T>, Box<dyn std::error::Error + Send + Sync + 'static>> {
         let mut m_params = Vec::new();
         for param in params {
             m_params.push(param.as_postgres_param());
         }
 
-        let r = db_conn
-            .postgres_connection()
+        let postgres_connection = db_conn.postgres_connection();
+        let statement = prepare(postgres_connection, &stmt).await?;
+        let r = postgres_connection
             .client
-            .query(&stmt, m_params.as_slice())
+            .query(&statement, m_params.as_slice())
             .await?;
 
         Ok(CanyonRows::Postgres(r))
     }
+
+    pub async fn execute<'a>(
+        db_conn: &DatabaseConnection,
+        stmt: String,
+        params: &'a [&'_ dyn QueryParameter<'_>],
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mut m_params = Vec::new();
+        for param in params {
+            m_params.push(param.as_postgres_param());
+        }
+
+        let postgres_connection = db_conn.postgres_connection();
+        let statement = prepare(postgres_connection, &stmt).await?;
+        Ok(postgres_connection
+            .client
+            .execute(&statement, m_params.as_slice())
+            .await?)
+    }
+
+    /// Retrieves the execution plan of the statement. When it's `analyze`d, the
+    /// statement is executed within a transaction that is rolled back afterwards
+    pub async fn explain(
+        db_conn: &DatabaseConnection,
+        stmt: &str,
+        params: &[&'_ dyn QueryParameter<'_>],
+        analyze: bool,
+    ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let mut m_params = Vec::new();
+        for param in params {
+            m_params.push(param.as_postgres_param());
+        }
+
+        let client = &db_conn.postgres_connection().client;
+        if !analyze {
+            let row = client
+                .query_one(
+                    &format!("EXPLAIN (FORMAT JSON) {stmt}"),
+                    m_params.as_slice(),
+                )
+                .await?;
+            return Ok(row.get::<_, PlanText>(0).0);
+        }
+
+        client.batch_execute("BEGIN").await?;
+        let row = client
+            .query_one(
+                &format!("EXPLAIN (ANALYZE, FORMAT JSON) {stmt}"),
+                m_params.as_slice(),
+            )
+            .await;
+        client.batch_execute("ROLLBACK").await?;
+
+        Ok(row?.get::<_, PlanText>(0).0)
+    }
+
+    /// The text of a `json` plan, that isn't accepted by the [`String`] conversions
+    struct PlanText(String);
+
+    impl<'a> FromSql<'a> for PlanText {
+        fn from_sql(
+            _ty: &Type,
+            raw: &'a [u8],
+        ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            Ok(PlanText(String::from_utf8(raw.to_vec())?))
+        }
+
+        fn accepts(_ty: &Type) -> bool {
+            true
+        }
+    }
+
+    /// Retrieves the prepared statement for `stmt` from the statements cache of
+    /// the connection, preparing and caching it if it isn't there
+    async fn prepare(
+        conn: &PostgreSqlConnection,
+        stmt: &str,
+    ) -> Result<Statement, canyon_connection::tokio_postgres::Error> {
+        if let Some(statement) = conn.statements.get(stmt) {
+            return Ok(statement);
+        }
+
+        let statement = conn.client.prepare(stmt).await?;
+        conn.statements.insert(stmt.to_string(), statement.clone());
+        Ok(statement)
+    }
 }
 
 #[cfg(feature = "mssql")]
 mod sqlserver_query_launcher {
+    use crate::query_elements::sql_lexer::{rewrite_stmt, SqlDialect};
     use crate::rows::CanyonRows;
     use crate::{
         bounds::QueryParameter,
-        canyon_connection::{canyon_database_connector::DatabaseConnection, tiberius::Query},
+        canyon_connection::{
+            canyon_database_connector::DatabaseConnection,
+            tiberius::{xml::XmlData, Query, Row},
+        },
     };
 
-    pub async fn launch<'a, T, Z>(
+    pub async fn launch<'a, T>(
         db_conn: &mut DatabaseConnection,
         stmt: &mut String,
-        params: Z,
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-    where
-        Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
-    {
+        params: &[&'a dyn QueryParameter<'a>],
+    ) -> Result<CanyonRows<T>, Box<dyn std::error::Error + Send + Sync + 'static>> {
         // Re-generate de insert statement to adequate it to the SQL SERVER syntax to retrieve the PK value(s) after insert
         if stmt.contains("RETURNING") {
             let c = stmt.clone();
@@ -211,11 +681,8 @@ mod sqlserver_query_launcher {
             );
         }
 
-        let mut mssql_query = Query::new(stmt.to_owned().replace('$', "@P"));
-        params
-            .as_ref()
-            .iter()
-            .for_each(|param| mssql_query.bind(*param));
+        let mut mssql_query = Query::new(rewrite_stmt(stmt, SqlDialect::SqlServer).sql);
+        params.iter().for_each(|param| mssql_query.bind(*param));
 
         let _results = mssql_query
             .query(db_conn.sqlserver_connection().client)
@@ -227,17 +694,90 @@ mod sqlserver_query_launcher {
             _results.into_iter().flatten().collect(),
         ))
     }
+
+    pub async fn execute<'a>(
+        db_conn: &mut DatabaseConnection,
+        stmt: String,
+        params: &[&'a dyn QueryParameter<'a>],
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mut mssql_query = Query::new(rewrite_stmt(&stmt, SqlDialect::SqlServer).sql);
+        params.iter().for_each(|param| mssql_query.bind(*param));
+
+        Ok(mssql_query
+            .execute(db_conn.sqlserver_connection().client)
+            .await?
+            .total())
+    }
+
+    /// Retrieves the execution plan of the statement, without executing it, through
+    /// the `SHOWPLAN_XML` option of the session. When it's `analyze`d, the statement
+    /// is executed with the `STATISTICS XML` option instead, within a transaction that
+    /// is rolled back afterwards
+    pub async fn explain<'a>(
+        db_conn: &mut DatabaseConnection,
+        stmt: &str,
+        params: &[&'a dyn QueryParameter<'a>],
+        analyze: bool,
+    ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let (enable, disable) = if analyze {
+            (
+                "BEGIN TRAN; SET STATISTICS XML ON",
+                "SET STATISTICS XML OFF; ROLLBACK TRAN",
+            )
+        } else {
+            ("SET SHOWPLAN_XML ON", "SET SHOWPLAN_XML OFF")
+        };
+        let client = &mut *db_conn.sqlserver_connection().client;
+        client.simple_query(enable).await?.into_results().await?;
+
+        let mut mssql_query = Query::new(rewrite_stmt(stmt, SqlDialect::SqlServer).sql);
+        params.iter().for_each(|param| mssql_query.bind(*param));
+        // The plan comes after the rows of the statement when it's analyzed
+        let plan = match mssql_query.query(client).await {
+            Ok(stream) => stream.into_results().await.map(|results| {
+                results
+                    .iter()
+                    .rev()
+                    .filter_map(|rows| rows.first().and_then(plan_text))
+                    .find(|text| text.contains("ShowPlanXML"))
+            }),
+            Err(error) => Err(error),
+        };
+
+        // The session must leave the plan mode, whatever happened with the plan
+        client.simple_query(disable).await?.into_results().await?;
+
+        plan?.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "SqlServer didn't return any plan for the statement",
+            )
+            .into()
+        })
+    }
+
+    /// The showplan is returned as text by `SHOWPLAN_XML`, and as `xml` by `STATISTICS XML`
+    fn plan_text(row: &Row) -> Option<String> {
+        match row.try_get::<&str, usize>(0) {
+            Ok(text) => text.map(str::to_string),
+            Err(_) => row
+                .try_get::<&XmlData, usize>(0)
+                .ok()
+                .flatten()
+                .map(XmlData::to_string),
+        }
+    }
 }
 
 #[cfg(feature = "mysql")]
-mod mysql_query_launcher {
+pub(crate) mod mysql_query_launcher {
     use std::sync::Arc;
 
-    use mysql_async::prelude::Query;
-    use mysql_async::QueryWithParams;
-    use mysql_async::Value;
+    use mysql_async::prelude::Queryable;
+    use mysql_async::{Conn, Statement, TxOpts, Value};
 
     use canyon_connection::canyon_database_connector::DatabaseConnection;
+    use canyon_connection::statement_cache::PooledStatementCache;
 
     use crate::bounds::QueryParameter;
     use crate::rows::CanyonRows;
@@ -245,24 +785,21 @@ mod mysql_query_launcher {
     use mysql_common::constants::ColumnType;
     use mysql_common::row;
 
-    use super::reorder_params;
-    use crate::crud::{DETECT_PARAMS_IN_QUERY, DETECT_QUOTE_IN_QUERY};
-    use regex::Regex;
+    use crate::batch::{max_params, primary_keys_condition};
+    use crate::query_elements::sql_lexer::{rewrite_stmt, RewrittenStmt, SqlDialect};
 
     pub async fn launch<'a, T>(
         db_conn: &DatabaseConnection,
         stmt: String,
         params: &'a [&'_ dyn QueryParameter<'_>],
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-        let mysql_connection = db_conn.mysql_connection().client.get_conn().await?;
-
-        let stmt_with_escape_characters = regex::escape(&stmt);
-        let query_string =
-            Regex::new(DETECT_PARAMS_IN_QUERY)?.replace_all(&stmt_with_escape_characters, "?");
+    ) -> Result<CanyonRows<T>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mysql_connection = db_conn.mysql_connection();
+        let mut conn = mysql_connection.client.get_conn().await?;
 
-        let mut query_string = Regex::new(DETECT_QUOTE_IN_QUERY)?
-            .replace_all(&query_string, "")
-            .to_string();
+        let RewrittenStmt {
+            sql: mut query_string,
+            params_order,
+        } = rewrite_stmt(&stmt, SqlDialect::MySql);
 
         let mut is_insert = false;
         if let Some(index_start_clausule_returning) = query_string.find(" RETURNING") {
@@ -270,16 +807,18 @@ mod mysql_query_launcher {
             is_insert = true;
         }
 
-        let params_query: Vec<Value> =
-            reorder_params(&stmt, params, |f| f.as_mysql_param().to_value());
-
-        let query_with_params = QueryWithParams {
-            query: query_string,
-            params: params_query,
-        };
-
-        let mut query_result = query_with_params
-            .run(mysql_connection)
+        let params_query: Vec<Value> = reorder_params(&params_order, params);
+
+        let conn_id = conn.id();
+        let statement = prepare(
+            &mysql_connection.statements,
+            conn_id,
+            &mut conn,
+            query_string,
+        )
+        .await?;
+        let mut query_result = conn
+            .exec_iter(statement, params_query)
             .await
             .expect("Error executing query in mysql");
 
@@ -302,30 +841,167 @@ mod mysql_query_launcher {
 
         Ok(CanyonRows::MySQL(result_rows))
     }
-}
 
-#[cfg(feature = "mysql")]
-fn reorder_params<T>(
-    stmt: &str,
-    params: &[&'_ dyn QueryParameter<'_>],
-    fn_parser: impl Fn(&&dyn QueryParameter<'_>) -> T,
-) -> Vec<T> {
-    let mut ordered_params = vec![];
-    let rg = regex::Regex::new(DETECT_PARAMS_IN_QUERY)
-        .expect("Error create regex with detect params pattern expression");
-
-    for positional_param in rg.find_iter(stmt) {
-        let pp: &str = positional_param.as_str();
-        let pp_index = pp[1..] // param $1 -> get 1
-            .parse::<usize>()
-            .expect("Error parse mapped parameter to usized.")
-            - 1;
-
-        let element = params
-            .get(pp_index)
-            .expect("Error obtaining the element of the mapping against parameters.");
-        ordered_params.push(fn_parser(element));
+    pub async fn execute<'a>(
+        db_conn: &DatabaseConnection,
+        stmt: String,
+        params: &'a [&'_ dyn QueryParameter<'_>],
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mysql_connection = db_conn.mysql_connection();
+        let mut conn = mysql_connection.client.get_conn().await?;
+        execute_on(&mysql_connection.statements, &mut conn, stmt, params).await
+    }
+
+    /// Same as [`execute`], but over the given connection, instead of one of the pool
+    pub async fn execute_on<'a>(
+        statements: &PooledStatementCache<Statement>,
+        conn: &mut Conn,
+        stmt: String,
+        params: &'a [&'_ dyn QueryParameter<'_>],
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let stmt = rewrite_stmt(&stmt, SqlDialect::MySql);
+        let params_query: Vec<Value> = reorder_params(&stmt.params_order, params);
+
+        let conn_id = conn.id();
+        let statement = prepare(statements, conn_id, conn, stmt.sql).await?;
+        conn.exec_drop(statement, params_query).await?;
+
+        Ok(conn.affected_rows())
+    }
+
+    /// Launches an *UPDATE* or *DELETE* statement (`mutation_stmt`) within a transaction,
+    /// along with the `select_stmt` that locks and retrieves the rows matched by it before
+    /// the mutation, given that `MySQL` does not support any kind of `RETURNING` clause.
+    ///
+    /// When the `updated_primary_key` columns are given, the `select_stmt` only retrieves
+    /// them, and the rows with those primary keys are selected again from the `table`
+    /// after the mutation, in order to retrieve them with their new values
+    pub async fn launch_returning<'a, T>(
+        db_conn: &DatabaseConnection,
+        table: &str,
+        select_stmt: String,
+        mutation_stmt: String,
+        params: &'a [&'_ dyn QueryParameter<'_>],
+        updated_primary_key: Option<&[String]>,
+    ) -> Result<CanyonRows<T>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mysql_connection = db_conn.mysql_connection();
+        let mut conn = mysql_connection.client.get_conn().await?;
+        let conn_id = conn.id();
+        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+
+        let select_stmt = rewrite_stmt(&select_stmt, SqlDialect::MySql);
+        let mutation_stmt = rewrite_stmt(&mutation_stmt, SqlDialect::MySql);
+        let select_params: Vec<Value> = reorder_params(&select_stmt.params_order, params);
+        let mutation_params: Vec<Value> = reorder_params(&mutation_stmt.params_order, params);
+
+        let statements = &mysql_connection.statements;
+        let select = prepare(statements, conn_id, &mut tx, select_stmt.sql).await?;
+        let mutation = prepare(statements, conn_id, &mut tx, mutation_stmt.sql).await?;
+
+        let selected_rows = tx.exec::<Row, _, _>(select, select_params).await?;
+        tx.exec_drop(mutation, mutation_params).await?;
+
+        let result_rows = match updated_primary_key {
+            Some(primary_key) => {
+                let columns = primary_key.iter().map(String::as_str).collect::<Vec<_>>();
+                let chunk_len = max_params(SqlDialect::MySql) / columns.len().max(1);
+                let mut updated_rows = Vec::with_capacity(selected_rows.len());
+                // The statements depends on the number of keys, so they aren't cached
+                for keys in selected_rows.chunks(chunk_len) {
+                    let reselect_stmt = format!(
+                        "SELECT * FROM {table} WHERE {}",
+                        primary_keys_condition(&columns, keys.len())
+                    );
+                    let keys_params = keys
+                        .iter()
+                        .flat_map(|key| key.clone().unwrap())
+                        .collect::<Vec<Value>>();
+                    updated_rows.extend(
+                        tx.exec::<Row, _, _>(
+                            rewrite_stmt(&reselect_stmt, SqlDialect::MySql).sql,
+                            keys_params,
+                        )
+                        .await?,
+                    );
+                }
+                updated_rows
+            }
+            None => selected_rows,
+        };
+        tx.commit().await?;
+
+        Ok(CanyonRows::MySQL(result_rows))
+    }
+
+    /// Retrieves the prepared statement for `sql` from the statements cache of the
+    /// connection `conn_id` of the pool, preparing and caching it on `conn` if it
+    /// isn't there. The statement evicted from the cache is closed on the server
+    async fn prepare<Q: Queryable>(
+        statements: &PooledStatementCache<Statement>,
+        conn_id: u32,
+        conn: &mut Q,
+        sql: String,
+    ) -> Result<Statement, mysql_async::Error> {
+        if let Some(statement) = statements.get(conn_id, &sql) {
+            return Ok(statement);
+        }
+
+        let statement = conn.prep(&sql).await?;
+        if let Some(evicted) = statements.insert(conn_id, sql, statement.clone()) {
+            conn.close(evicted).await?;
+        }
+        Ok(statement)
+    }
+
+    /// Retrieves the execution plan of the statement, in `JSON`. When it's `analyze`d,
+    /// the statement is executed within a transaction that is rolled back afterwards,
+    /// and the plan is retrieved as the tree of `EXPLAIN ANALYZE`
+    pub async fn explain(
+        db_conn: &DatabaseConnection,
+        stmt: &str,
+        params: &[&'_ dyn QueryParameter<'_>],
+        analyze: bool,
+    ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let mut conn = db_conn.mysql_connection().client.get_conn().await?;
+
+        let mut stmt = rewrite_stmt(stmt, SqlDialect::MySql);
+        if let Some(index_start_clausule_returning) = stmt.sql.find(" RETURNING") {
+            stmt.sql.truncate(index_start_clausule_returning);
+        }
+        let params_query: Vec<Value> = reorder_params(&stmt.params_order, params);
+
+        let plan: Option<String> = if analyze {
+            let mut tx = conn.start_transaction(TxOpts::default()).await?;
+            let plan = tx
+                .exec_first(format!("EXPLAIN ANALYZE {}", stmt.sql), params_query)
+                .await;
+            tx.rollback().await?;
+            plan?
+        } else {
+            conn.exec_first(format!("EXPLAIN FORMAT=JSON {}", stmt.sql), params_query)
+                .await?
+        };
+        plan.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "MySQL didn't return any plan for the statement",
+            )
+            .into()
+        })
     }
 
-    ordered_params
+    /// Retrieves the values of the parameters in the order that the `?` placeholders
+    /// of the rewritten statement requires them
+    fn reorder_params(params_order: &[usize], params: &[&'_ dyn QueryParameter<'_>]) -> Vec<Value> {
+        params_order
+            .iter()
+            .map(|idx| {
+                params
+                    .get(*idx)
+                    .expect("Error obtaining the element of the mapping against parameters.")
+                    .as_mysql_param()
+                    .to_value()
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,183 @@
+//! Support for the fieldless Rust enums as the type of the columns.
+//!
+//! The [`CanyonEnum`] trait is implemented with the `#[derive(CanyonEnum)]` macro, which also
+//! implements [`crate::types::CanyonType`] for the enum. Depending on its [`EnumStorage`], the
+//! enum is stored as the label of the variant, as its discriminant or, on `PostgreSQL`, as a
+//! native enumerated type created by the migrations.
+//!
+//! ```ignore
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, CanyonEnum)]
+//! #[canyon(rename_all = "lowercase")]
+//! pub enum Role {
+//!     Top,
+//!     Jungle,
+//!     Mid,
+//!     #[canyon(rename = "bottom")]
+//!     Adc,
+//!     Support,
+//! }
+//! ```
+use std::{borrow::Cow, error::Error, fmt::Debug};
+
+use crate::mapper::FromColumn;
+
+/// How the enums are stored in the database columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumStorage {
+    /// The label of the variant, as text. It's the default one
+    Text,
+    /// The discriminant of the variant, as an integer
+    Integer,
+    /// The label of the variant, as a value of the `PostgreSQL` enumerated type with the
+    /// given name. The rest of the databases stores the label as text
+    Native(&'static str),
+}
+
+/// The label of a variant of a [`CanyonEnum`], as it's written in the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumLabel(pub Cow<'static, str>);
+
+/// A fieldless enum that can be used as the type of the fields of the entities.
+///
+/// Prefer the `#[derive(CanyonEnum)]` macro over implementing it by hand, given that the
+/// macro implements the [`crate::types::CanyonType`] of the enum too
+pub trait CanyonEnum: Debug + Send + Sync + 'static {
+    /// How the enum is stored in the database
+    const STORAGE: EnumStorage;
+    /// The labels of the variants, in declaration order
+    const LABELS: &'static [EnumLabel];
+    /// The discriminants of the variants, in declaration order
+    const DISCRIMINANTS: &'static [i32];
+
+    /// The position of the variant in the declaration of the enum
+    fn index(&self) -> usize;
+
+    /// The variant declared at the given position
+    fn from_index(index: usize) -> Self;
+
+    /// The label of the variant
+    fn label(&self) -> &'static str {
+        &Self::LABELS[self.index()].0
+    }
+
+    /// The discriminant of the variant
+    fn discriminant(&self) -> i32 {
+        Self::DISCRIMINANTS[self.index()]
+    }
+
+    /// The variant with the given label, if any
+    fn from_label(label: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::LABELS
+            .iter()
+            .position(|known| known.0 == label)
+            .map(Self::from_index)
+    }
+
+    /// The variant with the given discriminant, if any
+    fn from_discriminant(discriminant: i64) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::DISCRIMINANTS
+            .iter()
+            .position(|known| i64::from(*known) == discriminant)
+            .map(Self::from_index)
+    }
+}
+
+/// Builds the `T` enum from the label read from the database. Used by the `CanyonType`
+/// implementations of the `CanyonEnum` derive
+#[doc(hidden)]
+pub fn from_label_repr<T: CanyonEnum>(label: EnumLabel) -> Result<T, Box<dyn Error + Send + Sync>> {
+    T::from_label(&label.0).ok_or_else(|| unknown_value::<T>(label.0).into())
+}
+
+/// Builds the `T` enum from the discriminant read from the database. Used by the
+/// `CanyonType` implementations of the `CanyonEnum` derive
+#[doc(hidden)]
+pub fn from_discriminant_repr<T: CanyonEnum>(
+    discriminant: i32,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    T::from_discriminant(i64::from(discriminant))
+        .ok_or_else(|| unknown_value::<T>(discriminant).into())
+}
+
+fn unknown_value<T>(value: impl std::fmt::Display) -> String {
+    format!(
+        "`{value}` isn't a value of the `{}` enum",
+        std::any::type_name::<T>()
+    )
+}
+
+/// `SqlServer` and `MySQL` stores the labels as text
+impl FromColumn for EnumLabel {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &canyon_connection::tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        Ok(row.try_get::<_, Option<EnumLabel>>(column)?)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &canyon_connection::tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        Ok(String::from_sqlserver(row, column)?.map(|label| EnumLabel(Cow::Owned(label))))
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &canyon_connection::mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        Ok(String::from_mysql(row, column)?.map(|label| EnumLabel(Cow::Owned(label))))
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use super::EnumLabel;
+    use bytes::BytesMut;
+    use canyon_connection::tokio_postgres::types::{
+        to_sql_checked, FromSql, IsNull, Kind, ToSql, Type,
+    };
+    use std::{borrow::Cow, error::Error};
+
+    /// The values of the native enumerated types are received as their labels
+    impl<'a> FromSql<'a> for EnumLabel {
+        fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            Ok(EnumLabel(Cow::Owned(std::str::from_utf8(raw)?.to_owned())))
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            matches!(ty.kind(), Kind::Enum(_)) || <&str as FromSql>::accepts(ty)
+        }
+    }
+
+    impl ToSql for EnumLabel {
+        fn to_sql(
+            &self,
+            _ty: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            out.extend_from_slice(self.0.as_bytes());
+            Ok(IsNull::No)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            matches!(ty.kind(), Kind::Enum(_)) || <&str as ToSql>::accepts(ty)
+        }
+
+        to_sql_checked!();
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<EnumLabel> for mysql_common::Value {
+    fn from(label: EnumLabel) -> Self {
+        mysql_common::Value::Bytes(label.0.into_owned().into_bytes())
+    }
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+
+/// Lifecycle hooks for the entities declared with the `hooks` argument of the
+/// `canyon_entity` macro, like `#[canyon_entity(hooks)]`.
+///
+/// The CRUD operations generated for those entities calls the hooks around the
+/// statements that they launch against the database. Every hook has a default
+/// implementation that does nothing, so only the required ones must be implemented.
+///
+/// An error returned by any of the hooks aborts the operation, being returned
+/// to the caller. Note that when an `after_*` hook fails, the statement has
+/// already been executed.
+///
+/// > Note: the queries generated with the *QueryBuilder* family doesn't call the hooks
+#[async_trait]
+pub trait CanyonHooks: Sync + Send {
+    /// Called before inserting the entity, so it can still be modified
+    async fn before_insert(&mut self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called after inserting the entity, once its primary key has been set
+    async fn after_insert(&mut self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called before updating the record of the entity
+    async fn before_update(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called after updating the record of the entity
+    async fn after_update(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called before deleting the record of the entity
+    async fn before_delete(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called after deleting the record of the entity
+    async fn after_delete(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called over every entity retrieved by the `find_*` and `search_*` operations
+    async fn after_load(&mut self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+}
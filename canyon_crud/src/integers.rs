@@ -0,0 +1,302 @@
+//! Support for the integer types without a matching column on every database: `i8`, `u8`,
+//! `u16`, `u32` and `u64`.
+//!
+//! `PostgreSQL` and `SqlServer` has no unsigned columns, so the values are widened to the
+//! smallest signed column that holds all of them: `smallint` for `i8` (and for `u8` on
+//! `PostgreSQL`, which neither has `tinyint`), `integer` for `u16` and `bigint` for `u32`.
+//! The `u64` are stored as `bigint` too, so the values above [`i64::MAX`] are rejected
+//! when they're bound. `MySQL` has signed and unsigned columns for all of them.
+//!
+//! The values read from the rows are checked against the range of the Rust type, failing
+//! instead of wrapping when the column holds a value out of it.
+use std::{error::Error, fmt::Debug};
+
+#[cfg(feature = "mysql")]
+use canyon_connection::mysql_async::{self, prelude::ToValue};
+#[cfg(feature = "mssql")]
+use canyon_connection::tiberius::{self, numeric::Numeric, ColumnData};
+#[cfg(feature = "postgres")]
+use canyon_connection::tokio_postgres::{
+    self,
+    types::{to_sql_checked, FromSql, IsNull, ToSql, Type},
+};
+
+use crate::bounds::{QueryParameter, SqlLiteral};
+#[cfg(feature = "mysql")]
+use crate::mapper::from_mysql_row;
+#[cfg(feature = "postgres")]
+use crate::mapper::from_postgres_row;
+#[cfg(feature = "mssql")]
+use crate::mapper::from_sqlserver_row;
+use crate::mapper::FromColumn;
+
+/// The integer types that are widened or range checked to be stored on every database
+trait Integer: Copy + Debug + Send + Sync + Into<i128> + TryFrom<i128> {
+    /// The value as a parameter of `SqlServer`, widened to a column that holds it
+    #[cfg(feature = "mssql")]
+    fn sqlserver_data(value: Option<Self>) -> ColumnData<'static>;
+}
+
+impl Integer for i8 {
+    #[cfg(feature = "mssql")]
+    fn sqlserver_data(value: Option<Self>) -> ColumnData<'static> {
+        ColumnData::I16(value.map(i16::from))
+    }
+}
+
+impl Integer for u8 {
+    #[cfg(feature = "mssql")]
+    fn sqlserver_data(value: Option<Self>) -> ColumnData<'static> {
+        ColumnData::U8(value)
+    }
+}
+
+impl Integer for u16 {
+    #[cfg(feature = "mssql")]
+    fn sqlserver_data(value: Option<Self>) -> ColumnData<'static> {
+        ColumnData::I32(value.map(i32::from))
+    }
+}
+
+impl Integer for u32 {
+    #[cfg(feature = "mssql")]
+    fn sqlserver_data(value: Option<Self>) -> ColumnData<'static> {
+        ColumnData::I64(value.map(i64::from))
+    }
+}
+
+/// The values above [`i64::MAX`] are sent as numerics, so `SqlServer` reports the
+/// overflow of the `bigint` column instead of storing a wrapped value
+impl Integer for u64 {
+    #[cfg(feature = "mssql")]
+    fn sqlserver_data(value: Option<Self>) -> ColumnData<'static> {
+        match value {
+            Some(value) if i64::try_from(value).is_err() => {
+                ColumnData::Numeric(Some(Numeric::new_with_scale(value.into(), 0)))
+            }
+            value => ColumnData::I64(value.map(|value| value as i64)),
+        }
+    }
+}
+
+/// The values that holds an [`Integer`], as the query parameters does
+trait IntegerParameter: Debug + Sync {
+    type Integer: Integer;
+
+    fn value(&self) -> Option<Self::Integer>;
+
+    fn integer(&self) -> Option<i128> {
+        self.value().map(Into::into)
+    }
+}
+
+/// A parameter of `PostgreSQL` bound to any of its integer columns, as long as the value
+/// is in the range of the column
+#[cfg(feature = "postgres")]
+#[derive(Debug)]
+#[repr(transparent)]
+struct PgInteger<T>(T);
+
+#[cfg(feature = "postgres")]
+impl<T> PgInteger<T> {
+    fn from_ref(value: &T) -> &Self {
+        // SAFETY: `PgInteger` is a transparent wrapper over the value, so both has the
+        // same layout, and the reference keeps the lifetime of the original one
+        unsafe { &*(value as *const T).cast::<Self>() }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<T: IntegerParameter> ToSql for PgInteger<T> {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let Some(value) = self.0.integer() else {
+            return Ok(IsNull::Yes);
+        };
+        let out_of_range =
+            |_| format!("The value `{value}` is out of the range of a `{ty}` column");
+
+        match *ty {
+            Type::INT2 => i16::try_from(value).map_err(out_of_range)?.to_sql(ty, out),
+            Type::INT4 => i32::try_from(value).map_err(out_of_range)?.to_sql(ty, out),
+            _ => i64::try_from(value).map_err(out_of_range)?.to_sql(ty, out),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INT2 | Type::INT4 | Type::INT8)
+    }
+
+    to_sql_checked!();
+}
+
+/// Reads the value of any of the integer columns of `PostgreSQL`
+#[cfg(feature = "postgres")]
+impl<'a> FromSql<'a> for PgInteger<i64> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match *ty {
+            Type::INT2 => i16::from_sql(ty, raw).map(i64::from),
+            Type::INT4 => i32::from_sql(ty, raw).map(i64::from),
+            _ => i64::from_sql(ty, raw),
+        }
+        .map(PgInteger)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INT2 | Type::INT4 | Type::INT8)
+    }
+}
+
+/// Reads the value of any of the integer columns of `SqlServer`
+#[cfg(feature = "mssql")]
+struct SqlServerInteger(i128);
+
+#[cfg(feature = "mssql")]
+impl<'a> tiberius::FromSql<'a> for SqlServerInteger {
+    fn from_sql(value: &'a ColumnData<'static>) -> tiberius::Result<Option<Self>> {
+        let value = match value {
+            ColumnData::U8(value) => value.map(i128::from),
+            ColumnData::I16(value) => value.map(i128::from),
+            ColumnData::I32(value) => value.map(i128::from),
+            ColumnData::I64(value) => value.map(i128::from),
+            ColumnData::Numeric(Some(numeric)) if numeric.scale() == 0 => Some(numeric.value()),
+            ColumnData::Numeric(None) => None,
+            value => {
+                return Err(tiberius::error::Error::Conversion(
+                    format!("Cannot interpret {value:?} as an integer").into(),
+                ))
+            }
+        };
+        Ok(value.map(SqlServerInteger))
+    }
+}
+
+/// Narrows the value read from the given column to the integer type, failing when it's
+/// out of its range
+fn in_range<T: Integer>(value: i128, column: &str) -> Result<T, Box<dyn Error + Send + Sync>> {
+    T::try_from(value).map_err(|_| {
+        format!(
+            "The value `{value}` of the `{column}` column is out of the range of `{}`",
+            std::any::type_name::<T>()
+        )
+        .into()
+    })
+}
+
+/// Writes the value as a literal. The `u64` above [`i64::MAX`] are only stored by
+/// `MySQL`, so they're the only ones written as unsigned literals
+fn integer_literal(value: Option<i128>) -> SqlLiteral<'static> {
+    match value {
+        None => SqlLiteral::Null,
+        Some(value) => match i64::try_from(value) {
+            Ok(value) => SqlLiteral::Int(value),
+            Err(_) => SqlLiteral::UInt(value as u64),
+        },
+    }
+}
+
+/// Implements [`QueryParameter`] for the integers, their references and their optional
+/// values, and [`FromColumn`] for the integers
+macro_rules! impl_integer {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl_integer!(@parameter $ty, $ty, |value| Some(*value));
+            impl_integer!(@parameter &$ty, $ty, |value| Some(**value));
+            impl_integer!(@parameter Option<$ty>, $ty, |value| *value);
+            impl_integer!(@parameter Option<&$ty>, $ty, |value| value.copied());
+
+            impl FromColumn for $ty {
+                #[cfg(feature = "postgres")]
+                fn from_postgres(
+                    row: &tokio_postgres::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    from_postgres_row::<PgInteger<i64>>(row, column)?
+                        .map(|PgInteger(value)| in_range(value.into(), column))
+                        .transpose()
+                }
+                #[cfg(feature = "mssql")]
+                fn from_sqlserver(
+                    row: &tiberius::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    from_sqlserver_row::<SqlServerInteger>(row, column)?
+                        .map(|SqlServerInteger(value)| in_range(value, column))
+                        .transpose()
+                }
+                #[cfg(feature = "mysql")]
+                fn from_mysql(
+                    row: &mysql_async::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    from_mysql_row::<i128>(row, column)?
+                        .map(|value| in_range(value, column))
+                        .transpose()
+                }
+            }
+        )*
+    };
+    (@parameter $param:ty, $integer:ty, |$value:ident| $body:expr) => {
+        impl IntegerParameter for $param {
+            type Integer = $integer;
+
+            fn value(&self) -> Option<$integer> {
+                let $value = self;
+                $body
+            }
+        }
+
+        impl<'a> QueryParameter<'a> for $param {
+            #[cfg(feature = "postgres")]
+            fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+                PgInteger::from_ref(self)
+            }
+            #[cfg(feature = "mssql")]
+            fn as_sqlserver_param(&self) -> ColumnData<'_> {
+                <$integer>::sqlserver_data(self.value())
+            }
+            #[cfg(feature = "mysql")]
+            fn as_mysql_param(&self) -> &dyn ToValue {
+                self
+            }
+            fn as_sql_literal(&self) -> SqlLiteral<'_> {
+                integer_literal(self.integer())
+            }
+        }
+    };
+}
+
+impl_integer!(i8, u8, u16, u32, u64);
+
+#[cfg(test)]
+mod integers_tests {
+    use super::*;
+
+    /// The values are narrowed to the Rust type only when they're in its range
+    #[test]
+    fn decoded_values_are_range_checked() {
+        assert_eq!(in_range::<u8>(255, "level").unwrap(), 255);
+        assert_eq!(in_range::<i8>(-128, "level").unwrap(), -128);
+        assert_eq!(in_range::<u64>(u64::MAX.into(), "views").unwrap(), u64::MAX);
+
+        let err = in_range::<u8>(-1, "level").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The value `-1` of the `level` column is out of the range of `u8`"
+        );
+        assert!(in_range::<u16>(70000, "level").is_err());
+        assert!(in_range::<u32>(i128::from(u32::MAX) + 1, "level").is_err());
+    }
+
+    /// The integers are written as literals, even the `u64` out of the range of `i64`
+    #[test]
+    fn integer_literals() {
+        assert_eq!(7_u8.as_sql_literal(), SqlLiteral::Int(7));
+        assert_eq!(Some(-7_i8).as_sql_literal(), SqlLiteral::Int(-7));
+        assert_eq!(None::<u32>.as_sql_literal(), SqlLiteral::Null);
+        assert_eq!(u64::MAX.as_sql_literal(), SqlLiteral::UInt(u64::MAX));
+    }
+}
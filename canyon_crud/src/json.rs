@@ -0,0 +1,231 @@
+//! Support for the columns that stores JSON documents.
+//!
+//! Any [`serde_json::Value`] can be used directly as the type of a field of an entity,
+//! while the [`Json`] wrapper allows to use any type that can be serialized and deserialized
+//! with `serde`, so the document is read back already typed.
+//!
+//! The documents are stored as `jsonb` on `PostgreSQL`, as `JSON` on `MySQL` and as text,
+//! in a `nvarchar(max)` column, on `SqlServer`.
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+};
+
+use crate::mapper::FromColumn;
+
+/// Wrapper over any `T` that is stored as a JSON document in the database
+///
+/// ```ignore
+/// #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// pub struct LeagueSettings {
+///     pub theme: String,
+///     pub max_teams: i32,
+/// }
+///
+/// #[canyon_entity]
+/// pub struct League {
+///     #[primary_key]
+///     id: i32,
+///     settings: Json<LeagueSettings>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    /// Takes the value out of the wrapper
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Serialize> Serialize for Json<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Json<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Writes the wrapped value as its JSON document
+impl<T: Serialize> Display for Json<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let document = serde_json::to_string(&self.0).map_err(|_| fmt::Error)?;
+        f.write_str(&document)
+    }
+}
+
+/// Writes the document of any serializable value. The serialization only fails for
+/// the maps without string keys, or for the custom `Serialize` implementations that
+/// reports an error, which are bugs on the type of the field
+pub(crate) fn to_document<T: Serialize + ?Sized>(value: &T) -> String {
+    serde_json::to_string(value)
+        .unwrap_or_else(|err| panic!("Failed to serialize the value as a JSON document: {err}"))
+}
+
+/// Decodes the JSON document stored as text in the given column of a `SqlServer` row,
+/// given that `SqlServer` hasn't a dedicated JSON type
+#[cfg(feature = "mssql")]
+fn from_sqlserver_document<T: serde::de::DeserializeOwned>(
+    row: &canyon_connection::tiberius::Row,
+    column: &str,
+) -> Result<Option<T>, Box<dyn std::error::Error + Send + Sync>> {
+    match row.try_get::<&str, &str>(column)? {
+        Some(document) => Ok(Some(serde_json::from_str(document)?)),
+        None => Ok(None),
+    }
+}
+
+impl FromColumn for serde_json::Value {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &canyon_connection::tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(row.try_get::<_, Option<Self>>(column)?)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &canyon_connection::tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error + Send + Sync>> {
+        from_sqlserver_document(row, column)
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &canyon_connection::mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error + Send + Sync>> {
+        match row.get_opt::<Option<Self>, _>(column) {
+            Some(document) => Ok(document?),
+            None => Err(format!("There's no `{column}` column on the row").into()),
+        }
+    }
+}
+
+impl<T: DeserializeOwned> FromColumn for Json<T> {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &canyon_connection::tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(row.try_get::<_, Option<Self>>(column)?)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &canyon_connection::tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error + Send + Sync>> {
+        from_sqlserver_document(row, column)
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &canyon_connection::mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error + Send + Sync>> {
+        match row.get_opt::<Option<Self>, _>(column) {
+            Some(document) => Ok(document?),
+            None => Err(format!("There's no `{column}` column on the row").into()),
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use super::Json;
+    use bytes::BytesMut;
+    use canyon_connection::tokio_postgres::types::{
+        self, accepts, to_sql_checked, FromSql, IsNull, ToSql, Type,
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::{error::Error, fmt::Debug};
+
+    impl<'a, T: DeserializeOwned> FromSql<'a> for Json<T> {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            types::Json::<T>::from_sql(ty, raw).map(|document| Json(document.0))
+        }
+
+        accepts!(JSON, JSONB);
+    }
+
+    impl<T: Serialize + Debug> ToSql for Json<T> {
+        fn to_sql(
+            &self,
+            ty: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            types::Json(&self.0).to_sql(ty, out)
+        }
+
+        accepts!(JSON, JSONB);
+        to_sql_checked!();
+    }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql {
+    use super::{to_document, Json};
+    use mysql_common::{
+        value::convert::{FromValue, FromValueError},
+        Value,
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+
+    impl<T: Serialize> From<Json<T>> for Value {
+        fn from(document: Json<T>) -> Self {
+            Value::Bytes(to_document(&document.0).into_bytes())
+        }
+    }
+
+    /// Intermediate result of parsing a JSON document from a `MySQL` value
+    pub struct JsonIr<T>(T);
+
+    impl<T: DeserializeOwned> TryFrom<Value> for JsonIr<T> {
+        type Error = FromValueError;
+
+        fn try_from(value: Value) -> Result<Self, Self::Error> {
+            match value {
+                Value::Bytes(ref bytes) => match serde_json::from_slice(bytes) {
+                    Ok(document) => Ok(JsonIr(document)),
+                    Err(_) => Err(FromValueError(value)),
+                },
+                other => Err(FromValueError(other)),
+            }
+        }
+    }
+
+    impl<T> From<JsonIr<T>> for Json<T> {
+        fn from(ir: JsonIr<T>) -> Self {
+            Json(ir.0)
+        }
+    }
+
+    impl<T: DeserializeOwned> FromValue for Json<T> {
+        type Intermediate = JsonIr<T>;
+    }
+}
@@ -1,13 +1,32 @@
 pub extern crate async_trait;
 extern crate canyon_connection;
 
+pub mod arrays;
+pub mod batch;
+pub mod blob;
 pub mod bounds;
 pub mod crud;
+pub mod enums;
+pub mod hooks;
+pub mod integers;
+#[cfg(feature = "json")]
+pub mod json;
 pub mod mapper;
+pub mod metrics;
 pub mod query_elements;
+mod query_log;
 pub mod rows;
+pub mod slow_query;
+pub mod types;
+pub mod validation;
 
 pub use query_elements::operators::*;
 
 pub use canyon_connection::{canyon_database_connector::DatabaseType, datasources::*};
 pub use chrono;
+#[cfg(feature = "decimal")]
+pub use rust_decimal;
+#[cfg(feature = "json")]
+pub use serde_json;
+#[cfg(feature = "uuid")]
+pub use uuid;
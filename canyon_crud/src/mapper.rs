@@ -4,17 +4,352 @@ use canyon_connection::mysql_async;
 use canyon_connection::tiberius;
 #[cfg(feature = "postgres")]
 use canyon_connection::tokio_postgres;
+#[cfg(feature = "mysql")]
+use chrono::TimeZone;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+use std::error::Error;
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
 
 use crate::crud::Transaction;
+use crate::types::CanyonType;
 
 /// Declares functions that takes care to deserialize data incoming
 /// from some supported database in Canyon-SQL into a user's defined
-/// type `T`
+/// type `T`, failing when some column can't be decoded into its field
 pub trait RowMapper<T: Transaction<T>>: Sized {
     #[cfg(feature = "postgres")]
-    fn deserialize_postgresql(row: &tokio_postgres::Row) -> T;
+    fn deserialize_postgresql(row: &tokio_postgres::Row)
+        -> Result<T, Box<dyn Error + Send + Sync>>;
+    #[cfg(feature = "mssql")]
+    fn deserialize_sqlserver(row: &tiberius::Row) -> Result<T, Box<dyn Error + Send + Sync>>;
+    #[cfg(feature = "mysql")]
+    fn deserialize_mysql(row: &mysql_async::Row) -> Result<T, Box<dyn Error + Send + Sync>>;
+}
+
+/// Decodes the value of a column of the rows of every supported database.
+///
+/// It's implemented for all the types supported by Canyon, for their [`Option`], and for
+/// every [`CanyonType`]. The methods return [`None`] when the column is `NULL`
+pub trait FromColumn: Sized {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>>;
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>>;
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>>;
+}
+
+/// Reads the given column of a `PostgreSQL` row. Used by the [`RowMapper`] implementations
+/// of the `CanyonMapper` derive
+#[cfg(feature = "postgres")]
+#[doc(hidden)]
+pub fn postgres_column<T: FromColumn>(
+    row: &tokio_postgres::Row,
+    column: &str,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    required(column, T::from_postgres(row, column))
+}
+
+/// Reads the given column of a `SqlServer` row. Used by the [`RowMapper`] implementations
+/// of the `CanyonMapper` derive
+#[cfg(feature = "mssql")]
+#[doc(hidden)]
+pub fn sqlserver_column<T: FromColumn>(
+    row: &tiberius::Row,
+    column: &str,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    required(column, T::from_sqlserver(row, column))
+}
+
+/// Reads the given column of a `MySQL` row. Used by the [`RowMapper`] implementations
+/// of the `CanyonMapper` derive
+#[cfg(feature = "mysql")]
+#[doc(hidden)]
+pub fn mysql_column<T: FromColumn>(
+    row: &mysql_async::Row,
+    column: &str,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    required(column, T::from_mysql(row, column))
+}
+
+/// The fields that aren't an [`Option`] can't be filled with a `NULL` column
+#[cfg(any(feature = "postgres", feature = "mssql", feature = "mysql"))]
+fn required<T>(
+    column: &str,
+    value: Result<Option<T>, Box<dyn Error + Send + Sync>>,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    match value {
+        Ok(Some(value)) => Ok(value),
+        Ok(None) => {
+            Err(format!("Failed to retrieve the `{column}` field: the column is NULL").into())
+        }
+        Err(err) => Err(format!("Failed to retrieve the `{column}` field: {err}").into()),
+    }
+}
+
+#[cfg(feature = "postgres")]
+pub(crate) fn from_postgres_row<'a, T: tokio_postgres::types::FromSql<'a>>(
+    row: &'a tokio_postgres::Row,
+    column: &str,
+) -> Result<Option<T>, Box<dyn Error + Send + Sync>> {
+    Ok(row.try_get::<_, Option<T>>(column)?)
+}
+
+#[cfg(feature = "mssql")]
+pub(crate) fn from_sqlserver_row<'a, T: tiberius::FromSql<'a>>(
+    row: &'a tiberius::Row,
+    column: &str,
+) -> Result<Option<T>, Box<dyn Error + Send + Sync>> {
+    Ok(row.try_get::<T, _>(column)?)
+}
+
+#[cfg(feature = "mysql")]
+pub(crate) fn from_mysql_row<T: mysql_async::prelude::FromValue>(
+    row: &mysql_async::Row,
+    column: &str,
+) -> Result<Option<T>, Box<dyn Error + Send + Sync>> {
+    match row.get_opt::<Option<T>, _>(column) {
+        Some(value) => Ok(value?),
+        None => Err(format!("There's no `{column}` column on the row").into()),
+    }
+}
+
+/// Implements [`FromColumn`] for the types that the three database clients already decodes
+macro_rules! impl_from_column {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FromColumn for $ty {
+                #[cfg(feature = "postgres")]
+                fn from_postgres(
+                    row: &tokio_postgres::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    from_postgres_row(row, column)
+                }
+                #[cfg(feature = "mssql")]
+                fn from_sqlserver(
+                    row: &tiberius::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    from_sqlserver_row(row, column)
+                }
+                #[cfg(feature = "mysql")]
+                fn from_mysql(
+                    row: &mysql_async::Row,
+                    column: &str,
+                ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+                    from_mysql_row(row, column)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_column!(
+    bool,
+    i16,
+    i32,
+    i64,
+    f32,
+    f64,
+    NaiveDate,
+    NaiveTime,
+    NaiveDateTime
+);
+#[cfg(feature = "uuid")]
+impl_from_column!(Uuid);
+#[cfg(feature = "decimal")]
+impl_from_column!(Decimal);
+
+/// `SqlServer` only lends the text of the columns
+impl FromColumn for String {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_postgres_row(row, column)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        Ok(from_sqlserver_row::<&str>(row, column)?.map(str::to_owned))
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_mysql_row(row, column)
+    }
+}
+
+/// `SqlServer` only lends the bytes of the columns
+impl FromColumn for Vec<u8> {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_postgres_row(row, column)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        Ok(from_sqlserver_row::<&[u8]>(row, column)?.map(<[u8]>::to_vec))
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_mysql_row(row, column)
+    }
+}
+
+/// `MySQL` has no time zones, so the date times are read as UTC ones
+impl FromColumn for DateTime<Utc> {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_postgres_row(row, column)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_sqlserver_row(row, column)
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        Ok(from_mysql_row::<NaiveDateTime>(row, column)?
+            .map(|date_time| Utc.from_utc_datetime(&date_time)))
+    }
+}
+
+/// `MySQL` has no time zones, so the date times are read as UTC ones
+impl FromColumn for DateTime<FixedOffset> {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_postgres_row(row, column)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        from_sqlserver_row(row, column)
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        Ok(DateTime::<Utc>::from_mysql(row, column)?.map(Into::into))
+    }
+}
+
+/// `NULL` is read as [`None`], so the optional columns are never missing
+impl<T: FromColumn> FromColumn for Option<T> {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        T::from_postgres(row, column).map(Some)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(
+        row: &tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        T::from_sqlserver(row, column).map(Some)
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(
+        row: &mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        T::from_mysql(row, column).map(Some)
+    }
+}
+
+/// The custom types are decoded from their representation
+impl<T: CanyonType> FromColumn for T {
+    #[cfg(feature = "postgres")]
+    fn from_postgres(
+        row: &tokio_postgres::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        T::Repr::from_postgres(row, column)?
+            .map(T::from_repr)
+            .transpose()
+    }
     #[cfg(feature = "mssql")]
-    fn deserialize_sqlserver(row: &tiberius::Row) -> T;
+    fn from_sqlserver(
+        row: &tiberius::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        T::Repr::from_sqlserver(row, column)?
+            .map(T::from_repr)
+            .transpose()
+    }
     #[cfg(feature = "mysql")]
-    fn deserialize_mysql(row: &mysql_async::Row) -> T;
+    fn from_mysql(
+        row: &mysql_async::Row,
+        column: &str,
+    ) -> Result<Option<Self>, Box<dyn Error + Send + Sync>> {
+        T::Repr::from_mysql(row, column)?
+            .map(T::from_repr)
+            .transpose()
+    }
+}
+
+#[cfg(all(test, any(feature = "postgres", feature = "mssql", feature = "mysql")))]
+mod mapper_tests {
+    use super::*;
+
+    /// A `NULL` column or a value that can't be decoded fails the mapping of the row,
+    /// naming the field
+    #[test]
+    fn required_columns_are_reported_as_errors() {
+        assert_eq!(required("id", Ok(Some(1))).unwrap(), 1);
+
+        let null = required::<i32>("id", Ok(None)).unwrap_err();
+        assert_eq!(
+            null.to_string(),
+            "Failed to retrieve the `id` field: the column is NULL"
+        );
+
+        let out_of_range = required::<i32>("id", Err("out of range".into())).unwrap_err();
+        assert_eq!(
+            out_of_range.to_string(),
+            "Failed to retrieve the `id` field: out of range"
+        );
+    }
 }
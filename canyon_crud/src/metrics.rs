@@ -0,0 +1,278 @@
+//! Metrics of the statements launched by Canyon, gathered per datasource and per
+//! operation of the entities.
+//!
+//! Every statement launched through the [`crate::crud::Transaction`] trait is accounted
+//! to its datasource, and, when it's launched by one of the generated CRUD operations,
+//! to the entity and the operation too. The accumulated values are retrieved with
+//! [`snapshot`], and are also exported through the facade of the `metrics` crate when
+//! the `metrics` feature is enabled.
+use std::{collections::BTreeMap, error::Error, future::Future, sync::Mutex, time::Duration};
+
+use canyon_connection::lazy_static::lazy_static;
+
+/// The upper bounds (in milliseconds) of the buckets of the [`Histogram`]s. The last
+/// bucket of every histogram holds the values greater than the last bound
+pub const HISTOGRAM_BUCKETS_MS: [u64; 11] = [1, 5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000];
+
+lazy_static! {
+    static ref METRICS: Mutex<MetricsSnapshot> = Mutex::new(MetricsSnapshot::default());
+}
+
+canyon_connection::tokio::task_local! {
+    /// The entity and the operation that are launching the statements of the task
+    static OPERATION: (&'static str, &'static str);
+}
+
+/// The distribution of a set of durations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    /// The number of values of every bucket, whose bounds are [`HISTOGRAM_BUCKETS_MS`]
+    pub buckets: Vec<u64>,
+    /// The number of recorded values
+    pub count: u64,
+    /// The sum of every recorded value
+    pub sum: Duration,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: vec![0; HISTOGRAM_BUCKETS_MS.len() + 1],
+            count: 0,
+            sum: Duration::ZERO,
+        }
+    }
+}
+
+impl Histogram {
+    fn record(&mut self, value: Duration) {
+        let bucket = HISTOGRAM_BUCKETS_MS
+            .iter()
+            .position(|bound| value <= Duration::from_millis(*bound))
+            .unwrap_or(HISTOGRAM_BUCKETS_MS.len());
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.sum += value;
+    }
+
+    /// The mean of the recorded values, if any
+    pub fn mean(&self) -> Option<Duration> {
+        u32::try_from(self.count)
+            .ok()
+            .filter(|count| *count > 0)
+            .map(|count| self.sum / count)
+    }
+}
+
+/// The metrics of a set of statements
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metrics {
+    /// The number of launched statements, including the failed ones
+    pub queries: u64,
+    /// The number of failed statements by the class of their error:
+    ///
+    /// * `sqlstate:XX`, with the class of the `SQLSTATE` reported by PostgreSQL or MySQL
+    /// * `server`, for the errors reported by SqlServer
+    /// * `connection` and `driver`, for the errors on the client side of the database
+    /// * `io`, for the errors raised by Canyon itself (e.g. a missing named parameter)
+    /// * `other`, for anything else
+    pub errors: BTreeMap<String, u64>,
+    /// The number of rows retrieved or affected by the statements
+    pub rows: u64,
+    /// The time spent by the statements
+    pub latency: Histogram,
+    /// The time spent waiting for the connection of the datasource
+    pub connection_wait: Histogram,
+}
+
+impl Metrics {
+    /// The number of failed statements, whatever their error was
+    pub fn error_count(&self) -> u64 {
+        self.errors.values().sum()
+    }
+
+    fn record(&mut self, statement: &StatementMetrics<'_>) {
+        self.queries += 1;
+        match &statement.error_class {
+            Some(class) => *self.errors.entry(class.clone()).or_default() += 1,
+            None => self.rows += statement.rows,
+        }
+        self.latency.record(statement.duration);
+        self.connection_wait.record(statement.connection_wait);
+    }
+}
+
+/// Identifies an operation of an entity launched against a datasource
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OperationKey {
+    pub datasource: String,
+    pub entity: String,
+    /// The name of the CRUD operation, without the `_datasource` suffix
+    pub operation: String,
+}
+
+/// The metrics gathered since the program started, or since the last [`reset`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// The metrics of every statement, by the name of its datasource
+    pub datasources: BTreeMap<String, Metrics>,
+    /// The metrics of the statements launched by the CRUD operations of the entities
+    pub operations: BTreeMap<OperationKey, Metrics>,
+}
+
+/// Retrieves a copy of the current metrics
+pub fn snapshot() -> MetricsSnapshot {
+    METRICS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Discards every metric gathered until now
+pub fn reset() {
+    *METRICS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = MetricsSnapshot::default();
+}
+
+/// Launches the future of an operation of an entity, accounting the statements
+/// launched by it to the operation. Used by the generated CRUD operations.
+#[doc(hidden)]
+pub async fn with_operation<F, R>(entity: &'static str, operation: &'static str, future: F) -> R
+where
+    F: Future<Output = R>,
+{
+    OPERATION.scope((entity, operation), future).await
+}
+
+/// The outcome of a single statement
+pub(crate) struct StatementMetrics<'a> {
+    pub datasource: &'a str,
+    pub rows: u64,
+    pub duration: Duration,
+    pub connection_wait: Duration,
+    pub error_class: Option<String>,
+}
+
+/// Accounts the statement to its datasource and to the operation of the current task (if any)
+pub(crate) fn record(statement: StatementMetrics<'_>) {
+    let operation = OPERATION.try_with(|operation| *operation).ok();
+    #[cfg(feature = "metrics")]
+    export(&statement, operation);
+
+    let mut metrics = METRICS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    metrics
+        .datasources
+        .entry(statement.datasource.to_string())
+        .or_default()
+        .record(&statement);
+
+    if let Some((entity, operation)) = operation {
+        let key = OperationKey {
+            datasource: statement.datasource.to_string(),
+            entity: entity.to_string(),
+            operation: operation.to_string(),
+        };
+        metrics
+            .operations
+            .entry(key)
+            .or_default()
+            .record(&statement);
+    }
+}
+
+/// Classifies the error of a failed statement, as described in [`Metrics::errors`]
+pub(crate) fn error_class(error: &(dyn Error + Send + Sync + 'static)) -> String {
+    #[cfg(feature = "postgres")]
+    if let Some(error) = error.downcast_ref::<canyon_connection::tokio_postgres::Error>() {
+        return match error.code() {
+            Some(state) => format!("sqlstate:{}", &state.code()[..2]),
+            None if error.is_closed() => String::from("connection"),
+            None => String::from("driver"),
+        };
+    }
+    #[cfg(feature = "mssql")]
+    if let Some(error) = error.downcast_ref::<canyon_connection::tiberius::error::Error>() {
+        return String::from(match error {
+            canyon_connection::tiberius::error::Error::Server(_) => "server",
+            canyon_connection::tiberius::error::Error::Io { .. } => "connection",
+            _ => "driver",
+        });
+    }
+    #[cfg(feature = "mysql")]
+    if let Some(error) = error.downcast_ref::<canyon_connection::mysql_async::Error>() {
+        return match error {
+            canyon_connection::mysql_async::Error::Server(error) => {
+                format!("sqlstate:{}", error.state.get(..2).unwrap_or_default())
+            }
+            canyon_connection::mysql_async::Error::Io(_) => String::from("connection"),
+            _ => String::from("driver"),
+        };
+    }
+    if error.is::<std::io::Error>() {
+        return String::from("io");
+    }
+
+    String::from("other")
+}
+
+/// Exports the statement through the facade of the `metrics` crate
+#[cfg(feature = "metrics")]
+fn export(statement: &StatementMetrics<'_>, operation: Option<(&'static str, &'static str)>) {
+    use ::metrics::{counter, histogram, Label};
+
+    let mut labels = vec![Label::new("datasource", statement.datasource.to_string())];
+    if let Some((entity, operation)) = operation {
+        labels.push(Label::new("entity", entity));
+        labels.push(Label::new("operation", operation));
+    }
+
+    counter!("canyon_sql_queries_total", labels.clone()).increment(1);
+    match &statement.error_class {
+        Some(class) => {
+            let mut error_labels = labels.clone();
+            error_labels.push(Label::new("class", class.clone()));
+            counter!("canyon_sql_errors_total", error_labels).increment(1);
+        }
+        None => counter!("canyon_sql_rows_total", labels.clone()).increment(statement.rows),
+    }
+    histogram!("canyon_sql_query_duration_seconds", labels.clone())
+        .record(statement.duration.as_secs_f64());
+    histogram!("canyon_sql_connection_wait_seconds", labels)
+        .record(statement.connection_wait.as_secs_f64());
+}
+
+#[cfg(test)]
+mod metrics_tests {
+    use super::*;
+
+    /// Every duration goes to the first bucket whose bound isn't lower than it
+    #[test]
+    fn histogram_buckets() {
+        let mut histogram = Histogram::default();
+        histogram.record(Duration::from_millis(1));
+        histogram.record(Duration::from_millis(7));
+        histogram.record(Duration::from_secs(60));
+
+        assert_eq!(histogram.buckets[0], 1);
+        assert_eq!(histogram.buckets[2], 1);
+        assert_eq!(histogram.buckets[HISTOGRAM_BUCKETS_MS.len()], 1);
+        assert_eq!(histogram.count, 3);
+        assert_eq!(histogram.mean(), Some(Duration::from_millis(60_008) / 3));
+    }
+
+    /// The errors are classified by the type of error, or by its `SQLSTATE`
+    #[test]
+    fn classify_errors() {
+        let io_error: Box<dyn Error + Send + Sync> = Box::new(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Not found",
+        ));
+        assert_eq!(error_class(&*io_error), "io");
+
+        let other_error: Box<dyn Error + Send + Sync> = "Something went wrong".into();
+        assert_eq!(error_class(&*other_error), "other");
+    }
+}
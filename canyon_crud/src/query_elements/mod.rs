@@ -1,3 +1,6 @@
+pub mod named_params;
 pub mod operators;
 pub mod query;
 pub mod query_builder;
+pub mod query_plan;
+pub mod sql_lexer;
@@ -0,0 +1,151 @@
+use std::io::{Error, ErrorKind};
+
+use crate::bounds::QueryParameter;
+use crate::query_elements::sql_lexer::{tokenize, SqlDialect, SqlToken};
+
+/// The values of the `:name` placeholders of a raw SQL statement, usually
+/// created with the [`crate::params`] macro.
+///
+/// ```ignore
+/// let params = params! { "id" => &7, "slug" => &"LEC" };
+/// let leagues = League::query_named(
+///     "SELECT * FROM league WHERE id = :id OR slug = :slug",
+///     params,
+///     ""
+/// ).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NamedParams<'a> {
+    params: Vec<(&'a str, &'a dyn QueryParameter<'a>)>,
+}
+
+impl<'a> NamedParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of the placeholder with the given name, replacing the previous one (if any)
+    pub fn add(&mut self, name: &'a str, value: &'a dyn QueryParameter<'a>) -> &mut Self {
+        let name = name.strip_prefix(':').unwrap_or(name);
+        match self.params.iter_mut().find(|(n, _)| *n == name) {
+            Some(param) => param.1 = value,
+            None => self.params.push((name, value)),
+        }
+        self
+    }
+
+    /// Retrieves the value of the placeholder with the given name (if any)
+    pub fn get(&self, name: &str) -> Option<&'a dyn QueryParameter<'a>> {
+        self.params
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Translates the `:name` placeholders of the statement into the positional ones
+    /// used by Canyon, that are adapted later to every database dialect, returning
+    /// the values in the position that they must be bound.
+    ///
+    /// The placeholders inside string literals, quoted identifiers and comments (following
+    /// the rules of the given dialect) are ignored, and every appearance of the same name
+    /// is bound to the same positional parameter.
+    ///
+    /// Fails with a [`std::io::ErrorKind::InvalidInput`] error if the statement uses
+    /// positional placeholders, or there's no value for any of the named ones
+    pub fn bind(
+        &self,
+        stmt: &str,
+        dialect: SqlDialect,
+    ) -> Result<(String, Vec<&'a dyn QueryParameter<'a>>), Error> {
+        let mut positional_stmt = String::with_capacity(stmt.len());
+        let mut names: Vec<&str> = Vec::new();
+        let mut values = Vec::new();
+
+        for token in tokenize(stmt, dialect) {
+            match token {
+                SqlToken::Named(name) => {
+                    let position = match names.iter().position(|n| *n == name) {
+                        Some(idx) => idx + 1,
+                        None => {
+                            let value = self.get(name).ok_or_else(|| {
+                                Error::new(
+                                    ErrorKind::InvalidInput,
+                                    format!("There's no value for the named parameter `:{name}`"),
+                                )
+                            })?;
+                            names.push(name);
+                            values.push(value);
+                            names.len()
+                        }
+                    };
+                    SqlToken::Positional(position).push_to(&mut positional_stmt);
+                }
+                SqlToken::Positional(position) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "The positional parameter `${position}` can't be mixed with named parameters"
+                        ),
+                    ))
+                }
+                other => other.push_to(&mut positional_stmt),
+            }
+        }
+
+        Ok((positional_stmt, values))
+    }
+}
+
+/// Builds the [`NamedParams`] of a raw SQL statement, from pairs of names
+/// (without the leading colon) and references to the values
+///
+/// ```ignore
+/// let params = params! { "id" => &7, "slug" => &"LEC" };
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($($name:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut params = $crate::query_elements::named_params::NamedParams::new();
+        $( params.add($name, $value); )*
+        params
+    }};
+}
+
+#[cfg(test)]
+mod named_params_tests {
+    use crate::query_elements::sql_lexer::SqlDialect;
+
+    /// Every name gets a positional parameter, in the order of their first appearance
+    #[test]
+    fn bind_named_params() {
+        let params = crate::params! { "slug" => &"lla", "id" => &1_i32 };
+        let (stmt, values) = params
+            .bind(
+                "SELECT * FROM league WHERE id = :id OR slug = :slug OR id = :id::int",
+                SqlDialect::PostgreSql,
+            )
+            .expect("All the named parameters have a value");
+
+        assert_eq!(
+            stmt,
+            "SELECT * FROM league WHERE id = $1 OR slug = $2 OR id = $1::int"
+        );
+        assert_eq!(format!("{values:?}"), r#"[1, "lla"]"#);
+    }
+
+    /// The named parameters without value and the positional ones are refused
+    #[test]
+    fn bind_invalid_params() {
+        let params = crate::params! { "id" => &1_i32 };
+        assert!(params
+            .bind("SELECT * FROM league WHERE slug = :slug", SqlDialect::MySql)
+            .is_err());
+        assert!(params
+            .bind(
+                "SELECT * FROM league WHERE id = :id OR id = $1",
+                SqlDialect::MySql
+            )
+            .is_err());
+    }
+}
@@ -67,3 +67,230 @@ impl Operator for Like {
         }
     }
 }
+
+/// Operators over the JSON documents stored in a column, used with the `*_json` methods
+/// of the query builders
+pub enum JsonOp<'p> {
+    /// Compares, as text, the value found at the given SQL/JSON path (like `$.theme` or
+    /// `$.limits.max_teams`) with the given comparison operator. The path is bound as
+    /// a parameter of the query, placed before the compared value
+    Path(&'p str, Comp),
+    /// The document stored in the column contains the given one. `SqlServer` has no
+    /// equivalent, so the statements that use it against a `SqlServer` datasource
+    /// fails when they're launched
+    Contains,
+}
+
+impl JsonOp<'_> {
+    /// Writes the condition over the given column, with the syntax of the
+    /// given database, or [`None`] if the database doesn't support it.
+    /// For [`JsonOp::Path`], the `placeholder_counter` is the one of the path,
+    /// and the compared value takes the next one
+    pub fn as_condition(
+        &self,
+        column: &str,
+        placeholder_counter: usize,
+        datasource_type: &DatabaseType,
+    ) -> Option<String> {
+        match self {
+            JsonOp::Path(_, comp) => Some(format!(
+                "{}{}",
+                json_path_access(column, placeholder_counter, datasource_type),
+                comp.as_str(placeholder_counter + 1, datasource_type)
+            )),
+            JsonOp::Contains => match datasource_type {
+                #[cfg(feature = "postgres")]
+                DatabaseType::PostgreSql => Some(format!("{column} @> ${placeholder_counter}")),
+                #[cfg(feature = "mssql")]
+                DatabaseType::SqlServer => None,
+                #[cfg(feature = "mysql")]
+                DatabaseType::MySQL => {
+                    Some(format!("JSON_CONTAINS({column}, ${placeholder_counter})"))
+                }
+            },
+        }
+    }
+}
+
+/// Operators over the array columns, used with the `*_array` methods of the query builders.
+///
+/// `SqlServer` and `MySQL` stores the arrays as JSON documents, where the elements are
+/// compared as text
+pub enum ArrayOp {
+    /// The array contains the given element, as the `= ANY` of `PostgreSQL`
+    Any,
+    /// The array contains every element of the given one, as the `@>` of `PostgreSQL`
+    Contains,
+    /// The array has some element in common with the given one, as the `&&` of `PostgreSQL`
+    Overlaps,
+}
+
+impl ArrayOp {
+    /// Writes the condition over the given column, with the syntax of the
+    /// given database
+    pub fn as_condition(
+        &self,
+        column: &str,
+        placeholder_counter: usize,
+        datasource_type: &DatabaseType,
+    ) -> String {
+        let value = format!("${placeholder_counter}");
+        match datasource_type {
+            #[cfg(feature = "postgres")]
+            DatabaseType::PostgreSql => match self {
+                ArrayOp::Any => format!("{value} = ANY({column})"),
+                ArrayOp::Contains => format!("{column} @> {value}"),
+                ArrayOp::Overlaps => format!("{column} && {value}"),
+            },
+            #[cfg(feature = "mssql")]
+            DatabaseType::SqlServer => {
+                let elements = format!("SELECT value FROM OPENJSON({column})");
+                match self {
+                    ArrayOp::Any => format!("{value} IN ({elements})"),
+                    ArrayOp::Contains => format!(
+                        "NOT EXISTS (SELECT 1 FROM OPENJSON({value}) AS wanted \
+                        WHERE wanted.value NOT IN ({elements}))"
+                    ),
+                    ArrayOp::Overlaps => format!(
+                        "EXISTS (SELECT 1 FROM OPENJSON({value}) AS wanted \
+                        WHERE wanted.value IN ({elements}))"
+                    ),
+                }
+            }
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL => match self {
+                ArrayOp::Any => format!(
+                    "EXISTS (SELECT 1 FROM JSON_TABLE({column}, '$[*]' \
+                    COLUMNS (value TEXT PATH '$')) AS elements WHERE elements.value = {value})"
+                ),
+                ArrayOp::Contains => format!("JSON_CONTAINS({column}, CAST({value} AS JSON))"),
+                ArrayOp::Overlaps => format!("JSON_OVERLAPS({column}, CAST({value} AS JSON))"),
+            },
+        }
+    }
+}
+
+/// Writes the access, as text, to the value found at the JSON path of the column, where
+/// the path is the parameter bound at the given placeholder
+fn json_path_access(
+    column: &str,
+    placeholder_counter: usize,
+    datasource_type: &DatabaseType,
+) -> String {
+    let path = format!("${placeholder_counter}");
+    match datasource_type {
+        #[cfg(feature = "postgres")]
+        DatabaseType::PostgreSql => {
+            format!("jsonb_path_query_first({column}, {path}::text::jsonpath) #>> '{{}}'")
+        }
+        #[cfg(feature = "mssql")]
+        DatabaseType::SqlServer => format!("JSON_VALUE({column}, {path})"),
+        #[cfg(feature = "mysql")]
+        DatabaseType::MySQL => format!("JSON_UNQUOTE(JSON_EXTRACT({column}, {path}))"),
+    }
+}
+
+#[cfg(test)]
+mod operators_tests {
+    use super::*;
+
+    /// The JSON paths are bound as parameters, and accessed with the functions of
+    /// every database
+    #[test]
+    fn json_path_conditions() {
+        let roster = JsonOp::Path("$.limits.rosters[0]", Comp::GtEq);
+
+        #[cfg(feature = "postgres")]
+        {
+            assert_eq!(
+                roster.as_condition("settings", 1, &DatabaseType::PostgreSql),
+                Some("jsonb_path_query_first(settings, $1::text::jsonpath) #>> '{}' >= $2".into())
+            );
+            assert_eq!(
+                JsonOp::Contains.as_condition("settings", 3, &DatabaseType::PostgreSql),
+                Some("settings @> $3".into())
+            );
+        }
+        #[cfg(feature = "mssql")]
+        {
+            assert_eq!(
+                roster.as_condition("settings", 1, &DatabaseType::SqlServer),
+                Some("JSON_VALUE(settings, $1) >= $2".into())
+            );
+            assert_eq!(
+                JsonOp::Contains.as_condition("settings", 3, &DatabaseType::SqlServer),
+                None
+            );
+        }
+        #[cfg(feature = "mysql")]
+        {
+            assert_eq!(
+                roster.as_condition("settings", 1, &DatabaseType::MySQL),
+                Some("JSON_UNQUOTE(JSON_EXTRACT(settings, $1)) >= $2".into())
+            );
+            assert_eq!(
+                JsonOp::Contains.as_condition("settings", 3, &DatabaseType::MySQL),
+                Some("JSON_CONTAINS(settings, $3)".into())
+            );
+        }
+    }
+
+    /// The array operators are written with the native arrays of `PostgreSQL`, and
+    /// with the functions over the JSON arrays of the other databases
+    #[test]
+    fn array_conditions() {
+        #[cfg(feature = "postgres")]
+        {
+            assert_eq!(
+                ArrayOp::Any.as_condition("tags", 1, &DatabaseType::PostgreSql),
+                "$1 = ANY(tags)"
+            );
+            assert_eq!(
+                ArrayOp::Contains.as_condition("tags", 2, &DatabaseType::PostgreSql),
+                "tags @> $2"
+            );
+            assert_eq!(
+                ArrayOp::Overlaps.as_condition("tags", 3, &DatabaseType::PostgreSql),
+                "tags && $3"
+            );
+        }
+        #[cfg(feature = "mssql")]
+        {
+            assert_eq!(
+                ArrayOp::Any.as_condition("tags", 1, &DatabaseType::SqlServer),
+                "$1 IN (SELECT value FROM OPENJSON(tags))"
+            );
+            assert_eq!(
+                ArrayOp::Overlaps.as_condition("tags", 2, &DatabaseType::SqlServer),
+                "EXISTS (SELECT 1 FROM OPENJSON($2) AS wanted \
+                WHERE wanted.value IN (SELECT value FROM OPENJSON(tags)))"
+            );
+        }
+        #[cfg(feature = "mysql")]
+        assert_eq!(
+            ArrayOp::Contains.as_condition("tags", 1, &DatabaseType::MySQL),
+            "JSON_CONTAINS(tags, CAST($1 AS JSON))"
+        );
+    }
+
+    /// Nothing of the path is written into the statement, so it can't escape from it
+    #[test]
+    fn json_paths_are_not_written_in_the_sql() {
+        let path = JsonOp::Path("$.\"it's\\\",{}\"", Comp::Eq);
+        #[cfg(feature = "postgres")]
+        assert!(!path
+            .as_condition("settings", 1, &DatabaseType::PostgreSql)
+            .unwrap()
+            .contains("it's"));
+        #[cfg(feature = "mssql")]
+        assert!(!path
+            .as_condition("settings", 1, &DatabaseType::SqlServer)
+            .unwrap()
+            .contains("it's"));
+        #[cfg(feature = "mysql")]
+        assert!(!path
+            .as_condition("settings", 1, &DatabaseType::MySQL)
+            .unwrap()
+            .contains("it's"));
+    }
+}
@@ -3,13 +3,22 @@ use std::fmt::Debug;
 use canyon_connection::{
     canyon_database_connector::DatabaseType, get_database_config, DATASOURCES,
 };
+#[cfg(feature = "mysql")]
+use canyon_connection::{get_database_connection, CACHED_DATABASE_CONN};
+
+#[cfg(feature = "mysql")]
+use crate::{query_log::StatementLog, rows::CanyonRows};
 
 use crate::{
     bounds::{FieldIdentifier, FieldValueIdentifier, QueryParameter},
     crud::{CrudOperations, Transaction},
     mapper::RowMapper,
-    query_elements::query::Query,
-    Operator,
+    query_elements::{
+        query::Query,
+        query_plan::QueryPlan,
+        sql_lexer::{find_keyword, render_debug_sql, SqlDialect},
+    },
+    ArrayOp, JsonOp, Operator,
 };
 
 /// Contains the elements that makes part of the formal declaration
@@ -54,6 +63,18 @@ pub mod ops {
         /// with the same lifetime as self
         fn read_sql(&'a self) -> &'a str;
 
+        /// Returns the values bound to the placeholders of the SQL sentence,
+        /// in the order of their positions
+        fn params(&self) -> &[&'a dyn QueryParameter<'a>];
+
+        /// Returns the SQL sentence with the values of its parameters written as
+        /// literals in place of their placeholders, quoted and escaped with the
+        /// syntax of the database targeted by the datasource.
+        ///
+        /// It's meant for logs and test assertions, so the parameters should be
+        /// bound through the builder when the statement is launched
+        fn to_debug_sql(&self) -> String;
+
         /// Public interface for append the content of an slice to the end of
         /// the underlying SQL sentece.
         ///
@@ -124,6 +145,75 @@ pub mod ops {
         fn or<Z: FieldValueIdentifier<'a, T>>(&mut self, column: Z, op: impl Operator)
             -> &mut Self;
 
+        /// Generates a `WHERE` SQL clause over the JSON document stored in a column.
+        ///
+        /// * `column` - A [`FieldIdentifier`] that will provide the target
+        ///   column name for the filter
+        /// * `op` - A [`JsonOp`] with the access to the document to perform. The path
+        ///   of a [`JsonOp::Path`] is bound as a parameter of the query
+        /// * `value` - The value compared with the one found at the JSON path, or
+        ///   the document that must be contained in the column
+        fn where_json<Z: FieldIdentifier<T>>(
+            &mut self,
+            column: Z,
+            op: &'a JsonOp<'a>,
+            value: &'a dyn QueryParameter<'a>,
+        ) -> &mut Self;
+
+        /// Generates an `AND` SQL clause over the JSON document stored in a column.
+        ///
+        /// See [`QueryBuilder::where_json`] for the meaning of the arguments
+        fn and_json<Z: FieldIdentifier<T>>(
+            &mut self,
+            column: Z,
+            op: &'a JsonOp<'a>,
+            value: &'a dyn QueryParameter<'a>,
+        ) -> &mut Self;
+
+        /// Generates an `OR` SQL clause over the JSON document stored in a column.
+        ///
+        /// See [`QueryBuilder::where_json`] for the meaning of the arguments
+        fn or_json<Z: FieldIdentifier<T>>(
+            &mut self,
+            column: Z,
+            op: &'a JsonOp<'a>,
+            value: &'a dyn QueryParameter<'a>,
+        ) -> &mut Self;
+
+        /// Generates a `WHERE` SQL clause over the array stored in a column.
+        ///
+        /// * `column` - A [`FieldIdentifier`] that will provide the target
+        ///   column name for the filter
+        /// * `op` - An [`ArrayOp`] with the comparison to perform over the array
+        /// * `value` - The element searched in the array for [`ArrayOp::Any`], or
+        ///   the array compared with the column for the other operators
+        fn where_array<Z: FieldIdentifier<T>>(
+            &mut self,
+            column: Z,
+            op: ArrayOp,
+            value: &'a dyn QueryParameter<'a>,
+        ) -> &mut Self;
+
+        /// Generates an `AND` SQL clause over the array stored in a column.
+        ///
+        /// See [`QueryBuilder::where_array`] for the meaning of the arguments
+        fn and_array<Z: FieldIdentifier<T>>(
+            &mut self,
+            column: Z,
+            op: ArrayOp,
+            value: &'a dyn QueryParameter<'a>,
+        ) -> &mut Self;
+
+        /// Generates an `OR` SQL clause over the array stored in a column.
+        ///
+        /// See [`QueryBuilder::where_array`] for the meaning of the arguments
+        fn or_array<Z: FieldIdentifier<T>>(
+            &mut self,
+            column: Z,
+            op: ArrayOp,
+            value: &'a dyn QueryParameter<'a>,
+        ) -> &mut Self;
+
         /// Generates a `ORDER BY` SQL clause for constraint the query.
         ///
         /// * `order_by` - A [`FieldIdentifier`] that will provide the target
@@ -134,6 +224,97 @@ pub mod ops {
     }
 }
 
+/// Describes which rows must be given back by the database after launching
+/// an *UPDATE* or a *DELETE* statement generated with a [`QueryBuilder`].
+///
+/// `MySQL` retrieves them with a separated *SELECT*, so for it every variant holds
+/// the table (with its schema, if any) targeted by the statement
+#[derive(Debug, Clone)]
+enum Returning {
+    /// The rows of the table with their already updated values. `MySQL` looks for
+    /// them again by the columns of their primary key
+    Updated {
+        #[cfg(feature = "mysql")]
+        table: String,
+        #[cfg(feature = "mysql")]
+        primary_key: Vec<String>,
+    },
+    /// The rows removed from the table
+    Deleted {
+        #[cfg(feature = "mysql")]
+        table: String,
+    },
+}
+
+impl Returning {
+    /// The `SqlServer` `OUTPUT` clause that matches the kind of statement
+    #[cfg(feature = "mssql")]
+    fn output_clause(&self) -> &'static str {
+        match self {
+            Returning::Updated { .. } => " OUTPUT inserted.*",
+            Returning::Deleted { .. } => " OUTPUT deleted.*",
+        }
+    }
+
+    #[cfg(feature = "mysql")]
+    fn table(&self) -> &str {
+        match self {
+            Returning::Updated { table, .. } | Returning::Deleted { table } => table,
+        }
+    }
+}
+
+/// Describes which rows of an entity declared with the `soft_delete` argument
+/// of the `canyon_entity` macro must be retrieved by a *SELECT* statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SoftDeleteFilter {
+    /// Only the rows that aren't marked as deleted. The default one
+    Exclude,
+    /// Every row, no matter if it's marked as deleted or not
+    Include,
+    /// Only the rows marked as deleted
+    Only,
+}
+
+/// The clauses that may follow the filters of a statement
+const CLAUSES_AFTER_FILTERS: [&str; 4] = ["GROUP BY", "ORDER BY", "LIMIT", "OFFSET"];
+
+/// Wires the condition over the soft delete column (if any) within the filters
+/// of the query, grouping the ones declared by the user, so an `OR` on them
+/// can't retrieve rows that should be discarded.
+///
+/// The clauses are found with the [`crate::query_elements::sql_lexer`], so the
+/// ones written inside literals or subqueries aren't taken into account
+fn apply_soft_delete_filter(
+    sql: &mut String,
+    soft_delete: &Option<(String, SoftDeleteFilter)>,
+    dialect: SqlDialect,
+) {
+    let condition = match soft_delete {
+        Some((column, SoftDeleteFilter::Exclude)) => format!("{column} IS NULL"),
+        Some((column, SoftDeleteFilter::Only)) => format!("{column} IS NOT NULL"),
+        Some((_, SoftDeleteFilter::Include)) | None => return,
+    };
+
+    let end_of_filters = CLAUSES_AFTER_FILTERS
+        .iter()
+        .filter_map(|clause| find_keyword(sql, dialect, clause))
+        .min()
+        .unwrap_or(sql.len());
+    let end_of_filters = sql[..end_of_filters].trim_end().len();
+
+    match find_keyword(sql, dialect, "WHERE") {
+        Some(idx) if idx < end_of_filters => {
+            sql.insert(end_of_filters, ')');
+            let filters_start = idx + "WHERE".len();
+            let filters_start = filters_start + sql[filters_start..].len()
+                - sql[filters_start..].trim_start().len();
+            sql.replace_range(idx..filters_start, &format!("WHERE {condition} AND ("));
+        }
+        _ => sql.insert_str(end_of_filters, &format!(" WHERE {condition}")),
+    }
+}
+
 /// Type for construct more complex queries than the classical CRUD ones.
 #[derive(Debug, Clone)]
 pub struct QueryBuilder<'a, T>
@@ -143,6 +324,11 @@ where
     query: Query<'a, T>,
     datasource_name: &'a str,
     datasource_type: DatabaseType,
+    returning: Option<Returning>,
+    soft_delete: Option<(String, SoftDeleteFilter)>,
+    /// Why the statement can't be launched, when some of its conditions isn't
+    /// supported by the database targeted by the datasource
+    unsupported: Option<&'static str>,
 }
 
 unsafe impl<'a, T> Send for QueryBuilder<'a, T> where
@@ -166,6 +352,19 @@ where
             datasource_type: DatabaseType::from(
                 &get_database_config(datasource_name, &DATASOURCES).auth,
             ),
+            returning: None,
+            soft_delete: None,
+            unsupported: None,
+        }
+    }
+
+    /// Fails with the reason why the statement can't be launched, if any
+    fn check_supported(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send + 'static>> {
+        match self.unsupported {
+            Some(reason) => {
+                Err(std::io::Error::new(std::io::ErrorKind::Unsupported, reason).into())
+            }
+            None => Ok(()),
         }
     }
 
@@ -173,16 +372,155 @@ where
     /// by the selected datasource
     pub async fn query(
         &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Sync + Send + 'static>> {
+        self.check_supported()?;
+        apply_soft_delete_filter(
+            &mut self.query.sql,
+            &self.soft_delete,
+            self.datasource_type.into(),
+        );
+
+        match (&self.returning, self.datasource_type) {
+            (None, _) => {}
+            #[cfg(feature = "postgres")]
+            (Some(_), DatabaseType::PostgreSql) => self.query.sql.push_str(" RETURNING *"),
+            #[cfg(feature = "mssql")]
+            (Some(returning), DatabaseType::SqlServer) => {
+                // The `OUTPUT` clause goes just before the filters of the statement
+                let output_clause = returning.output_clause();
+                let sql = &self.query.sql;
+                let idx = find_keyword(sql, SqlDialect::SqlServer, "WHERE")
+                    .map_or(sql.len(), |idx| sql[..idx].trim_end().len());
+                self.query.sql.insert_str(idx, output_clause);
+            }
+            #[cfg(feature = "mysql")]
+            (Some(returning), DatabaseType::MySQL) => {
+                return self.query_returning_mysql(returning).await
+            }
+        }
+
         self.query.sql.push(';');
 
-        Ok(T::query(
+        T::query(
             self.query.sql.clone(),
             self.query.params.to_vec(),
             self.datasource_name,
         )
         .await?
-        .into_results::<T>())
+        .into_results::<T>()
+    }
+
+    /// Launches the generated statement against the database targeted
+    /// by the selected datasource, returning the number of affected rows
+    pub async fn execute(
+        &'a mut self,
+    ) -> Result<u64, Box<dyn std::error::Error + Sync + Send + 'static>> {
+        self.check_supported()?;
+        apply_soft_delete_filter(
+            &mut self.query.sql,
+            &self.soft_delete,
+            self.datasource_type.into(),
+        );
+        self.query.sql.push(';');
+
+        T::execute(
+            self.query.sql.clone(),
+            self.query.params.to_vec(),
+            self.datasource_name,
+        )
+        .await
+    }
+
+    /// Retrieves the execution plan of the generated statement, without the clauses
+    /// added by [`QueryBuilder::query`] to retrieve the affected rows (if any)
+    pub async fn explain(
+        &self,
+        analyze: bool,
+    ) -> Result<QueryPlan, Box<dyn std::error::Error + Sync + Send>> {
+        self.check_supported()?;
+        crate::crud::explain_statement(
+            self.datasource_name,
+            &self.filtered_sql(),
+            &self.query.params,
+            analyze,
+        )
+        .await
+    }
+
+    /// Renders the generated statement with the values of its parameters written
+    /// in place of their placeholders, with the syntax of the targeted database
+    pub fn to_debug_sql(&self) -> String {
+        render_debug_sql(
+            &self.filtered_sql(),
+            &self.query.params,
+            self.datasource_type.into(),
+        )
+    }
+
+    /// The generated statement, with the filter over the soft delete column (if any)
+    fn filtered_sql(&self) -> String {
+        let mut sql = self.query.sql.clone();
+        apply_soft_delete_filter(&mut sql, &self.soft_delete, self.datasource_type.into());
+        sql
+    }
+
+    /// `MySQL` does not have any clause to retrieve the rows affected by an *UPDATE*
+    /// or a *DELETE* statement, so a `SELECT` with the same filters is launched before
+    /// the statement, within the same transaction. For an *UPDATE*, it only retrieves
+    /// the primary keys of the rows, that are selected again after the update in order
+    /// to retrieve them with their new values, even if they stop matching the filters
+    #[cfg(feature = "mysql")]
+    async fn query_returning_mysql(
+        &self,
+        returning: &Returning,
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Sync + Send + 'static>> {
+        let sql = &self.query.sql;
+        let filters = find_keyword(sql, SqlDialect::MySql, "WHERE")
+            .map(|idx| format!(" {}", &sql[idx..]))
+            .unwrap_or_default();
+
+        let (columns, updated_primary_key) = match returning {
+            Returning::Updated { primary_key, .. } if primary_key.is_empty() => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "MySQL needs the primary key of the entity to give back the updated rows",
+                )
+                .into())
+            }
+            Returning::Updated { primary_key, .. } => (
+                primary_key
+                    .iter()
+                    .map(|column| format!("\"{column}\""))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Some(primary_key.as_slice()),
+            ),
+            Returning::Deleted { .. } => ("*".to_string(), None),
+        };
+        let select_stmt = format!(
+            "SELECT {columns} FROM {}{filters} FOR UPDATE",
+            returning.table()
+        );
+
+        let mut log = StatementLog::new(self.datasource_name, &self.query.sql, &self.query.params);
+        let mut guarded_cache = CACHED_DATABASE_CONN.lock().await;
+        let database_conn = get_database_connection(self.datasource_name, &mut guarded_cache);
+
+        let statement = crate::crud::mysql_query_launcher::launch_returning::<T>(
+            database_conn,
+            returning.table(),
+            select_stmt,
+            self.query.sql.clone(),
+            &self.query.params,
+            updated_primary_key,
+        );
+
+        let result = log
+            .run(|rows: &CanyonRows<T>| rows.len() as u64, statement)
+            .await;
+        crate::crud::report_if_slow(&log, database_conn, &self.query.sql, &self.query.params).await;
+
+        result?.into_results::<T>()
     }
 
     pub fn r#where<Z: FieldValueIdentifier<'a, T>>(&mut self, r#where: Z, op: impl Operator) {
@@ -280,6 +618,51 @@ where
         self.query.sql.push(')')
     }
 
+    /// Appends a condition over the JSON document stored in the column, joined
+    /// with the given keyword. When the database doesn't support it, the statement
+    /// fails once it's launched
+    pub fn json_condition<Z: FieldIdentifier<T>>(
+        &mut self,
+        keyword: &str,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) {
+        let Some(condition) = op.as_condition(
+            column.as_str(),
+            self.query.params.len() + 1,
+            &self.datasource_type,
+        ) else {
+            self.unsupported = Some("The JSON containment isn't supported by SqlServer");
+            return;
+        };
+
+        self.query.sql.push_str(&format!(" {keyword} {condition}"));
+        if let JsonOp::Path(path, _) = op {
+            self.query.params.push(path);
+        }
+        self.query.params.push(value);
+    }
+
+    /// Appends a condition over the array stored in the column, joined with
+    /// the given keyword
+    pub fn array_condition<Z: FieldIdentifier<T>>(
+        &mut self,
+        keyword: &str,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) {
+        let condition = op.as_condition(
+            column.as_str(),
+            self.query.params.len() + 1,
+            &self.datasource_type,
+        );
+
+        self.query.sql.push_str(&format!(" {keyword} {condition}"));
+        self.query.params.push(value);
+    }
+
     #[inline]
     pub fn order_by<Z: FieldIdentifier<T>>(&mut self, order_by: Z, desc: bool) {
         self.query.sql.push_str(
@@ -314,15 +697,69 @@ where
         }
     }
 
+    /// Generates a new public instance of the [`SelectQueryBuilder`] for an entity
+    /// declared with the `soft_delete` argument of the `canyon_entity` macro, that
+    /// discards the rows marked as deleted on the `soft_delete_column`
+    pub fn new_soft_deletable(
+        table_schema_data: &str,
+        datasource_name: &'a str,
+        soft_delete_column: &str,
+    ) -> Self {
+        let mut select = Self::new(table_schema_data, datasource_name);
+        select._inner.soft_delete = Some((
+            format!("{table_schema_data}.{soft_delete_column}"),
+            SoftDeleteFilter::Exclude,
+        ));
+        select
+    }
+
     /// Launches the generated query to the database pointed by the
     /// selected datasource
     #[inline]
     pub async fn query(
         &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Sync + Send + 'static>> {
         self._inner.query().await
     }
 
+    /// Retrieves the execution plan of the generated query from the database pointed
+    /// by the selected datasource, without executing it
+    #[inline]
+    pub async fn explain(&self) -> Result<QueryPlan, Box<dyn std::error::Error + Sync + Send>> {
+        self._inner.explain(false).await
+    }
+
+    /// Same as [`SelectQueryBuilder::explain`], but the query is executed in order to
+    /// include the actual rows produced by every node of the plan.
+    ///
+    /// > Note: The query is executed within a transaction that is rolled back
+    #[inline]
+    pub async fn explain_analyze(
+        &self,
+    ) -> Result<QueryPlan, Box<dyn std::error::Error + Sync + Send>> {
+        self._inner.explain(true).await
+    }
+
+    /// Makes the query retrieve the rows marked as deleted along with the other ones.
+    ///
+    /// > Note: It has no effect over entities not declared with the `soft_delete` argument
+    pub fn with_deleted(&mut self) -> &mut Self {
+        if let Some((_, filter)) = &mut self._inner.soft_delete {
+            *filter = SoftDeleteFilter::Include;
+        }
+        self
+    }
+
+    /// Makes the query retrieve only the rows marked as deleted.
+    ///
+    /// > Note: It has no effect over entities not declared with the `soft_delete` argument
+    pub fn only_deleted(&mut self) -> &mut Self {
+        if let Some((_, filter)) = &mut self._inner.soft_delete {
+            *filter = SoftDeleteFilter::Only;
+        }
+        self
+    }
+
     /// Adds a *LEFT JOIN* SQL statement to the underlying
     /// [`Query`] held by the [`QueryBuilder`], where:
     ///
@@ -397,6 +834,16 @@ where
         self._inner.query.sql.as_str()
     }
 
+    #[inline]
+    fn params(&self) -> &[&'a dyn QueryParameter<'a>] {
+        &self._inner.query.params
+    }
+
+    #[inline]
+    fn to_debug_sql(&self) -> String {
+        self._inner.to_debug_sql()
+    }
+
     #[inline(always)]
     fn push_sql(&mut self, sql: &str) {
         self._inner.query.sql.push_str(sql);
@@ -444,6 +891,72 @@ where
         self
     }
 
+    #[inline]
+    fn where_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("WHERE", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn and_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("AND", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn or_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("OR", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn where_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("WHERE", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn and_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("AND", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn or_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("OR", column, op, value);
+        self
+    }
+
     #[inline]
     fn order_by<Z: FieldIdentifier<T>>(&mut self, order_by: Z, desc: bool) -> &mut Self {
         self._inner.order_by(order_by, desc);
@@ -461,6 +974,11 @@ where
     T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
 {
     _inner: QueryBuilder<'a, T>,
+    #[cfg(feature = "mysql")]
+    table_schema_data: String,
+    #[cfg(feature = "mysql")]
+    primary_key: Vec<String>,
+    updated_at: Option<String>,
 }
 
 impl<'a, T> UpdateQueryBuilder<'a, T>
@@ -474,18 +992,98 @@ where
                 Query::new(format!("UPDATE {table_schema_data}")),
                 datasource_name,
             ),
+            #[cfg(feature = "mysql")]
+            table_schema_data: table_schema_data.to_string(),
+            #[cfg(feature = "mysql")]
+            primary_key: Vec::new(),
+            updated_at: None,
         }
     }
 
+    /// Sets the columns of the primary key of the entity, that `MySQL` needs in order
+    /// to give back the updated rows on [`UpdateQueryBuilder::returning`]
+    #[cfg_attr(not(feature = "mysql"), allow(unused_variables))]
+    pub fn with_primary_key(&mut self, columns: &[&str]) -> &mut Self {
+        #[cfg(feature = "mysql")]
+        {
+            self.primary_key = columns.iter().map(|column| column.to_string()).collect();
+        }
+        self
+    }
+
+    /// Generates a new public instance of the [`UpdateQueryBuilder`] for the entities
+    /// with a field annotated with `#[updated_at]`, so the `SET` clause also sets its
+    /// column to the current time, in UTC
+    pub fn new_with_updated_at(
+        table_schema_data: &str,
+        datasource_name: &'a str,
+        updated_at_column: &str,
+    ) -> Self {
+        let mut builder = Self::new(table_schema_data, datasource_name);
+        builder.updated_at = Some(updated_at_column.to_string());
+        builder
+    }
+
     /// Launches the generated query to the database pointed by the
     /// selected datasource
     #[inline]
     pub async fn query(
         &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Sync + Send + 'static>> {
         self._inner.query().await
     }
 
+    /// Launches the generated query to the database pointed by the
+    /// selected datasource, returning the number of rows updated
+    /// instead of the rows themselves
+    #[inline]
+    pub async fn execute(
+        &'a mut self,
+    ) -> Result<u64, Box<dyn std::error::Error + Sync + Send + 'static>> {
+        self._inner.execute().await
+    }
+
+    /// Retrieves the execution plan of the generated query from the database pointed
+    /// by the selected datasource, without executing it
+    #[inline]
+    pub async fn explain(&self) -> Result<QueryPlan, Box<dyn std::error::Error + Sync + Send>> {
+        self._inner.explain(false).await
+    }
+
+    /// Same as [`UpdateQueryBuilder::explain`], but the query is executed in order to
+    /// include the actual rows produced by every node of the plan.
+    ///
+    /// > Note: The query is executed within a transaction that is rolled back,
+    /// > so no row is updated by it
+    #[inline]
+    pub async fn explain_analyze(
+        &self,
+    ) -> Result<QueryPlan, Box<dyn std::error::Error + Sync + Send>> {
+        self._inner.explain(true).await
+    }
+
+    /// Makes the [`UpdateQueryBuilder::query`] method give back the updated rows,
+    /// with their new values.
+    ///
+    /// It's generated as a `RETURNING *` clause for `PostgreSQL` and an
+    /// `OUTPUT inserted.*` clause for `SqlServer`. `MySQL` has no equivalent,
+    /// so the primary keys of the rows that matches the filters are selected
+    /// before the update, and their rows are selected again after it, all
+    /// within the same transaction.
+    ///
+    /// > Note: `MySQL` fails to give back the rows when the columns of the
+    /// > primary key aren't known, as it happens for the entities without
+    /// > any `#[primary_key]` field
+    pub fn returning(&mut self) -> &mut Self {
+        self._inner.returning = Some(Returning::Updated {
+            #[cfg(feature = "mysql")]
+            table: self.table_schema_data.clone(),
+            #[cfg(feature = "mysql")]
+            primary_key: self.primary_key.clone(),
+        });
+        self
+    }
+
     /// Creates an SQL `SET` clause to especify the columns that must be updated in the sentence
     pub fn set<Z, Q>(&mut self, columns: &'a [(Z, Q)]) -> &mut Self
     where
@@ -520,6 +1118,24 @@ where
             self._inner.query.params.push(&column.1);
         }
 
+        // The `#[updated_at]` column is bumped, unless it's explicitly set by the user
+        if let Some(updated_at) = &self.updated_at {
+            if !columns
+                .iter()
+                .any(|column| column.0.as_str() == *updated_at)
+            {
+                let now = match self._inner.datasource_type {
+                    #[cfg(feature = "postgres")]
+                    DatabaseType::PostgreSql => "(CURRENT_TIMESTAMP AT TIME ZONE 'UTC')",
+                    #[cfg(feature = "mssql")]
+                    DatabaseType::SqlServer => "SYSUTCDATETIME()",
+                    #[cfg(feature = "mysql")]
+                    DatabaseType::MySQL => "UTC_TIMESTAMP(6)",
+                };
+                set_clause.push_str(&format!(", {updated_at} = {now}"));
+            }
+        }
+
         self._inner.query.sql.push_str(&set_clause);
         self
     }
@@ -534,6 +1150,16 @@ where
         self._inner.query.sql.as_str()
     }
 
+    #[inline]
+    fn params(&self) -> &[&'a dyn QueryParameter<'a>] {
+        &self._inner.query.params
+    }
+
+    #[inline]
+    fn to_debug_sql(&self) -> String {
+        self._inner.to_debug_sql()
+    }
+
     #[inline(always)]
     fn push_sql(&mut self, sql: &str) {
         self._inner.query.sql.push_str(sql);
@@ -581,6 +1207,72 @@ where
         self
     }
 
+    #[inline]
+    fn where_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("WHERE", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn and_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("AND", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn or_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("OR", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn where_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("WHERE", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn and_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("AND", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn or_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("OR", column, op, value);
+        self
+    }
+
     #[inline]
     fn order_by<Z: FieldIdentifier<T>>(&mut self, order_by: Z, desc: bool) -> &mut Self {
         self._inner.order_by(order_by, desc);
@@ -599,6 +1291,8 @@ where
     T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
 {
     _inner: QueryBuilder<'a, T>,
+    #[cfg(feature = "mysql")]
+    table_schema_data: String,
 }
 
 impl<'a, T> DeleteQueryBuilder<'a, T>
@@ -612,6 +1306,35 @@ where
                 Query::new(format!("DELETE FROM {table_schema_data}")),
                 datasource_name,
             ),
+            #[cfg(feature = "mysql")]
+            table_schema_data: table_schema_data.to_string(),
+        }
+    }
+
+    /// Generates a new public instance of the [`DeleteQueryBuilder`] for an entity
+    /// declared with the `soft_delete` argument of the `canyon_entity` macro, that
+    /// marks the matching rows as deleted on the `soft_delete_column` instead of
+    /// removing them. The rows already marked as deleted are left untouched
+    pub fn new_soft_deletable(
+        table_schema_data: &str,
+        datasource_name: &'a str,
+        soft_delete_column: &str,
+    ) -> Self {
+        let mut inner = QueryBuilder::<T>::new(
+            Query::new(format!(
+                "UPDATE {table_schema_data} SET {soft_delete_column} = CURRENT_TIMESTAMP"
+            )),
+            datasource_name,
+        );
+        inner.soft_delete = Some((
+            format!("{table_schema_data}.{soft_delete_column}"),
+            SoftDeleteFilter::Exclude,
+        ));
+
+        Self {
+            _inner: inner,
+            #[cfg(feature = "mysql")]
+            table_schema_data: table_schema_data.to_string(),
         }
     }
 
@@ -620,9 +1343,55 @@ where
     #[inline]
     pub async fn query(
         &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
+    ) -> Result<Vec<T>, Box<dyn std::error::Error + Sync + Send + 'static>> {
         self._inner.query().await
     }
+
+    /// Launches the generated query to the database pointed by the
+    /// selected datasource, returning the number of rows deleted
+    /// instead of the rows themselves
+    #[inline]
+    pub async fn execute(
+        &'a mut self,
+    ) -> Result<u64, Box<dyn std::error::Error + Sync + Send + 'static>> {
+        self._inner.execute().await
+    }
+
+    /// Retrieves the execution plan of the generated query from the database pointed
+    /// by the selected datasource, without executing it
+    #[inline]
+    pub async fn explain(&self) -> Result<QueryPlan, Box<dyn std::error::Error + Sync + Send>> {
+        self._inner.explain(false).await
+    }
+
+    /// Same as [`DeleteQueryBuilder::explain`], but the query is executed in order to
+    /// include the actual rows produced by every node of the plan.
+    ///
+    /// > Note: The query is executed within a transaction that is rolled back,
+    /// > so no row is deleted by it
+    #[inline]
+    pub async fn explain_analyze(
+        &self,
+    ) -> Result<QueryPlan, Box<dyn std::error::Error + Sync + Send>> {
+        self._inner.explain(true).await
+    }
+
+    /// Makes the [`DeleteQueryBuilder::query`] method give back the deleted rows.
+    ///
+    /// It's generated as a `RETURNING *` clause for `PostgreSQL` and an
+    /// `OUTPUT deleted.*` clause for `SqlServer`. `MySQL` has no equivalent,
+    /// so a `SELECT` with the same filters is launched before the delete,
+    /// within the same transaction.
+    ///
+    /// > Note: For the entities declared with the `soft_delete` argument, `SqlServer`
+    /// > and `MySQL` give back the rows as they were before being marked as deleted
+    pub fn returning(&mut self) -> &mut Self {
+        self._inner.returning = Some(Returning::Deleted {
+            #[cfg(feature = "mysql")]
+            table: self.table_schema_data.clone(),
+        });
+        self
+    }
 }
 
 impl<'a, T> ops::QueryBuilder<'a, T> for DeleteQueryBuilder<'a, T>
@@ -634,6 +1403,16 @@ where
         self._inner.query.sql.as_str()
     }
 
+    #[inline]
+    fn params(&self) -> &[&'a dyn QueryParameter<'a>] {
+        &self._inner.query.params
+    }
+
+    #[inline]
+    fn to_debug_sql(&self) -> String {
+        self._inner.to_debug_sql()
+    }
+
     #[inline(always)]
     fn push_sql(&mut self, sql: &str) {
         self._inner.query.sql.push_str(sql);
@@ -681,9 +1460,138 @@ where
         self
     }
 
+    #[inline]
+    fn where_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("WHERE", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn and_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("AND", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn or_json<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: &'a JsonOp<'a>,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.json_condition("OR", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn where_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("WHERE", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn and_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("AND", column, op, value);
+        self
+    }
+
+    #[inline]
+    fn or_array<Z: FieldIdentifier<T>>(
+        &mut self,
+        column: Z,
+        op: ArrayOp,
+        value: &'a dyn QueryParameter<'a>,
+    ) -> &mut Self {
+        self._inner.array_condition("OR", column, op, value);
+        self
+    }
+
     #[inline]
     fn order_by<Z: FieldIdentifier<T>>(&mut self, order_by: Z, desc: bool) -> &mut Self {
         self._inner.order_by(order_by, desc);
         self
     }
 }
+
+#[cfg(test)]
+mod query_builder_tests {
+    use super::*;
+
+    fn soft_delete(filter: SoftDeleteFilter) -> Option<(String, SoftDeleteFilter)> {
+        Some((String::from("tournament.deleted_at"), filter))
+    }
+
+    /// The user filters are grouped, and the condition over the soft delete column
+    /// goes before any clause written after them, in any case
+    #[test]
+    fn soft_delete_filter_groups_the_filters() {
+        let mut sql = String::from("SELECT * FROM tournament WHERE id = $1 OR id = $2 order by id");
+        apply_soft_delete_filter(
+            &mut sql,
+            &soft_delete(SoftDeleteFilter::Exclude),
+            SqlDialect::PostgreSql,
+        );
+        assert_eq!(
+            sql,
+            "SELECT * FROM tournament WHERE tournament.deleted_at IS NULL AND (id = $1 OR id = $2) order by id"
+        );
+
+        let mut sql = String::from("SELECT * FROM tournament ORDER BY id");
+        apply_soft_delete_filter(
+            &mut sql,
+            &soft_delete(SoftDeleteFilter::Only),
+            SqlDialect::MySql,
+        );
+        assert_eq!(
+            sql,
+            "SELECT * FROM tournament WHERE tournament.deleted_at IS NOT NULL ORDER BY id"
+        );
+    }
+
+    /// The clauses written inside literals or subqueries are ignored
+    #[test]
+    fn soft_delete_filter_skips_literals_and_subqueries() {
+        let mut sql = String::from(
+            "SELECT * FROM tournament WHERE slug = ' ORDER BY ' \
+            AND league IN (SELECT id FROM league WHERE region = $1 ORDER BY id)",
+        );
+        apply_soft_delete_filter(
+            &mut sql,
+            &soft_delete(SoftDeleteFilter::Exclude),
+            SqlDialect::SqlServer,
+        );
+        assert_eq!(
+            sql,
+            "SELECT * FROM tournament WHERE tournament.deleted_at IS NULL AND (slug = ' ORDER BY ' \
+            AND league IN (SELECT id FROM league WHERE region = $1 ORDER BY id))"
+        );
+
+        let mut sql = String::from("SELECT * FROM tournament");
+        apply_soft_delete_filter(
+            &mut sql,
+            &soft_delete(SoftDeleteFilter::Include),
+            SqlDialect::PostgreSql,
+        );
+        assert_eq!(sql, "SELECT * FROM tournament");
+    }
+}
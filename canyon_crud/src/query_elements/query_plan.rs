@@ -0,0 +1,405 @@
+//! The execution plans of the statements generated by the query builders, as returned
+//! by the `explain()` and `explain_analyze()` methods.
+//!
+//! Every database describes its plans in its own format, so they are translated into a
+//! tree of [`PlanNode`]s that holds the common elements between them, keeping the
+//! original plan in [`QueryPlan::raw`] for anything else.
+use std::error::Error;
+
+/// The execution plan of a statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryPlan {
+    /// The node that produces the result of the statement
+    pub root: PlanNode,
+    /// The plan as returned by the database: the `JSON` of PostgreSQL, the
+    /// `XML` showplan of SqlServer, and the `JSON` (or the tree, when it's
+    /// analyzed) of MySQL
+    pub raw: String,
+}
+
+/// An operation of an execution plan, like a scan over a table or a join
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlanNode {
+    /// The kind of operation, as named by the database (e.g. `Seq Scan`
+    /// or `Index Scan` on PostgreSQL, `Clustered Index Seek` on SqlServer,
+    /// or the access type of the table, like `ALL` or `const`, on MySQL)
+    pub node_type: String,
+    /// The table read by the operation (if any)
+    pub relation: Option<String>,
+    /// The index used by the operation (if any)
+    pub index: Option<String>,
+    /// The number of rows that the planner expects the operation to produce
+    pub estimated_rows: Option<f64>,
+    /// The number of rows produced by the operation, along every loop of it.
+    /// Only available when the plan is analyzed
+    pub actual_rows: Option<f64>,
+    /// The estimated cost of the operation, including its children, in the
+    /// arbitrary units of the planner of the database
+    pub cost: Option<f64>,
+    /// The operations whose output is consumed by this one
+    pub children: Vec<PlanNode>,
+}
+
+impl QueryPlan {
+    /// Iterates over every node of the plan, starting by the root, depth first
+    pub fn nodes(&self) -> impl Iterator<Item = &PlanNode> {
+        let mut pending = vec![&self.root];
+        std::iter::from_fn(move || {
+            let node = pending.pop()?;
+            pending.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Whether any operation of the plan reads the given table
+    pub fn reads(&self, relation: &str) -> bool {
+        self.nodes()
+            .any(|node| node.relation.as_deref() == Some(relation))
+    }
+
+    /// Whether any operation of the plan uses the given index
+    pub fn uses_index(&self, index: &str) -> bool {
+        self.nodes()
+            .any(|node| node.index.as_deref() == Some(index))
+    }
+
+    /// Parses the output of `EXPLAIN (FORMAT JSON)` on PostgreSQL
+    #[cfg(feature = "postgres")]
+    pub(crate) fn from_postgres(raw: String) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let plans: serde_json::Value = serde_json::from_str(&raw)?;
+        let root = plans
+            .get(0)
+            .and_then(|plan| plan.get("Plan"))
+            .ok_or_else(|| invalid_plan("PostgreSQL", "there's no `Plan` on it"))?;
+
+        Ok(Self {
+            root: postgres_node(root),
+            raw,
+        })
+    }
+
+    /// Parses the `XML` showplan of SqlServer
+    #[cfg(feature = "mssql")]
+    pub(crate) fn from_sqlserver(raw: String) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let document = roxmltree::Document::parse(&raw)?;
+        let root = document
+            .descendants()
+            .find(|node| node.has_tag_name("RelOp"))
+            .map(sqlserver_node)
+            .ok_or_else(|| invalid_plan("SqlServer", "there's no `RelOp` on it"))?;
+
+        Ok(Self { root, raw })
+    }
+
+    /// Parses the output of `EXPLAIN FORMAT=JSON` on MySQL
+    #[cfg(feature = "mysql")]
+    pub(crate) fn from_mysql_json(raw: String) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let plan: serde_json::Value = serde_json::from_str(&raw)?;
+        let query_block = plan
+            .get("query_block")
+            .ok_or_else(|| invalid_plan("MySQL", "there's no `query_block` on it"))?;
+
+        Ok(Self {
+            root: mysql_json_node("query_block", query_block),
+            raw,
+        })
+    }
+
+    /// Parses the tree returned by `EXPLAIN ANALYZE` on MySQL, where every node
+    /// is a line that starts with `->`, indented under its parent
+    #[cfg(feature = "mysql")]
+    pub(crate) fn from_mysql_tree(raw: String) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        // The nodes being built, along with their indentation, from the root to the last one
+        let mut stack: Vec<(usize, PlanNode)> = Vec::new();
+        let mut root = None;
+
+        for line in raw.lines() {
+            let indentation = line.len() - line.trim_start().len();
+            let description = match line.trim_start().strip_prefix("-> ") {
+                Some(description) => description,
+                None => continue, // The continuation of the condition of the previous node
+            };
+
+            while stack
+                .last()
+                .is_some_and(|(parent_indentation, _)| *parent_indentation >= indentation)
+            {
+                let (_, node) = stack.pop().unwrap();
+                match stack.last_mut() {
+                    Some((_, parent)) => parent.children.push(node),
+                    None => root = Some(node),
+                }
+            }
+            stack.push((indentation, mysql_tree_node(description)));
+        }
+        while let Some((_, node)) = stack.pop() {
+            match stack.last_mut() {
+                Some((_, parent)) => parent.children.push(node),
+                None => root = Some(node),
+            }
+        }
+
+        Ok(Self {
+            root: root.ok_or_else(|| invalid_plan("MySQL", "there's no node on it"))?,
+            raw,
+        })
+    }
+}
+
+fn invalid_plan(database: &str, reason: &str) -> Box<dyn Error + Send + Sync> {
+    Box::new(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("The execution plan returned by {database} can't be read: {reason}"),
+    ))
+}
+
+#[cfg(feature = "postgres")]
+fn postgres_node(plan: &serde_json::Value) -> PlanNode {
+    let text = |key: &str| plan.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    let number = |key: &str| plan.get(key).and_then(|v| v.as_f64());
+
+    PlanNode {
+        node_type: text("Node Type").unwrap_or_default(),
+        relation: text("Relation Name"),
+        index: text("Index Name"),
+        estimated_rows: number("Plan Rows"),
+        actual_rows: number("Actual Rows").map(|rows| rows * number("Actual Loops").unwrap_or(1.0)),
+        cost: number("Total Cost"),
+        children: plan
+            .get("Plans")
+            .and_then(|plans| plans.as_array())
+            .map(|plans| plans.iter().map(postgres_node).collect())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(feature = "mssql")]
+fn sqlserver_node(rel_op: roxmltree::Node<'_, '_>) -> PlanNode {
+    // The nearest `RelOp` ancestor of an element is the operation that it belongs to
+    let owned_by_this = |node: &roxmltree::Node<'_, '_>| {
+        node.ancestors()
+            .skip(1)
+            .find(|ancestor| ancestor.has_tag_name("RelOp"))
+            == Some(rel_op)
+    };
+    let number = |name: &str| rel_op.attribute(name).and_then(|v| v.parse::<f64>().ok());
+    let object = rel_op
+        .descendants()
+        .find(|node| node.has_tag_name("Object") && owned_by_this(node));
+    let identifier = |name: &str| {
+        object
+            .and_then(|object| object.attribute(name))
+            .map(|v| v.trim_matches(['[', ']']).to_string())
+    };
+    let actual_rows = rel_op
+        .children()
+        .find(|node| node.has_tag_name("RunTimeInformation"))
+        .map(|runtime| {
+            runtime
+                .children()
+                .filter_map(|counters| counters.attribute("ActualRows"))
+                .filter_map(|rows| rows.parse::<f64>().ok())
+                .sum()
+        });
+
+    PlanNode {
+        node_type: rel_op
+            .attribute("PhysicalOp")
+            .unwrap_or_default()
+            .to_string(),
+        relation: identifier("Table"),
+        index: identifier("Index"),
+        estimated_rows: number("EstimateRows"),
+        actual_rows,
+        cost: number("EstimatedTotalSubtreeCost"),
+        children: rel_op
+            .descendants()
+            .skip(1)
+            .filter(|node| node.has_tag_name("RelOp") && owned_by_this(node))
+            .map(sqlserver_node)
+            .collect(),
+    }
+}
+
+/// Translates an element of the `JSON` plan of MySQL, where the tables are the leaves,
+/// and the rest of the objects (the query blocks, the nested loops, the sorts...) are
+/// named by their key
+#[cfg(feature = "mysql")]
+fn mysql_json_node(key: &str, plan: &serde_json::Value) -> PlanNode {
+    let text = |key: &str| plan.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    // MySQL writes most of its numbers as strings
+    let number = |value: Option<&serde_json::Value>| {
+        value.and_then(|v| v.as_f64().or_else(|| v.as_str()?.parse().ok()))
+    };
+    let cost_info = plan.get("cost_info");
+    let cost = number(cost_info.and_then(|c| c.get("query_cost")))
+        .or_else(|| number(cost_info.and_then(|c| c.get("prefix_cost"))));
+
+    let mut children = Vec::new();
+    if let Some(elements) = plan.as_object() {
+        for (key, value) in elements.iter().filter(|(key, _)| *key != "cost_info") {
+            match value {
+                serde_json::Value::Object(_) => children.push(mysql_json_node(key, value)),
+                serde_json::Value::Array(values) => {
+                    let nested: Vec<PlanNode> = values
+                        .iter()
+                        .filter_map(|value| value.as_object())
+                        .flat_map(|object| object.iter())
+                        .filter(|(_, value)| value.is_object())
+                        .map(|(key, value)| mysql_json_node(key, value))
+                        .collect();
+                    if !nested.is_empty() {
+                        children.push(PlanNode {
+                            node_type: key.to_string(),
+                            children: nested,
+                            ..Default::default()
+                        });
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    if key == "table" {
+        PlanNode {
+            node_type: text("access_type").unwrap_or_else(|| key.to_string()),
+            relation: text("table_name"),
+            index: text("key"),
+            estimated_rows: number(plan.get("rows_produced_per_join"))
+                .or_else(|| number(plan.get("rows_examined_per_scan"))),
+            actual_rows: None,
+            cost,
+            children,
+        }
+    } else {
+        PlanNode {
+            node_type: key.to_string(),
+            cost,
+            children,
+            ..Default::default()
+        }
+    }
+}
+
+/// Translates a line of the tree of MySQL, like
+/// `Index lookup on league using PRIMARY (id=1)  (cost=0.35 rows=1) (actual time=0.02..0.02 rows=1 loops=1)`
+#[cfg(feature = "mysql")]
+fn mysql_tree_node(description: &str) -> PlanNode {
+    let (operation, metrics) = description
+        .split_once("  (")
+        .map(|(operation, metrics)| (operation, format!("({metrics}")))
+        .unwrap_or((description, String::new()));
+
+    // The figures between parentheses, like `(cost=0.35 rows=1)`
+    let figure = |group: &str, name: &str| {
+        metrics
+            .split('(')
+            .find(|g| g.starts_with(group))
+            .and_then(|g| {
+                g.split([' ', ')'])
+                    .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                    .and_then(|v| v.parse::<f64>().ok())
+            })
+    };
+    let word_after = |marker: &str| {
+        operation
+            .split_once(marker)
+            .and_then(|(_, rest)| rest.split_whitespace().next())
+            .map(str::to_string)
+    };
+    let node_type = operation
+        .split_once(" on ")
+        .map(|(node_type, _)| node_type)
+        .or_else(|| operation.split_once(':').map(|(node_type, _)| node_type))
+        .unwrap_or(operation);
+
+    PlanNode {
+        node_type: node_type.trim().to_string(),
+        relation: word_after(" on "),
+        index: word_after(" using "),
+        estimated_rows: figure("cost", "rows"),
+        actual_rows: figure("actual", "rows")
+            .map(|rows| rows * figure("actual", "loops").unwrap_or(1.0)),
+        cost: figure("cost", "cost"),
+        children: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod query_plan_tests {
+    use super::*;
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_plan() {
+        let raw = r#"[{"Plan": {"Node Type": "Nested Loop", "Total Cost": 16.6, "Plan Rows": 1,
+            "Actual Rows": 1, "Plans": [
+                {"Node Type": "Index Scan", "Relation Name": "league", "Index Name": "league_pkey",
+                 "Total Cost": 8.17, "Plan Rows": 1, "Actual Rows": 1},
+                {"Node Type": "Seq Scan", "Relation Name": "tournament", "Total Cost": 8.4,
+                 "Plan Rows": 3, "Actual Rows": 0}
+            ]}}]"#;
+        let plan = QueryPlan::from_postgres(raw.to_string()).unwrap();
+
+        assert_eq!(plan.root.node_type, "Nested Loop");
+        assert_eq!(plan.root.cost, Some(16.6));
+        assert_eq!(plan.root.children.len(), 2);
+        assert!(plan.uses_index("league_pkey"));
+        assert!(plan.reads("tournament"));
+        assert_eq!(
+            plan.nodes()
+                .map(|n| n.node_type.as_str())
+                .collect::<Vec<_>>(),
+            ["Nested Loop", "Index Scan", "Seq Scan"]
+        );
+    }
+
+    #[cfg(feature = "mssql")]
+    #[test]
+    fn sqlserver_plan() {
+        let raw = r#"<ShowPlanXML xmlns="http://schemas.microsoft.com/sqlserver/2004/07/showplan">
+            <BatchSequence><Batch><Statements><StmtSimple><QueryPlan>
+            <RelOp PhysicalOp="Nested Loops" EstimateRows="1" EstimatedTotalSubtreeCost="0.0065">
+              <NestedLoops>
+                <RelOp PhysicalOp="Clustered Index Seek" EstimateRows="1" EstimatedTotalSubtreeCost="0.0032">
+                  <RunTimeInformation><RunTimeCountersPerThread Thread="0" ActualRows="1" /></RunTimeInformation>
+                  <IndexScan><Object Table="[league]" Index="[PK_league]" /></IndexScan>
+                </RelOp>
+              </NestedLoops>
+            </RelOp>
+            </QueryPlan></StmtSimple></Statements></Batch></BatchSequence></ShowPlanXML>"#;
+        let plan = QueryPlan::from_sqlserver(raw.to_string()).unwrap();
+
+        assert_eq!(plan.root.node_type, "Nested Loops");
+        assert_eq!(plan.root.relation, None);
+        let seek = &plan.root.children[0];
+        assert_eq!(seek.node_type, "Clustered Index Seek");
+        assert_eq!(seek.relation.as_deref(), Some("league"));
+        assert_eq!(seek.actual_rows, Some(1.0));
+        assert!(plan.uses_index("PK_league"));
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn mysql_plans() {
+        let raw = r#"{"query_block": {"select_id": 1, "cost_info": {"query_cost": "0.35"},
+            "table": {"table_name": "league", "access_type": "const", "key": "PRIMARY",
+            "rows_examined_per_scan": 1, "rows_produced_per_join": 1}}}"#;
+        let plan = QueryPlan::from_mysql_json(raw.to_string()).unwrap();
+        assert_eq!(plan.root.cost, Some(0.35));
+        assert_eq!(plan.root.children[0].node_type, "const");
+        assert!(plan.uses_index("PRIMARY"));
+
+        let raw = "-> Filter: (league.id > 1)  (cost=0.85 rows=3) (actual time=0.02..0.03 rows=2 loops=2)\n    \
+            -> Index range scan on league using PRIMARY over (1 < id)  (cost=0.85 rows=3)\n";
+        let plan = QueryPlan::from_mysql_tree(raw.to_string()).unwrap();
+        assert_eq!(plan.root.node_type, "Filter");
+        assert_eq!(plan.root.actual_rows, Some(4.0));
+        let scan = &plan.root.children[0];
+        assert_eq!(scan.node_type, "Index range scan");
+        assert_eq!(scan.relation.as_deref(), Some("league"));
+        assert_eq!(scan.estimated_rows, Some(3.0));
+        assert!(plan.uses_index("PRIMARY"));
+    }
+}
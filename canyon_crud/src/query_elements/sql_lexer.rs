@@ -0,0 +1,596 @@
+//! A minimal lexer for the SQL statements handled by Canyon, that splits them into
+//! the pieces that the query launchers must rewrite (the placeholders of the parameters
+//! and the quoted identifiers) and the ones that must be kept untouched (literals and comments).
+//!
+//! The statements are written by Canyon (and by the users on the raw queries) with the
+//! PostgreSQL placeholders and quoted identifiers, so the launchers of the other databases
+//! rewrites them with [`rewrite_stmt`] before sending them.
+use canyon_connection::canyon_database_connector::DatabaseType;
+
+use crate::bounds::QueryParameter;
+
+/// The syntax rules of every database that matters when a statement is split
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// `$tag$ ... $tag$` dollar-quoted strings and `E'...'` escape strings
+    PostgreSql,
+    /// `[...]` quoted identifiers
+    SqlServer,
+    /// Backslash escapes on the quoted pieces, `` `...` `` quoted identifiers and `#` comments
+    MySql,
+}
+
+impl From<DatabaseType> for SqlDialect {
+    fn from(database_type: DatabaseType) -> Self {
+        match database_type {
+            #[cfg(feature = "postgres")]
+            DatabaseType::PostgreSql => SqlDialect::PostgreSql,
+            #[cfg(feature = "mssql")]
+            DatabaseType::SqlServer => SqlDialect::SqlServer,
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL => SqlDialect::MySql,
+        }
+    }
+}
+
+/// A piece of an SQL statement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlToken<'a> {
+    /// Any SQL code that isn't part of the other tokens
+    Text(&'a str),
+    /// A string literal, like `'LEC'` or `$$LEC$$`, including its delimiters
+    Literal(&'a str),
+    /// A quoted identifier, like `"league"`, `` `league` `` or `[league]`, including its quotes
+    QuotedIdentifier(&'a str),
+    /// A line (`-- ...`) or block (`/* ... */`) comment
+    Comment(&'a str),
+    /// A positional placeholder, like `$1`, holding its (one-based) position. The
+    /// ones with a zero or too large position are kept as [`SqlToken::Text`]
+    Positional(usize),
+    /// A named placeholder, like `:name`, holding the name without the colon
+    Named(&'a str),
+}
+
+/// Splits the given SQL statement into [`SqlToken`]s, following the rules of the
+/// given dialect. Joining the tokens back gives the original statement
+pub fn tokenize(stmt: &str, dialect: SqlDialect) -> Vec<SqlToken<'_>> {
+    let bytes = stmt.as_bytes();
+    let backslash_escapes = dialect == SqlDialect::MySql;
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let (token, end) = match bytes[idx] {
+            b'\'' => {
+                // The PostgreSQL escape strings, like E'it\'s', accepts backslash escapes
+                let escape_string = dialect == SqlDialect::PostgreSql
+                    && idx > text_start
+                    && matches!(bytes[idx - 1], b'E' | b'e')
+                    && (idx < 2 || !is_identifier_byte(bytes[idx - 2]));
+                let end = quoted_end(bytes, idx, b'\'', backslash_escapes || escape_string);
+                (SqlToken::Literal(&stmt[idx..end]), end)
+            }
+            b'"' => {
+                let end = quoted_end(bytes, idx, b'"', backslash_escapes);
+                (SqlToken::QuotedIdentifier(&stmt[idx..end]), end)
+            }
+            b'`' if dialect == SqlDialect::MySql => {
+                let end = quoted_end(bytes, idx, b'`', false);
+                (SqlToken::QuotedIdentifier(&stmt[idx..end]), end)
+            }
+            b'[' if dialect == SqlDialect::SqlServer => {
+                let end = quoted_end(bytes, idx, b']', false);
+                (SqlToken::QuotedIdentifier(&stmt[idx..end]), end)
+            }
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                let end = line_end(stmt, idx);
+                (SqlToken::Comment(&stmt[idx..end]), end)
+            }
+            b'#' if dialect == SqlDialect::MySql => {
+                let end = line_end(stmt, idx);
+                (SqlToken::Comment(&stmt[idx..end]), end)
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                let end = stmt[idx + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |close| idx + 2 + close + 2);
+                (SqlToken::Comment(&stmt[idx..end]), end)
+            }
+            b'$' if bytes.get(idx + 1).is_some_and(u8::is_ascii_digit) => {
+                let end = take_while(bytes, idx + 1, |b| b.is_ascii_digit());
+                match stmt[idx + 1..end].parse() {
+                    Ok(position) if position > 0 => (SqlToken::Positional(position), end),
+                    // The zero or too large positions can't refer to any parameter
+                    _ => {
+                        idx = end;
+                        continue;
+                    }
+                }
+            }
+            b'$' if dialect == SqlDialect::PostgreSql => match dollar_quoted_end(stmt, idx) {
+                Some(end) => (SqlToken::Literal(&stmt[idx..end]), end),
+                None => {
+                    idx += 1;
+                    continue;
+                }
+            },
+            // The `::` of the PostgreSQL casts isn't the start of a named placeholder
+            b':' if bytes.get(idx + 1) == Some(&b':') => {
+                idx = take_while(bytes, idx, |b| b == b':');
+                continue;
+            }
+            b':' if bytes
+                .get(idx + 1)
+                .is_some_and(|b| b.is_ascii_alphabetic() || *b == b'_') =>
+            {
+                let end = take_while(bytes, idx + 1, is_identifier_byte);
+                (SqlToken::Named(&stmt[idx + 1..end]), end)
+            }
+            _ => {
+                idx += 1;
+                continue;
+            }
+        };
+
+        if text_start < idx {
+            tokens.push(SqlToken::Text(&stmt[text_start..idx]));
+        }
+        tokens.push(token);
+        idx = end;
+        text_start = end;
+    }
+
+    if text_start < bytes.len() {
+        tokens.push(SqlToken::Text(&stmt[text_start..]));
+    }
+    tokens
+}
+
+/// A statement rewritten for a concrete database by [`rewrite_stmt`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewrittenStmt {
+    /// The statement, with the placeholders and quoted identifiers of the target database
+    pub sql: String,
+    /// The (zero-based) index of the parameter that must be bound to every placeholder,
+    /// in order of appearance. Only needed by the databases with anonymous placeholders,
+    /// being empty otherwise
+    pub params_order: Vec<usize>,
+}
+
+/// Rewrites a statement written with the Canyon syntax (`$n` placeholders and double
+/// quoted identifiers) to the syntax of the given database. The string literals, the
+/// comments and the rest of quoted pieces are kept untouched.
+///
+/// - `PostgreSQL`: the statement is kept as is
+/// - `SqlServer`: the `$n` placeholders becomes `@Pn`
+/// - `MySQL`: the `$n` placeholders becomes `?`, and the `"id"` identifiers becomes `` `id` ``
+pub fn rewrite_stmt(stmt: &str, dialect: SqlDialect) -> RewrittenStmt {
+    if dialect == SqlDialect::PostgreSql {
+        return RewrittenStmt {
+            sql: stmt.to_string(),
+            params_order: Vec::new(),
+        };
+    }
+
+    let mut sql = String::with_capacity(stmt.len());
+    let mut params_order = Vec::new();
+    for token in tokenize(stmt, dialect) {
+        match (token, dialect) {
+            (SqlToken::Positional(position), SqlDialect::SqlServer) => {
+                sql.push_str("@P");
+                sql.push_str(&position.to_string());
+            }
+            (SqlToken::Positional(position), SqlDialect::MySql) => {
+                sql.push('?');
+                params_order.push(position - 1);
+            }
+            (SqlToken::QuotedIdentifier(identifier), SqlDialect::MySql)
+                if identifier.starts_with('"') =>
+            {
+                push_mysql_identifier(&mut sql, identifier)
+            }
+            (other, _) => other.push_to(&mut sql),
+        }
+    }
+
+    RewrittenStmt { sql, params_order }
+}
+
+/// Renders a statement written with the Canyon syntax for the given database, as
+/// [`rewrite_stmt`] does, but writing the values of the parameters as literals in place
+/// of their placeholders. The result is meant for logs and tests, not to be launched.
+///
+/// The placeholders without a parameter are kept as they are
+pub fn render_debug_sql(
+    stmt: &str,
+    params: &[&'_ dyn QueryParameter<'_>],
+    dialect: SqlDialect,
+) -> String {
+    let mut sql = String::with_capacity(stmt.len());
+    for token in tokenize(stmt, dialect) {
+        match token {
+            SqlToken::Positional(position) if position > 0 && position <= params.len() => {
+                sql.push_str(&params[position - 1].as_sql_literal().to_sql(dialect))
+            }
+            SqlToken::QuotedIdentifier(identifier)
+                if dialect == SqlDialect::MySql && identifier.starts_with('"') =>
+            {
+                push_mysql_identifier(&mut sql, identifier)
+            }
+            other => other.push_to(&mut sql),
+        }
+    }
+    sql
+}
+
+/// Finds the position of the first appearance of the given keyword on the statement,
+/// ignoring the case. The literals, quoted identifiers and comments are skipped, as the
+/// pieces between parentheses (like subqueries). The words of the keyword, like the ones
+/// of `ORDER BY`, may be separated by any whitespace
+pub fn find_keyword(stmt: &str, dialect: SqlDialect, keyword: &str) -> Option<usize> {
+    let bytes = stmt.as_bytes();
+    let mut depth = 0_usize;
+    for token in tokenize(stmt, dialect) {
+        let SqlToken::Text(text) = token else {
+            continue;
+        };
+        // Every token is a slice of the statement
+        let text_start = text.as_ptr() as usize - stmt.as_ptr() as usize;
+        for (idx, c) in text.char_indices() {
+            let position = text_start + idx;
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                _ if depth == 0
+                    && (position == 0 || !is_identifier_byte(bytes[position - 1]))
+                    && keyword_len(&stmt[position..], keyword).is_some() =>
+                {
+                    return Some(position)
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// The length of the given keyword at the start of the text (if it's there), with
+/// its words separated by any whitespace
+fn keyword_len(text: &str, keyword: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut idx = 0;
+    for (i, word) in keyword.split_whitespace().enumerate() {
+        if i > 0 {
+            let words_start = take_while(bytes, idx, |b| b.is_ascii_whitespace());
+            if words_start == idx {
+                return None;
+            }
+            idx = words_start;
+        }
+        if !text
+            .get(idx..idx + word.len())
+            .is_some_and(|w| w.eq_ignore_ascii_case(word))
+        {
+            return None;
+        }
+        idx += word.len();
+    }
+    (!bytes.get(idx).copied().is_some_and(is_identifier_byte)).then_some(idx)
+}
+
+/// Writes a double quoted identifier as a MySQL backtick quoted one
+fn push_mysql_identifier(sql: &mut String, identifier: &str) {
+    let unquoted = identifier
+        .strip_prefix('"')
+        .and_then(|i| i.strip_suffix('"'))
+        .unwrap_or(identifier);
+    sql.push('`');
+    sql.push_str(&unquoted.replace("\"\"", "\"").replace('`', "``"));
+    sql.push('`');
+}
+
+/// Finds the end of the quoted piece that starts at `start`, where a doubled closing
+/// quote is an escaped one, as any character preceded by a backslash when they're
+/// allowed. An unterminated piece lasts until the end of the statement
+fn quoted_end(bytes: &[u8], start: usize, closing: u8, backslash_escapes: bool) -> usize {
+    let mut idx = start + 1;
+    while idx < bytes.len() {
+        if backslash_escapes && bytes[idx] == b'\\' {
+            idx += 2;
+            continue;
+        }
+        if bytes[idx] == closing {
+            if bytes.get(idx + 1) == Some(&closing) {
+                idx += 2;
+                continue;
+            }
+            return idx + 1;
+        }
+        idx += 1;
+    }
+    bytes.len()
+}
+
+/// Finds the end of the PostgreSQL dollar-quoted string that starts at `start`
+/// (if it's really one), like `$$it's$$` or `$body$it's$body$`
+fn dollar_quoted_end(stmt: &str, start: usize) -> Option<usize> {
+    let bytes = stmt.as_bytes();
+    let tag_end = take_while(bytes, start + 1, is_identifier_byte);
+    if bytes.get(tag_end) != Some(&b'$') {
+        return None;
+    }
+
+    let tag = &stmt[start..=tag_end];
+    Some(
+        stmt[tag_end + 1..]
+            .find(tag)
+            .map_or(bytes.len(), |close| tag_end + 1 + close + tag.len()),
+    )
+}
+
+/// Finds the end of the line comment that starts at `start`, without the line break
+fn line_end(stmt: &str, start: usize) -> usize {
+    stmt[start..].find('\n').map_or(stmt.len(), |nl| start + nl)
+}
+
+/// Advances from `start` while the bytes satisfy the predicate
+fn take_while(bytes: &[u8], start: usize, predicate: impl Fn(u8) -> bool) -> usize {
+    let mut idx = start;
+    while idx < bytes.len() && predicate(bytes[idx]) {
+        idx += 1;
+    }
+    idx
+}
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+impl<'a> SqlToken<'a> {
+    /// Writes back the token as it was found on the statement
+    pub fn push_to(&self, stmt: &mut String) {
+        match self {
+            SqlToken::Text(s)
+            | SqlToken::Literal(s)
+            | SqlToken::QuotedIdentifier(s)
+            | SqlToken::Comment(s) => stmt.push_str(s),
+            SqlToken::Positional(position) => {
+                stmt.push('$');
+                stmt.push_str(&position.to_string());
+            }
+            SqlToken::Named(name) => {
+                stmt.push(':');
+                stmt.push_str(name);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod sql_lexer_tests {
+    use super::*;
+
+    /// The placeholders inside literals, quoted identifiers and comments are ignored
+    #[test]
+    fn tokenize_ignores_quoted_pieces_and_comments() {
+        let stmt =
+            "SELECT * FROM \"my:table\" WHERE a = :a AND b = 'it''s :b' -- :c\nAND d = $1 /* :e */";
+        assert_eq!(
+            tokenize(stmt, SqlDialect::PostgreSql),
+            vec![
+                SqlToken::Text("SELECT * FROM "),
+                SqlToken::QuotedIdentifier("\"my:table\""),
+                SqlToken::Text(" WHERE a = "),
+                SqlToken::Named("a"),
+                SqlToken::Text(" AND b = "),
+                SqlToken::Literal("'it''s :b'"),
+                SqlToken::Text(" "),
+                SqlToken::Comment("-- :c"),
+                SqlToken::Text("\nAND d = "),
+                SqlToken::Positional(1),
+                SqlToken::Text(" "),
+                SqlToken::Comment("/* :e */"),
+            ]
+        );
+    }
+
+    /// The PostgreSQL casts aren't named placeholders
+    #[test]
+    fn tokenize_skips_casts() {
+        assert_eq!(
+            tokenize("SELECT :value::text", SqlDialect::PostgreSql),
+            vec![
+                SqlToken::Text("SELECT "),
+                SqlToken::Named("value"),
+                SqlToken::Text("::text"),
+            ]
+        );
+    }
+
+    /// The dollar-quoted strings and the escape strings of PostgreSQL are literals
+    #[test]
+    fn tokenize_postgres_strings() {
+        assert_eq!(
+            tokenize(
+                "SELECT $$a $1$$, $tag$:b$tag$, E'\\' $2', $3",
+                SqlDialect::PostgreSql
+            ),
+            vec![
+                SqlToken::Text("SELECT "),
+                SqlToken::Literal("$$a $1$$"),
+                SqlToken::Text(", "),
+                SqlToken::Literal("$tag$:b$tag$"),
+                SqlToken::Text(", E"),
+                SqlToken::Literal("'\\' $2'"),
+                SqlToken::Text(", "),
+                SqlToken::Positional(3),
+            ]
+        );
+    }
+
+    /// MySQL accepts backslash escapes, backtick identifiers and `#` comments
+    #[test]
+    fn tokenize_mysql_strings() {
+        assert_eq!(
+            tokenize("SELECT 'it\\'s $1', `a$2` # $3\n, $4", SqlDialect::MySql),
+            vec![
+                SqlToken::Text("SELECT "),
+                SqlToken::Literal("'it\\'s $1'"),
+                SqlToken::Text(", "),
+                SqlToken::QuotedIdentifier("`a$2`"),
+                SqlToken::Text(" "),
+                SqlToken::Comment("# $3"),
+                SqlToken::Text("\n, "),
+                SqlToken::Positional(4),
+            ]
+        );
+    }
+
+    /// Joining the tokens gives back the original statement
+    #[test]
+    fn tokenize_roundtrip() {
+        let stmt = "UPDATE t SET a = $12, b = 'x' WHERE [c] = :c_1 /* unterminated";
+        let mut rebuilt = String::new();
+        tokenize(stmt, SqlDialect::SqlServer)
+            .iter()
+            .for_each(|t| t.push_to(&mut rebuilt));
+        assert_eq!(rebuilt, stmt);
+    }
+
+    /// Only the placeholders and identifiers outside the literals are rewritten
+    #[test]
+    fn rewrite_placeholders() {
+        let stmt = "UPDATE \"league\" SET \"slug\" = $2 WHERE id = $1 AND name <> '$1 \"x\"'";
+        assert_eq!(
+            rewrite_stmt(stmt, SqlDialect::SqlServer).sql,
+            "UPDATE \"league\" SET \"slug\" = @P2 WHERE id = @P1 AND name <> '$1 \"x\"'"
+        );
+        assert_eq!(
+            rewrite_stmt(stmt, SqlDialect::MySql),
+            RewrittenStmt {
+                sql: "UPDATE `league` SET `slug` = ? WHERE id = ? AND name <> '$1 \"x\"'"
+                    .to_string(),
+                params_order: vec![1, 0],
+            }
+        );
+        assert_eq!(rewrite_stmt(stmt, SqlDialect::PostgreSql).sql, stmt);
+    }
+
+    /// The placeholders whose position can't refer to any parameter are kept as text
+    #[test]
+    fn invalid_placeholders_are_text() {
+        let stmt = "SELECT $0, $99999999999999999999 FROM t WHERE id = $1";
+        assert_eq!(
+            tokenize(stmt, SqlDialect::MySql),
+            vec![
+                SqlToken::Text("SELECT $0, $99999999999999999999 FROM t WHERE id = "),
+                SqlToken::Positional(1),
+            ]
+        );
+        assert_eq!(
+            rewrite_stmt(stmt, SqlDialect::MySql),
+            RewrittenStmt {
+                sql: "SELECT $0, $99999999999999999999 FROM t WHERE id = ?".to_string(),
+                params_order: vec![0],
+            }
+        );
+        assert_eq!(
+            rewrite_stmt(stmt, SqlDialect::SqlServer).sql,
+            "SELECT $0, $99999999999999999999 FROM t WHERE id = @P1"
+        );
+    }
+
+    /// The keywords are found in any case, but not inside literals, identifiers,
+    /// comments or subqueries
+    #[test]
+    fn find_keywords() {
+        let stmt = "SELECT * FROM \"order by\" WHERE name = ' ORDER BY ' -- ORDER BY\n\
+            AND id IN (SELECT id FROM t ORDER BY id) order\n  by name";
+        let order_by = find_keyword(stmt, SqlDialect::PostgreSql, "ORDER BY");
+        assert_eq!(order_by.map(|idx| &stmt[idx..]), Some("order\n  by name"));
+        assert_eq!(
+            find_keyword(stmt, SqlDialect::PostgreSql, "WHERE"),
+            Some(25)
+        );
+        assert_eq!(find_keyword(stmt, SqlDialect::PostgreSql, "LIMIT"), None);
+        assert_eq!(
+            find_keyword("SELECT * FROM orders", SqlDialect::MySql, "ORDER"),
+            None
+        );
+    }
+
+    /// The values are quoted and escaped as literals of every dialect
+    #[test]
+    fn render_debug_placeholders() {
+        let stmt = "SELECT * FROM \"player\" WHERE id = $1 AND name = $2 AND active = $3 OR team = $4 AND nick = '$2'";
+        let none: Option<i32> = None;
+        let params: [&dyn QueryParameter<'_>; 4] = [&7_i32, &"O'Neil \\o/", &true, &none];
+
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::PostgreSql),
+            "SELECT * FROM \"player\" WHERE id = 7 AND name = 'O''Neil \\o/' AND active = TRUE OR team = NULL AND nick = '$2'"
+        );
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::SqlServer),
+            "SELECT * FROM \"player\" WHERE id = 7 AND name = N'O''Neil \\o/' AND active = 1 OR team = NULL AND nick = '$2'"
+        );
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::MySql),
+            "SELECT * FROM `player` WHERE id = 7 AND name = 'O''Neil \\\\o/' AND active = TRUE OR team = NULL AND nick = '$2'"
+        );
+    }
+    /// The UUIDs are written as strings, but MySQL stores them as `BINARY(16)`
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn render_debug_uuid_placeholders() {
+        let stmt = "SELECT * FROM broadcast WHERE id = $1";
+        let id = uuid::Uuid::from_u128(0x0190_1b5a_3c8e_7d2a_9f41_5e6b_7c8d_9e0f);
+        let params: [&dyn QueryParameter<'_>; 1] = [&id];
+
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::PostgreSql),
+            "SELECT * FROM broadcast WHERE id = '01901b5a-3c8e-7d2a-9f41-5e6b7c8d9e0f'"
+        );
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::MySql),
+            "SELECT * FROM broadcast WHERE id = UUID_TO_BIN('01901b5a-3c8e-7d2a-9f41-5e6b7c8d9e0f')"
+        );
+    }
+
+    /// The decimal values are written as unquoted numeric literals, keeping their scale
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn render_debug_decimal_placeholders() {
+        let stmt = "SELECT * FROM sponsor WHERE amount > $1";
+        let amount = rust_decimal::Decimal::new(-15_002_500, 4);
+        let params: [&dyn QueryParameter<'_>; 1] = [&amount];
+
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::SqlServer),
+            "SELECT * FROM sponsor WHERE amount > -1500.2500"
+        );
+    }
+
+    /// The binary values are written as the hexadecimal literals of every database
+    #[test]
+    fn render_debug_bytes_placeholders() {
+        let stmt = "UPDATE attachment SET content = $1, thumbnail = $2";
+        let content: Vec<u8> = vec![0x00, 0x1f, 0xff];
+        let thumbnail: Option<Vec<u8>> = None;
+        let params: [&dyn QueryParameter<'_>; 2] = [&content, &thumbnail];
+
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::PostgreSql),
+            "UPDATE attachment SET content = '\\x001FFF', thumbnail = NULL"
+        );
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::SqlServer),
+            "UPDATE attachment SET content = 0x001FFF, thumbnail = NULL"
+        );
+        assert_eq!(
+            render_debug_sql(stmt, &params, SqlDialect::MySql),
+            "UPDATE attachment SET content = X'001FFF', thumbnail = NULL"
+        );
+    }
+}
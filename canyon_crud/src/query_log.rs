@@ -0,0 +1,266 @@
+//! Structured logging of the statements launched against the databases, through the
+//! [`tracing`] crate.
+//!
+//! Every statement runs inside a `canyon_sql::statement` span, with the datasource, the
+//! dialect, the SQL text and the number of parameters as its fields, and an event is
+//! emitted when it finishes, with the number of rows retrieved or affected, the duration,
+//! and the error (if any). The level and the logging of the values of the parameters
+//! are configured per datasource, through the `log_level` and `log_params` properties.
+//!
+//! The statements that exceed the `slow_query_ms` of the datasource are reported
+//! through the [`crate::slow_query`] facility, even when the logging is `off`.
+use std::{
+    error::Error,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use canyon_connection::datasources::{DatasourceConfig, ParamsLogging};
+use canyon_connection::{get_database_config, DATASOURCES};
+use tracing::{field, Instrument, Level, Span};
+
+use crate::bounds::QueryParameter;
+use crate::metrics::{self, StatementMetrics};
+use crate::slow_query::{self, SlowQuery};
+
+/// Dispatches a `tracing` macro that requires a constant level over a runtime one
+macro_rules! with_level {
+    ($level:expr, $macro:ident!($($args:tt)*)) => {
+        if $level == Level::ERROR {
+            tracing::$macro!(Level::ERROR, $($args)*)
+        } else if $level == Level::WARN {
+            tracing::$macro!(Level::WARN, $($args)*)
+        } else if $level == Level::INFO {
+            tracing::$macro!(Level::INFO, $($args)*)
+        } else if $level == Level::DEBUG {
+            tracing::$macro!(Level::DEBUG, $($args)*)
+        } else {
+            tracing::$macro!(Level::TRACE, $($args)*)
+        }
+    };
+}
+
+/// The log of a statement launched against a datasource, that must be created before
+/// launching it, and used to run it
+pub(crate) struct StatementLog {
+    config: &'static DatasourceConfig,
+    span: Span,
+    level: Option<Level>,
+    created: Instant,
+    duration: Option<Duration>,
+}
+
+impl StatementLog {
+    /// Opens the span of the statement, following the logging configuration of the
+    /// given datasource
+    pub(crate) fn new(
+        datasource_name: &str,
+        stmt: &str,
+        params: &[&'_ dyn QueryParameter<'_>],
+    ) -> Self {
+        let config = get_database_config(datasource_name, &DATASOURCES);
+        let level = config
+            .properties
+            .log_level
+            .unwrap_or_default()
+            .as_tracing_level();
+
+        let span = match level {
+            Some(level) => with_level!(
+                level,
+                span!(
+                    "canyon_sql::statement",
+                    datasource = config.name.as_str(),
+                    dialect = ?config.get_db_type(),
+                    sql = stmt,
+                    params_count = params.len(),
+                    params = field::Empty,
+                    rows = field::Empty,
+                    duration_ms = field::Empty,
+                    error = field::Empty,
+                )
+            ),
+            None => Span::none(),
+        };
+
+        let params_logging = config.properties.log_params.unwrap_or_default();
+        if !span.is_disabled() && params_logging != ParamsLogging::Hidden {
+            span.record(
+                "params",
+                field::display(render_params(params, params_logging)),
+            );
+        }
+
+        Self {
+            config,
+            span,
+            level,
+            created: Instant::now(),
+            duration: None,
+        }
+    }
+
+    /// Launches the `statement` future inside the span, recording its outcome, where
+    /// `rows` retrieves the number of rows retrieved or affected from its result
+    pub(crate) async fn run<R, F>(
+        &mut self,
+        rows: impl FnOnce(&R) -> u64,
+        statement: F,
+    ) -> Result<R, Box<dyn Error + Send + Sync>>
+    where
+        F: Future<Output = Result<R, Box<dyn Error + Send + Sync>>>,
+    {
+        // The log is created before acquiring the connection of the datasource
+        let start = Instant::now();
+        let connection_wait = start.duration_since(self.created);
+        let result = statement.instrument(self.span.clone()).await;
+        let duration = start.elapsed();
+        self.duration = Some(duration);
+
+        let rows = result.as_ref().map(rows).unwrap_or_default();
+        metrics::record(StatementMetrics {
+            datasource: &self.config.name,
+            rows,
+            duration,
+            connection_wait,
+            error_class: result
+                .as_ref()
+                .err()
+                .map(|error| metrics::error_class(&**error)),
+        });
+
+        let level = match self.level {
+            Some(level) if !self.span.is_disabled() => level,
+            _ => return result,
+        };
+
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+        let span = &self.span;
+        span.record("duration_ms", duration_ms);
+        span.in_scope(|| match &result {
+            Ok(_) => {
+                span.record("rows", rows);
+                with_level!(level, event!(rows, duration_ms, "Statement executed"))
+            }
+            Err(error) => {
+                span.record("error", field::display(error));
+                tracing::error!(error = %error, duration_ms, "Statement failed")
+            }
+        });
+
+        result
+    }
+
+    /// Whether the statement has exceeded the `slow_query_ms` of the datasource
+    pub(crate) fn is_slow(&self) -> bool {
+        match (self.duration, self.config.properties.slow_query_ms) {
+            (Some(duration), Some(threshold)) => duration >= Duration::from_millis(threshold),
+            _ => false,
+        }
+    }
+
+    /// Whether the plan of the slow statements must be retrieved
+    pub(crate) fn explains_slow_queries(&self) -> bool {
+        self.config
+            .properties
+            .explain_slow_queries
+            .unwrap_or_default()
+    }
+
+    /// Logs the slow statement and hands it to the registered handler
+    pub(crate) fn report_slow(
+        &self,
+        stmt: &str,
+        params: &[&'_ dyn QueryParameter<'_>],
+        plan: Option<String>,
+    ) {
+        let slow_query = SlowQuery {
+            datasource: self.config.name.clone(),
+            sql: stmt.to_string(),
+            params: param_values(
+                params,
+                self.config.properties.log_params.unwrap_or_default(),
+            ),
+            duration: self.duration.unwrap_or_default(),
+            plan,
+        };
+
+        if self.level.is_some() {
+            let duration_ms = slow_query.duration.as_secs_f64() * 1000.0;
+            let threshold_ms = self.config.properties.slow_query_ms;
+            self.span.in_scope(|| {
+                tracing::warn!(
+                    duration_ms,
+                    threshold_ms,
+                    plan = slow_query.plan.as_deref(),
+                    "Slow statement"
+                )
+            });
+        }
+
+        slow_query::notify(&slow_query);
+    }
+}
+
+/// Renders the values of the parameters of an statement, following the
+/// [`ParamsLogging`] of the datasource
+fn render_params(params: &[&'_ dyn QueryParameter<'_>], logging: ParamsLogging) -> String {
+    format!("[{}]", param_values(params, logging).join(", "))
+}
+
+/// Formats every value of the parameters of an statement, following the
+/// [`ParamsLogging`] of the datasource. No value is retrieved when they are hidden
+fn param_values(params: &[&'_ dyn QueryParameter<'_>], logging: ParamsLogging) -> Vec<String> {
+    if logging == ParamsLogging::Hidden {
+        return Vec::new();
+    }
+
+    params
+        .iter()
+        .map(|param| {
+            let value = format!("{param:?}");
+            match logging {
+                ParamsLogging::Redacted if value != "None" => String::from("***"),
+                _ => value,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod query_log_tests {
+    use super::*;
+
+    /// The redacted values only tell if they are `NULL` or not
+    #[test]
+    fn render_redacted_params() {
+        let none: Option<i32> = None;
+        let params: [&dyn QueryParameter<'_>; 3] = [&1_i32, &"secret", &none];
+
+        assert_eq!(
+            render_params(&params, ParamsLogging::Values),
+            r#"[1, "secret", None]"#
+        );
+        assert_eq!(
+            render_params(&params, ParamsLogging::Redacted),
+            "[***, ***, None]"
+        );
+    }
+
+    /// The slow statements only carry the values of the parameters allowed by the
+    /// logging of the datasource
+    #[test]
+    fn slow_query_params_follow_the_logging() {
+        let params: [&dyn QueryParameter<'_>; 2] = [&1_i32, &"secret"];
+
+        assert!(param_values(&params, ParamsLogging::Hidden).is_empty());
+        assert_eq!(
+            param_values(&params, ParamsLogging::Redacted),
+            ["***", "***"]
+        );
+        assert_eq!(
+            param_values(&params, ParamsLogging::Values),
+            ["1", r#""secret""#]
+        );
+    }
+}
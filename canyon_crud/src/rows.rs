@@ -1,5 +1,6 @@
 use crate::crud::Transaction;
 use crate::mapper::RowMapper;
+use std::error::Error;
 use std::marker::PhantomData;
 
 /// Lightweight wrapper over the collection of results of the different crates
@@ -16,7 +17,8 @@ pub enum CanyonRows<T> {
     #[cfg(feature = "mysql")]
     MySQL(Vec<mysql_async::Row>),
 
-    UnusableTypeMarker(PhantomData<T>),
+    // `fn() -> T` keeps the rows `Send` whatever the entity is, given that no `T` is stored
+    UnusableTypeMarker(PhantomData<fn() -> T>),
 }
 
 impl<T> CanyonRows<T> {
@@ -44,8 +46,9 @@ impl<T> CanyonRows<T> {
         }
     }
 
-    /// Consumes `self` and returns the wrapped [`std::vec::Vec`] with the instances of T
-    pub fn into_results<Z: RowMapper<T>>(self) -> Vec<T>
+    /// Consumes `self` and returns the wrapped [`std::vec::Vec`] with the instances of T,
+    /// or the error of the first row that can't be decoded into one
+    pub fn into_results<Z: RowMapper<T>>(self) -> Result<Vec<T>, Box<dyn Error + Send + Sync>>
     where
         T: Transaction<T>,
     {
@@ -0,0 +1,69 @@
+//! Reporting of the statements that exceed the `slow_query_ms` threshold of their
+//! datasource.
+//!
+//! Every slow statement is logged with the `warn` level through `tracing`, and handed
+//! to the handler registered with [`set_slow_query_handler`] (if any), along with its
+//! execution plan when the `explain_slow_queries` property of the datasource is enabled.
+use std::{sync::RwLock, time::Duration};
+
+use canyon_connection::lazy_static::lazy_static;
+
+type SlowQueryHandler = Box<dyn Fn(&SlowQuery) + Send + Sync>;
+
+lazy_static! {
+    static ref SLOW_QUERY_HANDLER: RwLock<Option<SlowQueryHandler>> = RwLock::new(None);
+}
+
+/// A statement that took longer than the `slow_query_ms` of its datasource
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlowQuery {
+    /// The name of the datasource where the statement was launched
+    pub datasource: String,
+    /// The SQL text of the statement, as written by Canyon or the user
+    pub sql: String,
+    /// The values of the parameters of the statement, formatted with [`std::fmt::Debug`].
+    /// They follow the `log_params` of the datasource, so they are empty when the
+    /// parameters are hidden, and `***` when they are redacted
+    pub params: Vec<String>,
+    /// How long the statement took
+    pub duration: Duration,
+    /// The execution plan of the statement, when the `explain_slow_queries` property
+    /// of the datasource is enabled and the database was able to explain it. It's the
+    /// output of `EXPLAIN (FORMAT JSON)` on PostgreSQL, of `SET SHOWPLAN_XML ON` on
+    /// SqlServer and of `EXPLAIN FORMAT=JSON` on MySQL
+    pub plan: Option<String>,
+}
+
+/// Registers the function that receives every slow statement, replacing the previous one.
+///
+/// The handler is called on the task that launched the statement, after it has finished,
+/// so it should hand the [`SlowQuery`] to another task if it has to do any heavy work
+///
+/// ```ignore
+/// set_slow_query_handler(|slow_query| {
+///     metrics_sink.send(slow_query.sql.clone(), slow_query.duration);
+/// });
+/// ```
+pub fn set_slow_query_handler(handler: impl Fn(&SlowQuery) + Send + Sync + 'static) {
+    *SLOW_QUERY_HANDLER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Box::new(handler));
+}
+
+/// Removes the handler of the slow statements (if any)
+pub fn clear_slow_query_handler() {
+    *SLOW_QUERY_HANDLER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Hands the slow statement to the registered handler (if any)
+pub(crate) fn notify(slow_query: &SlowQuery) {
+    if let Some(handler) = SLOW_QUERY_HANDLER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+    {
+        handler(slow_query)
+    }
+}
@@ -0,0 +1,61 @@
+//! Extension point for the user defined types of the columns.
+//!
+//! A [`CanyonType`] is stored as another type already supported by Canyon, its
+//! representation, so implementing it once for a newtype allows to use the newtype as
+//! the type of the fields of the entities, as a query parameter and as a filter value
+//! on every database. The `#[canyon_type]` attribute over the implementation makes the
+//! migrations aware of the column datatypes of the custom type.
+//!
+//! ```ignore
+//! #[derive(Debug, Clone, PartialEq, Eq)]
+//! pub struct Email(String);
+//!
+//! #[canyon_type]
+//! impl CanyonType for Email {
+//!     type Repr = String;
+//!     const SQLSERVER_TYPE: Option<&'static str> = Some("NVARCHAR(320)");
+//!     const MYSQL_TYPE: Option<&'static str> = Some("VARCHAR(320)");
+//!
+//!     fn as_repr(&self) -> &Self::Repr {
+//!         &self.0
+//!     }
+//!
+//!     fn from_repr(repr: Self::Repr) -> Result<Self, Box<dyn Error + Send + Sync>> {
+//!         if repr.contains('@') {
+//!             Ok(Email(repr))
+//!         } else {
+//!             Err(format!("`{repr}` isn't a valid email").into())
+//!         }
+//!     }
+//! }
+//! ```
+use std::{error::Error, fmt::Debug};
+
+use crate::{bounds::QueryParameter, mapper::FromColumn};
+
+/// A user defined type that is stored in the database as its [`CanyonType::Repr`].
+///
+/// The values are encoded for every database as the query parameters of their
+/// representation, and decoded from the columns as the representation too, before being
+/// checked by [`CanyonType::from_repr`].
+pub trait CanyonType: Debug + Send + Sync + Sized + 'static {
+    /// The supported type that holds the value in the database
+    type Repr: for<'a> QueryParameter<'a> + FromColumn;
+
+    /// The datatype of the `PostgreSQL` columns created by the migrations, that
+    /// defaults to the one of the representation
+    const POSTGRES_TYPE: Option<&'static str> = None;
+    /// The datatype of the `SqlServer` columns created by the migrations, that
+    /// defaults to the one of the representation
+    const SQLSERVER_TYPE: Option<&'static str> = None;
+    /// The datatype of the `MySQL` columns created by the migrations, that
+    /// defaults to the one of the representation
+    const MYSQL_TYPE: Option<&'static str> = None;
+
+    /// The representation of the value, as it's sent to the database
+    fn as_repr(&self) -> &Self::Repr;
+
+    /// Builds the value from the representation read from the database, failing if it
+    /// isn't a valid one
+    fn from_repr(repr: Self::Repr) -> Result<Self, Box<dyn Error + Send + Sync>>;
+}
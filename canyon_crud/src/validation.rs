@@ -0,0 +1,241 @@
+//! Runtime support for the declarative validations of the fields of an entity,
+//! declared with the `#[validate(...)]` annotation.
+//!
+//! The `insert`, `insert_datasource`, `multi_insert` and `update` operations generated
+//! for an entity checks every declared rule before sending any statement to the
+//! database, returning a [`ValidationError`] with all the fields that didn't pass them.
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+    sync::Mutex,
+};
+
+use canyon_connection::lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// The compiled patterns of the `regex` validations, reused between checks
+    static ref VALIDATION_PATTERNS: Mutex<HashMap<String, Regex>> = Mutex::new(HashMap::new());
+}
+
+/// A field that didn't pass one of its validations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldValidationError {
+    /// The name of the field
+    pub field: String,
+    /// What was wrong with the value
+    pub message: String,
+}
+
+/// Error returned by the CRUD operations when the fields of the entity doesn't pass
+/// the validations declared over them. No statement has been sent to the database.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Every failed validation, in the order of declaration of the fields
+    pub errors: Vec<FieldValidationError>,
+}
+
+impl ValidationError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a failed validation for the given field
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.push(FieldValidationError {
+            field: field.to_string(),
+            message: message.into(),
+        })
+    }
+
+    /// Returns the failed validations for the given field (if any)
+    pub fn field_errors<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.errors
+            .iter()
+            .filter(move |e| e.field == field)
+            .map(|e| e.message.as_str())
+    }
+
+    /// Turns the collected errors into a [`Result`], being an error if
+    /// any validation has failed
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Validation failed: ")?;
+        for (idx, error) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "`{}` {}", error.field, error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationError {}
+
+/// The types that can be checked with the `length` validation. The length of the
+/// text types is measured in characters, and the one of the collections in elements.
+///
+/// A `None` value is never checked, so it's always valid.
+pub trait ValidateLength {
+    fn validation_length(&self) -> Option<usize>;
+}
+
+impl ValidateLength for String {
+    fn validation_length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl ValidateLength for &str {
+    fn validation_length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl<T> ValidateLength for Vec<T> {
+    fn validation_length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: ValidateLength> ValidateLength for Option<T> {
+    fn validation_length(&self) -> Option<usize> {
+        self.as_ref().and_then(|v| v.validation_length())
+    }
+}
+
+/// The types that can be checked with the `regex` validation.
+///
+/// A `None` value is never checked, so it's always valid.
+pub trait ValidateText {
+    fn validation_text(&self) -> Option<&str>;
+}
+
+impl ValidateText for String {
+    fn validation_text(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+}
+
+impl ValidateText for &str {
+    fn validation_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: ValidateText> ValidateText for Option<T> {
+    fn validation_text(&self) -> Option<&str> {
+        self.as_ref().and_then(|v| v.validation_text())
+    }
+}
+
+/// The types that can be checked with the `range` validation, where the
+/// `Value` is the type of the limits.
+///
+/// A `None` value is never checked, so it's always valid.
+pub trait ValidateRange {
+    type Value: PartialOrd + Display + Copy;
+
+    fn validation_value(&self) -> Option<Self::Value>;
+}
+
+macro_rules! impl_validate_range {
+    ($($ty:ty),*) => {
+        $(
+            impl ValidateRange for $ty {
+                type Value = $ty;
+
+                fn validation_value(&self) -> Option<Self::Value> {
+                    Some(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_range!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: ValidateRange> ValidateRange for Option<T> {
+    type Value = T::Value;
+
+    fn validation_value(&self) -> Option<Self::Value> {
+        self.as_ref().and_then(|v| v.validation_value())
+    }
+}
+
+/// Checks the `length(min = x, max = y)` validation of a field
+pub fn check_length<T: ValidateLength + ?Sized>(
+    errors: &mut ValidationError,
+    field: &str,
+    value: &T,
+    min: Option<usize>,
+    max: Option<usize>,
+) {
+    if let Some(length) = value.validation_length() {
+        if let Some(min) = min.filter(|min| length < *min) {
+            errors.add(field, format!("must have a length of at least {min}"));
+        }
+        if let Some(max) = max.filter(|max| length > *max) {
+            errors.add(field, format!("must have a length of at most {max}"));
+        }
+    }
+}
+
+/// Checks the `range(min = x, max = y)` validation of a field
+pub fn check_range<T: ValidateRange + ?Sized>(
+    errors: &mut ValidationError,
+    field: &str,
+    value: &T,
+    min: Option<T::Value>,
+    max: Option<T::Value>,
+) {
+    if let Some(value) = value.validation_value() {
+        if let Some(min) = min.filter(|min| value < *min) {
+            errors.add(field, format!("must be greater than or equal to {min}"));
+        }
+        if let Some(max) = max.filter(|max| value > *max) {
+            errors.add(field, format!("must be less than or equal to {max}"));
+        }
+    }
+}
+
+/// Checks the `regex = "pattern"` validation of a field. The patterns are
+/// compiled once, and reused by the later checks.
+pub fn check_regex<T: ValidateText + ?Sized>(
+    errors: &mut ValidationError,
+    field: &str,
+    value: &T,
+    pattern: &str,
+) {
+    if let Some(text) = value.validation_text() {
+        let mut patterns = VALIDATION_PATTERNS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let regex = patterns.entry(pattern.to_string()).or_insert_with(|| {
+            // The pattern has been already checked when the entity was compiled
+            Regex::new(pattern).expect("Invalid regular expression on a `validate` annotation")
+        });
+        if !regex.is_match(text) {
+            errors.add(field, format!("must match the pattern `{pattern}`"));
+        }
+    }
+}
+
+/// Checks the `custom = function` validation of a field, where the error returned
+/// by the user defined function becomes the message of the failed validation
+pub fn check_custom(errors: &mut ValidationError, field: &str, result: Result<(), String>) {
+    if let Err(message) = result {
+        errors.add(field, message);
+    }
+}
@@ -8,6 +8,7 @@ use syn::{
 };
 
 use super::entity_fields::EntityField;
+use super::field_annotation::EntityFieldAnnotation;
 
 /// Provides a convenient way of handling the data on any
 /// `CanyonEntity` struct annotated with the macro `#[canyon_entity]`
@@ -131,6 +132,7 @@ impl Parse for CanyonEntity {
             let struct_attribute = EntityField::try_from(&field)?;
             parsed_fields.push(struct_attribute)
         }
+        composite_primary_key(&mut parsed_fields)?;
 
         Ok(Self {
             struct_name: _struct.ident,
@@ -143,3 +145,34 @@ impl Parse for CanyonEntity {
         })
     }
 }
+
+/// The values of a composite primary key, made by many `#[primary_key]` fields, are
+/// always given by the user, so none of its columns is autoincremental, and it's an
+/// error to ask Canyon to generate any of them
+fn composite_primary_key(fields: &mut [EntityField]) -> syn::Result<()> {
+    let is_primary_key =
+        |attr: &EntityFieldAnnotation| matches!(attr, EntityFieldAnnotation::PrimaryKey(..));
+    let primary_keys = fields
+        .iter()
+        .filter(|field| field.attributes.iter().any(is_primary_key))
+        .count();
+    if primary_keys < 2 {
+        return Ok(());
+    }
+
+    for field in fields.iter_mut() {
+        for attr in field.attributes.iter_mut() {
+            if let EntityFieldAnnotation::PrimaryKey(autoincremental, generator) = attr {
+                if generator.is_some() {
+                    return Err(syn::Error::new(
+                        field.name.span(),
+                        "The fields of a composite primary key can't be generated",
+                    ));
+                }
+                *autoincremental = false;
+            }
+        }
+    }
+
+    Ok(())
+}
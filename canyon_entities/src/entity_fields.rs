@@ -2,7 +2,7 @@ use partialdebug::placeholder::PartialDebug;
 use proc_macro2::Ident;
 use quote::ToTokens;
 use std::convert::TryFrom;
-use syn::{Attribute, Field, Type};
+use syn::{Attribute, Field, GenericArgument, PathArguments, Type};
 
 use super::field_annotation::EntityFieldAnnotation;
 /// Represents any of the fields and annotations (if any valid annotation) found for an Rust struct
@@ -45,6 +45,21 @@ impl EntityField {
             }
         }
 
+        // The moments managed by Canyon are stored without time zone, always in UTC
+        let is_managed_timestamp = attributes.iter().any(|annotation| {
+            matches!(
+                annotation,
+                EntityFieldAnnotation::CreatedAt | EntityFieldAnnotation::UpdatedAt
+            )
+        });
+        if is_managed_timestamp && !is_naive_date_time(ty) {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "The `#[created_at]` and `#[updated_at]` annotations are only supported \
+                on `NaiveDateTime` or `Option<NaiveDateTime>` fields",
+            ));
+        }
+
         Ok(Self {
             name: name.clone(),
             field_type: ty.clone(),
@@ -67,3 +82,20 @@ impl TryFrom<&Field> for EntityField {
         Self::new(name, &field.attrs, &field.ty)
     }
 }
+
+/// Checks if the type is written as a `NaiveDateTime`, or as an `Option` of it
+fn is_naive_date_time(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    match &segment.arguments {
+        PathArguments::None => segment.ident == "NaiveDateTime",
+        PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+            matches!(args.args.first(), Some(GenericArgument::Type(inner)) if is_naive_date_time(inner))
+        }
+        _ => false,
+    }
+}
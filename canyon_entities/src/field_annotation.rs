@@ -1,13 +1,70 @@
 use proc_macro2::Ident;
 use std::{collections::HashMap, convert::TryFrom};
-use syn::{punctuated::Punctuated, Attribute, MetaNameValue, Token};
+use syn::{
+    parse::ParseStream, punctuated::Punctuated, Attribute, Expr, ExprLit, ExprUnary, Lit, LitStr,
+    MetaNameValue, Path, Token, UnOp,
+};
 
 /// The available annotations for a field that belongs to any struct
 /// annotaded with `#[canyon_entity]`
 #[derive(Debug, Clone)]
 pub enum EntityFieldAnnotation {
-    PrimaryKey(bool),
-    ForeignKey(String, String),
+    PrimaryKey(bool, Option<KeyGenerator>),
+    /// `#[foreign_key(table = "t", column = "c")]`, along with the name of the `constraint`
+    /// shared by the fields that are the columns of a composite foreign key
+    ForeignKey(String, String, Option<String>),
+    Version,
+    CreatedAt,
+    UpdatedAt,
+    Validate(Vec<FieldValidation>),
+    /// `#[column(precision = p, scale = s)]`, the definition of the column in the database
+    Column {
+        precision: Option<u32>,
+        scale: Option<u32>,
+    },
+}
+
+/// The strategies available to generate the value of a primary key on the client side,
+/// declared with `#[primary_key(generate = "...")]`, instead of letting the database
+/// to generate it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyGenerator {
+    /// `generate = "uuid_v4"`, a random UUID
+    UuidV4,
+    /// `generate = "uuid_v7"`, a time ordered UUID
+    UuidV7,
+}
+
+impl KeyGenerator {
+    /// The value of the `generate` argument that selects the strategy
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UuidV4 => "uuid_v4",
+            Self::UuidV7 => "uuid_v7",
+        }
+    }
+}
+
+/// The rules declared over a field with the `#[validate(...)]` attribute, that the
+/// `insert` and `update` operations checks before sending any statement to the database
+#[derive(Debug, Clone)]
+pub enum FieldValidation {
+    /// `length(min = x, max = y)`, over the number of characters (or elements) of the value
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// `range(min = x, max = y)`, over numeric values. The limits are kept as they
+    /// were written, so they can be typed later as the type of the field
+    Range {
+        min: Option<String>,
+        max: Option<String>,
+    },
+    /// `regex = "pattern"`, checked at compile time
+    Regex(String),
+    /// `custom = path::to::function`, that receives a reference to the field and
+    /// returns a `Result<(), String>`
+    Custom(String),
 }
 
 impl EntityFieldAnnotation {
@@ -15,12 +72,33 @@ impl EntityFieldAnnotation {
     /// operations that requires character matching
     pub fn get_as_string(&self) -> String {
         match self {
-            Self::PrimaryKey(autoincremental) => {
+            Self::PrimaryKey(autoincremental, None) => {
                 format!("Annotation: PrimaryKey, Autoincremental: {autoincremental}")
             }
-            Self::ForeignKey(table, column) => {
+            Self::PrimaryKey(autoincremental, Some(generator)) => format!(
+                "Annotation: PrimaryKey, Autoincremental: {autoincremental}, Generate: {}",
+                generator.as_str()
+            ),
+            Self::ForeignKey(table, column, None) => {
                 format!("Annotation: ForeignKey, Table: {table}, Column: {column}")
             }
+            Self::ForeignKey(table, column, Some(constraint)) => format!(
+                "Annotation: ForeignKey, Table: {table}, Column: {column}, Constraint: {constraint}"
+            ),
+            Self::Version => "Annotation: Version".to_string(),
+            Self::CreatedAt => "Annotation: CreatedAt".to_string(),
+            Self::UpdatedAt => "Annotation: UpdatedAt".to_string(),
+            Self::Validate(_) => "Annotation: Validate".to_string(),
+            Self::Column { precision, scale } => {
+                let mut annotation = "Annotation: Column".to_string();
+                if let Some(precision) = precision {
+                    annotation.push_str(&format!(", Precision: {precision}"));
+                }
+                if let Some(scale) = scale {
+                    annotation.push_str(&format!(", Scale: {scale}"));
+                }
+                annotation
+            }
         }
     }
 
@@ -31,14 +109,37 @@ impl EntityFieldAnnotation {
     ) -> syn::Result<Self> {
         match attr_args {
             Ok(name_value) => {
-                let mut data: HashMap<String, bool> = HashMap::new();
+                let mut autoincremental = None;
+                let mut generator = None;
                 for nv in name_value {
                     // The identifier
                     let attr_value_ident = nv.path.get_ident().unwrap().to_string();
                     // The value after the Token[=]
-                    let attr_value = match &nv.lit {
+                    match (attr_value_ident.as_str(), &nv.lit) {
+                        ("generate", syn::Lit::Str(v)) => {
+                            generator = Some(match v.value().as_str() {
+                                "uuid_v4" => KeyGenerator::UuidV4,
+                                "uuid_v7" => KeyGenerator::UuidV7,
+                                other => {
+                                    return Err(syn::Error::new_spanned(
+                                        v,
+                                        format!("Unknown key generator `{other}`. Expected `uuid_v4` or `uuid_v7`"),
+                                    ))
+                                }
+                            })
+                        }
+                        ("generate", _) => {
+                            return Err(syn::Error::new_spanned(
+                                nv.path.clone(),
+                                "Only string literals are supported for the `generate` attribute",
+                            ))
+                        }
+                        (_, syn::Lit::Bool(v)) => {
+                            if attr_value_ident == "autoincremental" {
+                                autoincremental = Some(v.value())
+                            }
+                        }
                         // Error if the token is not a boolean literal
-                        syn::Lit::Bool(v) => v.value(),
                         _ => {
                             return Err(syn::Error::new_spanned(
                                 nv.path.clone(),
@@ -49,24 +150,29 @@ impl EntityFieldAnnotation {
                             ))
                         }
                     };
-                    data.insert(attr_value_ident, attr_value);
                 }
 
-                Ok(EntityFieldAnnotation::PrimaryKey(
-                    match data.get("autoincremental") {
-                        Some(aut) => aut.to_owned(),
-                        None => {
-                            // TODO En vez de error, false para default
-                            return Err(syn::Error::new_spanned(
-                                ident,
-                                "Missed `autoincremental` argument on the Primary Key annotation"
-                                    .to_string(),
-                            ));
-                        }
-                    },
-                ))
+                match (autoincremental, generator) {
+                    // The keys generated by Canyon are never generated by the database
+                    (Some(true), Some(_)) => Err(syn::Error::new_spanned(
+                        ident,
+                        "A Primary Key with a `generate` argument can't be `autoincremental`",
+                    )),
+                    (_, Some(generator)) => {
+                        Ok(EntityFieldAnnotation::PrimaryKey(false, Some(generator)))
+                    }
+                    (Some(autoincremental), None) => {
+                        Ok(EntityFieldAnnotation::PrimaryKey(autoincremental, None))
+                    }
+                    // TODO En vez de error, false para default
+                    (None, None) => Err(syn::Error::new_spanned(
+                        ident,
+                        "Missed `autoincremental` argument on the Primary Key annotation"
+                            .to_string(),
+                    )),
+                }
             }
-            Err(_) => Ok(EntityFieldAnnotation::PrimaryKey(true)),
+            Err(_) => Ok(EntityFieldAnnotation::PrimaryKey(true, None)),
         }
     }
 
@@ -118,6 +224,7 @@ impl EntityFieldAnnotation {
                             ))
                         }
                     },
+                    data.get("constraint").cloned(),
                 ))
             }
             Err(_) => Err(syn::Error::new_spanned(
@@ -126,6 +233,235 @@ impl EntityFieldAnnotation {
             )),
         }
     }
+
+    /// Retrieves the definition of the column declared in the #[column] attribute,
+    /// like `#[column(precision = 19, scale = 4)]`
+    fn column_parser(
+        ident: &Ident,
+        attr_args: &Result<Punctuated<MetaNameValue, Token![,]>, syn::Error>,
+    ) -> syn::Result<Self> {
+        let name_value = match attr_args {
+            Ok(name_value) if !name_value.is_empty() => name_value,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "The `column` annotation requires at least one argument",
+                ))
+            }
+        };
+
+        let (mut precision, mut scale) = (None, None);
+        for nv in name_value {
+            // The identifier
+            let attr_value_ident = nv.path.get_ident().unwrap().to_string();
+            // The value after the Token[=]
+            let attr_value = match &nv.lit {
+                // Error if the token is not an integer literal
+                syn::Lit::Int(v) => v.base10_parse::<u32>()?,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        nv.path.clone(),
+                        format!("Only integer literals are supported for the `{attr_value_ident}` attribute"),
+                    ))
+                }
+            };
+            match attr_value_ident.as_str() {
+                "precision" => precision = Some(attr_value),
+                "scale" => scale = Some(attr_value),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        nv.path.clone(),
+                        format!("Unknown argument `{attr_value_ident}` for the `column` annotation. Expected `precision` or `scale`"),
+                    ))
+                }
+            }
+        }
+
+        match (precision, scale) {
+            (Some(0), _) => Err(syn::Error::new_spanned(
+                ident,
+                "The precision of a column must be greater than zero",
+            )),
+            (None, Some(_)) => Err(syn::Error::new_spanned(
+                ident,
+                "The `scale` of a column requires its `precision`",
+            )),
+            (Some(p), Some(s)) if s > p => Err(syn::Error::new_spanned(
+                ident,
+                "The `scale` of a column can't be greater than its `precision`",
+            )),
+            _ => Ok(EntityFieldAnnotation::Column { precision, scale }),
+        }
+    }
+
+    /// Checks that the attributes that just mark a field, like the #[version] one,
+    /// that marks the column used for the optimistic locking of the entity, or the
+    /// #[created_at] and #[updated_at] ones, doesn't receive any argument
+    fn marker_parser(
+        ident: &Ident,
+        attr_args: &Result<Punctuated<MetaNameValue, Token![,]>, syn::Error>,
+        annotation: Self,
+    ) -> syn::Result<Self> {
+        match attr_args {
+            Ok(name_value) if !name_value.is_empty() => Err(syn::Error::new_spanned(
+                ident,
+                format!("The `{ident}` annotation doesn't accept arguments"),
+            )),
+            _ => Ok(annotation),
+        }
+    }
+
+    /// Retrieves the rules declared in the #[validate] attribute, like
+    /// `#[validate(length(max = 64), regex = "^[a-z-]+$")]`
+    fn validate_parser(attribute: &Attribute) -> syn::Result<Self> {
+        let validations = attribute.parse_args_with(|input: ParseStream| {
+            let mut validations = Vec::new();
+            while !input.is_empty() {
+                let rule: Ident = input.parse()?;
+                validations.push(match rule.to_string().as_str() {
+                    "length" => {
+                        let (min, max) = Self::validation_bounds(input, &rule)?;
+                        FieldValidation::Length {
+                            min: min.map(|v| Self::length_bound(&v)).transpose()?,
+                            max: max.map(|v| Self::length_bound(&v)).transpose()?,
+                        }
+                    }
+                    "range" => {
+                        let (min, max) = Self::validation_bounds(input, &rule)?;
+                        FieldValidation::Range {
+                            min: min.map(|v| Self::range_bound(&v)).transpose()?,
+                            max: max.map(|v| Self::range_bound(&v)).transpose()?,
+                        }
+                    }
+                    "regex" => {
+                        input.parse::<Token![=]>()?;
+                        let pattern: LitStr = input.parse()?;
+                        if let Err(e) = regex::Regex::new(&pattern.value()) {
+                            return Err(syn::Error::new_spanned(
+                                pattern,
+                                format!("Invalid regular expression: {e}"),
+                            ));
+                        }
+                        FieldValidation::Regex(pattern.value())
+                    }
+                    "custom" => {
+                        input.parse::<Token![=]>()?;
+                        let function: Path = if input.peek(LitStr) {
+                            input.parse::<LitStr>()?.parse()?
+                        } else {
+                            input.parse()?
+                        };
+                        FieldValidation::Custom(quote::ToTokens::to_token_stream(&function).to_string())
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            rule.clone(),
+                            format!("Unknown validation `{rule}`. Expected one of `length`, `range`, `regex` or `custom`"),
+                        ))
+                    }
+                });
+
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+            }
+            Ok(validations)
+        })?;
+
+        if validations.is_empty() {
+            return Err(syn::Error::new_spanned(
+                attribute,
+                "The `validate` annotation requires at least one validation",
+            ));
+        }
+        Ok(EntityFieldAnnotation::Validate(validations))
+    }
+
+    /// Parses the `(min = x, max = y)` arguments of the `length` and `range` validations,
+    /// being both of them optional, but not at the same time
+    fn validation_bounds(
+        input: ParseStream,
+        rule: &Ident,
+    ) -> syn::Result<(Option<Expr>, Option<Expr>)> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let (mut min, mut max) = (None, None);
+        while !content.is_empty() {
+            let bound: Ident = content.parse()?;
+            content.parse::<Token![=]>()?;
+            let value: Expr = content.parse()?;
+            match bound.to_string().as_str() {
+                "min" => min = Some(value),
+                "max" => max = Some(value),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        bound.clone(),
+                        format!("Unknown argument `{bound}` for the `{rule}` validation. Expected `min` or `max`"),
+                    ))
+                }
+            }
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        if min.is_none() && max.is_none() {
+            return Err(syn::Error::new_spanned(
+                rule,
+                format!("The `{rule}` validation requires a `min` or a `max` argument"),
+            ));
+        }
+        Ok((min, max))
+    }
+
+    /// The limits of the `length` validation must be non-negative integer literals
+    fn length_bound(value: &Expr) -> syn::Result<usize> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(v), ..
+            }) => v.base10_parse::<usize>(),
+            _ => Err(syn::Error::new_spanned(
+                value,
+                "Only non-negative integer literals are supported as `length` limits",
+            )),
+        }
+    }
+
+    /// The limits of the `range` validation must be numeric literals, optionally negated
+    fn range_bound(value: &Expr) -> syn::Result<String> {
+        let is_numeric = |lit: &Lit| matches!(lit, Lit::Int(_) | Lit::Float(_));
+        match value {
+            Expr::Lit(ExprLit { lit, .. }) if is_numeric(lit) => Ok(Self::unsuffixed(lit)),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => match expr.as_ref() {
+                Expr::Lit(ExprLit { lit, .. }) if is_numeric(lit) => {
+                    Ok(format!("-{}", Self::unsuffixed(lit)))
+                }
+                _ => Err(syn::Error::new_spanned(
+                    value,
+                    "Only numeric literals are supported as `range` limits",
+                )),
+            },
+            _ => Err(syn::Error::new_spanned(
+                value,
+                "Only numeric literals are supported as `range` limits",
+            )),
+        }
+    }
+
+    /// Removes the type suffix of a numeric literal (if any), given that the limit
+    /// will be typed as the field that is validated
+    fn unsuffixed(lit: &Lit) -> String {
+        match lit {
+            Lit::Int(v) => v.base10_digits().to_string(),
+            Lit::Float(v) => v.base10_digits().to_string(),
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl TryFrom<&&Attribute> for EntityFieldAnnotation {
@@ -133,12 +469,31 @@ impl TryFrom<&&Attribute> for EntityFieldAnnotation {
 
     fn try_from(attribute: &&Attribute) -> Result<Self, Self::Error> {
         let ident = attribute.path.segments[0].ident.clone();
+        if ident == "validate" {
+            return EntityFieldAnnotation::validate_parser(attribute);
+        }
         let name_values: Result<Punctuated<MetaNameValue, Token![,]>, syn::Error> =
             attribute.parse_args_with(Punctuated::parse_terminated);
 
         Ok(match ident.to_string().as_str() {
             "primary_key" => EntityFieldAnnotation::primary_key_parser(&ident, &name_values)?,
             "foreign_key" => EntityFieldAnnotation::foreign_key_parser(&ident, &name_values)?,
+            "column" => EntityFieldAnnotation::column_parser(&ident, &name_values)?,
+            "version" => EntityFieldAnnotation::marker_parser(
+                &ident,
+                &name_values,
+                EntityFieldAnnotation::Version,
+            )?,
+            "created_at" => EntityFieldAnnotation::marker_parser(
+                &ident,
+                &name_values,
+                EntityFieldAnnotation::CreatedAt,
+            )?,
+            "updated_at" => EntityFieldAnnotation::marker_parser(
+                &ident,
+                &name_values,
+                EntityFieldAnnotation::UpdatedAt,
+            )?,
             _ => {
                 return Err(syn::Error::new_spanned(
                     ident.clone(),
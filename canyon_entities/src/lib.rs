@@ -1,4 +1,4 @@
-use crate::register_types::CanyonRegisterEntity;
+use crate::register_types::{CanyonRegisterEntity, CanyonRegisterEnum, CanyonRegisterType};
 use std::sync::Mutex;
 
 pub mod entity;
@@ -9,3 +9,7 @@ pub mod register_types;
 
 pub static CANYON_REGISTER_ENTITIES: Mutex<Vec<CanyonRegisterEntity<'static>>> =
     Mutex::new(Vec::new());
+
+pub static CANYON_REGISTER_ENUMS: Mutex<Vec<CanyonRegisterEnum>> = Mutex::new(Vec::new());
+
+pub static CANYON_REGISTER_TYPES: Mutex<Vec<CanyonRegisterType>> = Mutex::new(Vec::new());
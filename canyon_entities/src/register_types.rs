@@ -13,6 +13,38 @@ pub struct CanyonRegisterEntity<'a> {
     pub entity_fields: Vec<CanyonRegisterEntityField>,
 }
 
+/// Gets the identifiers of a Rust enum derived with `CanyonEnum`, so the migrations
+/// knows the datatype of the columns that stores it
+#[derive(Debug, Clone, Default)]
+pub struct CanyonRegisterEnum {
+    pub enum_name: String,
+    pub storage: CanyonRegisterEnumStorage,
+    pub labels: Vec<String>,
+}
+
+/// How the values of a [`CanyonRegisterEnum`] are stored in the database
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CanyonRegisterEnumStorage {
+    #[default]
+    Text,
+    Integer,
+    /// A `PostgreSQL` enumerated type, with the given name
+    Native(String),
+}
+
+/// Gets the representation of a Rust type that implements `CanyonType` with the
+/// `#[canyon_type]` attribute, so the migrations knows the datatype of the columns
+/// that stores it
+#[derive(Debug, Clone, Default)]
+pub struct CanyonRegisterType {
+    pub type_name: String,
+    /// The type that holds the value in the database
+    pub repr_type: String,
+    pub postgres_type: Option<String>,
+    pub sqlserver_type: Option<String>,
+    pub mysql_type: Option<String>,
+}
+
 /// Complementary type for a field that represents a struct field that maps
 /// some real database column data
 #[derive(Debug, Clone, Default)]
@@ -38,6 +70,31 @@ impl CanyonRegisterEntityField {
         NUMERIC_PK_DATATYPE.contains(&self.field_type.as_str()) && pk_is_autoincremental
     }
 
+    /// Return if the field holds a moment managed by Canyon, so it's annotated
+    /// with `#[created_at]` or `#[updated_at]`
+    pub fn is_managed_timestamp(&self) -> bool {
+        self.annotations
+            .iter()
+            .any(|a| a == "Annotation: CreatedAt" || a == "Annotation: UpdatedAt")
+    }
+
+    /// Return the precision and the scale (if declared) of the column, given
+    /// with the `#[column(precision = p, scale = s)]` annotation
+    pub fn precision_and_scale(&self) -> Option<(u32, Option<u32>)> {
+        let annotation = self
+            .annotations
+            .iter()
+            .find(|a| a.starts_with("Annotation: Column"))?;
+        let argument = |name: &str| {
+            annotation
+                .split(", ")
+                .find_map(|arg| arg.strip_prefix(name))
+                .and_then(|value| value.parse::<u32>().ok())
+        };
+
+        Some((argument("Precision: ")?, argument("Scale: ")))
+    }
+
     /// Return the nullability of a the field
     pub fn is_nullable(&self) -> bool {
         self.field_type.to_uppercase().starts_with("OPTION")
@@ -4,12 +4,14 @@ use syn::NestedMeta;
 pub(crate) fn parse_canyon_entity_proc_macro_attr(
     attrs: Vec<NestedMeta>,
 ) -> (
+    Option<&'static str>,
     Option<&'static str>,
     Option<&'static str>,
     Option<TokenStream>,
 ) {
     let mut table_name: Option<&str> = None;
     let mut schema_name: Option<&str> = None;
+    let mut soft_delete_column: Option<&str> = None;
 
     let mut parsing_attribute_error: Option<TokenStream> = None;
 
@@ -25,13 +27,19 @@ pub(crate) fn parse_canyon_entity_proc_macro_attr(
                             .expect("Something went wrong parsing the `table_name` argument")
                             .to_string();
 
-                        if &attr_arg_ident == "table_name" || &attr_arg_ident == "schema" {
+                        if &attr_arg_ident == "table_name"
+                            || &attr_arg_ident == "schema"
+                            || &attr_arg_ident == "soft_delete"
+                        {
                             match nv.lit {
                                 syn::Lit::Str(ref l) => {
                                     if &attr_arg_ident == "table_name" {
                                         table_name = Some(Box::leak(l.value().into_boxed_str()))
-                                    } else {
+                                    } else if &attr_arg_ident == "schema" {
                                         schema_name = Some(Box::leak(l.value().into_boxed_str()))
+                                    } else {
+                                        soft_delete_column =
+                                            Some(Box::leak(l.value().into_boxed_str()))
                                     }
                                 }
                                 _ => {
@@ -54,6 +62,8 @@ pub(crate) fn parse_canyon_entity_proc_macro_attr(
                             );
                         }
                     }
+                    // The flag that enables the lifecycle hooks, handled by the `CanyonCrud` derive
+                    syn::Meta::Path(ref path) if path.is_ident("hooks") => {}
                     _ => {
                         parsing_attribute_error = Some(syn::Error::new(
                             Span::call_site(),
@@ -71,5 +81,10 @@ pub(crate) fn parse_canyon_entity_proc_macro_attr(
         }
     }
 
-    (table_name, schema_name, parsing_attribute_error)
+    (
+        table_name,
+        schema_name,
+        soft_delete_column,
+        parsing_attribute_error,
+    )
 }
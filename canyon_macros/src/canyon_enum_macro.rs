@@ -0,0 +1,282 @@
+//! Provides the helpers to build the `#[derive(CanyonEnum)]` procedural macro
+
+use canyon_entities::register_types::{CanyonRegisterEnum, CanyonRegisterEnumStorage};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+use crate::utils::helpers;
+
+/// The arguments given with the `#[canyon(...)]` attributes over the enum
+#[derive(Default)]
+struct CanyonEnumArgs {
+    storage: Option<String>,
+    type_name: Option<String>,
+    rename_all: Option<String>,
+}
+
+/// Generates the implementations of the `CanyonEnum` and `CanyonType` traits, and returns
+/// the data that the migrations needs
+pub(crate) fn generate_canyon_enum_tokens(
+    ast: &DeriveInput,
+) -> Result<(TokenStream, CanyonRegisterEnum), syn::Error> {
+    let ty = &ast.ident;
+    let variants = match &ast.data {
+        syn::Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new(
+                ty.span(),
+                "CanyonEnum only works with enums",
+            ))
+        }
+    };
+    if variants.is_empty() || !ast.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            ty.span(),
+            "CanyonEnum only works with non generic enums with at least one variant",
+        ));
+    }
+    if let Some(variant) = variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            "CanyonEnum only works with fieldless enums",
+        ));
+    }
+
+    let args = parse_canyon_enum_args(&ast.attrs)?;
+    let storage = match args.storage.as_deref() {
+        None | Some("text") => CanyonRegisterEnumStorage::Text,
+        Some("integer") => CanyonRegisterEnumStorage::Integer,
+        Some("native") => CanyonRegisterEnumStorage::Native(args.type_name.unwrap_or_else(|| {
+            helpers::default_database_table_name_from_entity_name(&ty.to_string())
+        })),
+        Some(other) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!("Unknown storage: `{other}`. Use `text`, `integer` or `native`"),
+            ))
+        }
+    };
+
+    let mut labels = Vec::new();
+    for variant in variants {
+        let label = match parse_canyon_variant_rename(&variant.attrs)? {
+            Some(rename) => rename,
+            None => rename_variant(&variant.ident.to_string(), args.rename_all.as_deref())?,
+        };
+        if labels.contains(&label) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!("The label `{label}` is used by more than one variant"),
+            ));
+        }
+        labels.push(label);
+    }
+
+    let storage_tokens = match &storage {
+        CanyonRegisterEnumStorage::Text => quote! { canyon_sql::crud::enums::EnumStorage::Text },
+        CanyonRegisterEnumStorage::Integer => {
+            quote! { canyon_sql::crud::enums::EnumStorage::Integer }
+        }
+        CanyonRegisterEnumStorage::Native(type_name) => {
+            quote! { canyon_sql::crud::enums::EnumStorage::Native(#type_name) }
+        }
+    };
+    // The enums are stored as their labels, or as their discriminants
+    let (repr_ty, repr_values, from_repr) = match &storage {
+        CanyonRegisterEnumStorage::Integer => (
+            quote! { i32 },
+            quote! { DISCRIMINANTS },
+            quote! { from_discriminant_repr },
+        ),
+        CanyonRegisterEnumStorage::Text | CanyonRegisterEnumStorage::Native(_) => (
+            quote! { canyon_sql::crud::enums::EnumLabel },
+            quote! { LABELS },
+            quote! { from_label_repr },
+        ),
+    };
+    let variant_idents = variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let indexes = 0..variant_idents.len();
+    let from_indexes = 0..variant_idents.len();
+
+    let tokens = quote! {
+        impl canyon_sql::crud::enums::CanyonEnum for #ty {
+            const STORAGE: canyon_sql::crud::enums::EnumStorage = #storage_tokens;
+            const LABELS: &'static [canyon_sql::crud::enums::EnumLabel] = &[
+                #(canyon_sql::crud::enums::EnumLabel(std::borrow::Cow::Borrowed(#labels))),*
+            ];
+            const DISCRIMINANTS: &'static [i32] = &[#(#ty::#variant_idents as i32),*];
+
+            fn index(&self) -> usize {
+                match self {
+                    #(#ty::#variant_idents => #indexes),*
+                }
+            }
+
+            fn from_index(index: usize) -> Self {
+                match index {
+                    #(#from_indexes => #ty::#variant_idents,)*
+                    _ => panic!("There's no variant of the `{}` enum at {}", stringify!(#ty), index),
+                }
+            }
+        }
+
+        impl canyon_sql::crud::types::CanyonType for #ty {
+            type Repr = #repr_ty;
+
+            fn as_repr(&self) -> &Self::Repr {
+                &<Self as canyon_sql::crud::enums::CanyonEnum>::#repr_values[
+                    canyon_sql::crud::enums::CanyonEnum::index(self)
+                ]
+            }
+
+            fn from_repr(
+                repr: Self::Repr,
+            ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+                canyon_sql::crud::enums::#from_repr(repr)
+            }
+        }
+    };
+
+    let register = CanyonRegisterEnum {
+        enum_name: ty.to_string(),
+        storage,
+        labels,
+    };
+
+    Ok((tokens, register))
+}
+
+/// Parses the `storage`, `type_name` and `rename_all` arguments of the `#[canyon(...)]`
+/// attributes placed over the enum
+fn parse_canyon_enum_args(attrs: &[Attribute]) -> Result<CanyonEnumArgs, syn::Error> {
+    let mut args = CanyonEnumArgs::default();
+    for (name, value) in canyon_attribute_args(attrs)? {
+        match name.as_str() {
+            "storage" => args.storage = Some(value),
+            "type_name" => args.type_name = Some(value),
+            "rename_all" => args.rename_all = Some(value),
+            _ => {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    format!("Argument: `{name}` is not allowed over a CanyonEnum"),
+                ))
+            }
+        }
+    }
+
+    Ok(args)
+}
+
+/// Parses the `rename` argument of the `#[canyon(...)]` attributes placed over a variant
+fn parse_canyon_variant_rename(attrs: &[Attribute]) -> Result<Option<String>, syn::Error> {
+    let mut rename = None;
+    for (name, value) in canyon_attribute_args(attrs)? {
+        if name != "rename" {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!("Argument: `{name}` is not allowed over the variants of a CanyonEnum"),
+            ));
+        }
+        rename = Some(value);
+    }
+
+    Ok(rename)
+}
+
+/// Collects the `name = "value"` arguments of every `#[canyon(...)]` attribute
+fn canyon_attribute_args(attrs: &[Attribute]) -> Result<Vec<(String, String)>, syn::Error> {
+    let mut args = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("canyon")) {
+        let nested = match attr.parse_meta()? {
+            Meta::List(list) => list.nested,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "Expected a list of arguments, like `#[canyon(rename = \"...\")]`",
+                ))
+            }
+        };
+
+        for element in nested {
+            match element {
+                NestedMeta::Meta(Meta::NameValue(nv)) => match (nv.path.get_ident(), &nv.lit) {
+                    (Some(name), Lit::Str(value)) => args.push((name.to_string(), value.value())),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            "Only string literals are valid values for the attributes",
+                        ))
+                    }
+                },
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "Only argument identifiers with a value after an `=` sign are allowed",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(args)
+}
+
+/// Writes the label of a variant with the case given by the `rename_all` argument
+fn rename_variant(variant: &str, rename_all: Option<&str>) -> Result<String, syn::Error> {
+    match rename_all {
+        None => Ok(variant.to_string()),
+        Some("lowercase") => Ok(variant.to_lowercase()),
+        Some("UPPERCASE") => Ok(variant.to_uppercase()),
+        Some("snake_case") => Ok(helpers::default_database_table_name_from_entity_name(
+            variant,
+        )),
+        Some("SCREAMING_SNAKE_CASE") => {
+            Ok(helpers::default_database_table_name_from_entity_name(variant).to_uppercase())
+        }
+        Some(other) => Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "Unknown case: `{other}`. Use `lowercase`, `UPPERCASE`, `snake_case` \
+                or `SCREAMING_SNAKE_CASE`"
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_labels_and_native_type_name() {
+        let ast: DeriveInput = syn::parse_str(
+            r#"
+            #[canyon(storage = "native", rename_all = "snake_case")]
+            enum PlayerRole {
+                TopLane,
+                #[canyon(rename = "bottom")]
+                Adc,
+            }
+            "#,
+        )
+        .unwrap();
+
+        let (_, register) = generate_canyon_enum_tokens(&ast).unwrap();
+        assert_eq!(
+            register.storage,
+            CanyonRegisterEnumStorage::Native("player_role".to_string())
+        );
+        assert_eq!(register.labels, ["top_lane", "bottom"]);
+    }
+
+    #[test]
+    fn reject_enums_with_fields() {
+        let ast: DeriveInput = syn::parse_str("enum Role { Top(i32), Mid }").unwrap();
+        assert!(generate_canyon_enum_tokens(&ast).is_err());
+
+        let ast: DeriveInput =
+            syn::parse_str(r#"#[canyon(storage = "blob")] enum Role { Top }"#).unwrap();
+        assert!(generate_canyon_enum_tokens(&ast).is_err());
+    }
+}
@@ -6,12 +6,17 @@ use canyon_migrations::{CM_QUERIES_TO_EXECUTE, QUERIES_TO_EXECUTE};
 use proc_macro2::TokenStream;
 use quote::quote;
 
+/// Runs the migrations, wiring the generated queries to be executed at runtime, or
+/// a compile error if some entity can't be migrated
 #[cfg(feature = "migrations")]
 pub fn main_with_queries() -> TokenStream {
-    CANYON_TOKIO_RUNTIME.block_on(async {
+    let migrated = CANYON_TOKIO_RUNTIME.block_on(async {
         canyon_connection::init_connections_cache().await;
-        Migrations::migrate().await;
+        Migrations::migrate().await
     });
+    if let Err(error) = migrated {
+        return syn::Error::new(proc_macro2::Span::call_site(), error).to_compile_error();
+    }
 
     // The queries to execute at runtime in the managed state
     let mut queries_tokens: Vec<TokenStream> = Vec::new();
@@ -0,0 +1,122 @@
+//! Provides the helpers to build the `#[canyon_type]` procedural macro
+
+use canyon_entities::register_types::CanyonRegisterType;
+use quote::ToTokens;
+use syn::{Expr, ImplItem, ItemImpl, Lit, Type};
+
+/// Collects the data that the migrations needs from an implementation of the
+/// `CanyonType` trait: the name of the type, its representation, and the datatypes
+/// of the columns declared with the associated constants
+pub(crate) fn parse_canyon_type_impl(item: &ItemImpl) -> Result<CanyonRegisterType, syn::Error> {
+    let is_canyon_type = item
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .is_some_and(|segment| segment.ident == "CanyonType");
+    if !is_canyon_type {
+        return Err(syn::Error::new_spanned(
+            &item.self_ty,
+            "#[canyon_type] only works over an implementation of the `CanyonType` trait",
+        ));
+    }
+
+    let mut register = CanyonRegisterType {
+        type_name: last_segment(&item.self_ty)?,
+        ..Default::default()
+    };
+    for impl_item in &item.items {
+        match impl_item {
+            ImplItem::Type(assoc_type) if assoc_type.ident == "Repr" => {
+                register.repr_type = last_segment(&assoc_type.ty)?
+            }
+            ImplItem::Const(assoc_const) => {
+                let datatype = column_datatype(&assoc_const.expr)?;
+                match assoc_const.ident.to_string().as_str() {
+                    "POSTGRES_TYPE" => register.postgres_type = datatype,
+                    "SQLSERVER_TYPE" => register.sqlserver_type = datatype,
+                    "MYSQL_TYPE" => register.mysql_type = datatype,
+                    _ => (),
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(register)
+}
+
+/// The type without the path that qualifies it, as the migrations knows the types
+fn last_segment(ty: &Type) -> Result<String, syn::Error> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.to_token_stream().to_string().replace(' ', ""))
+            .ok_or_else(|| syn::Error::new_spanned(ty, "Expected the name of a type")),
+        _ => Err(syn::Error::new_spanned(ty, "Expected the name of a type")),
+    }
+}
+
+/// Reads the datatype of the `Some("...")` or `None` given to the associated constants
+fn column_datatype(expr: &Expr) -> Result<Option<String>, syn::Error> {
+    match expr {
+        Expr::Path(path) if path.path.is_ident("None") => return Ok(None),
+        Expr::Call(call) => {
+            let is_some = matches!(&*call.func, Expr::Path(path) if path.path.is_ident("Some"));
+            if let (true, Some(Expr::Lit(lit))) = (is_some, call.args.first()) {
+                if let Lit::Str(datatype) = &lit.lit {
+                    return Ok(Some(datatype.value()));
+                }
+            }
+        }
+        _ => (),
+    }
+
+    Err(syn::Error::new_spanned(
+        expr,
+        "The datatypes of the columns must be given as `Some(\"...\")` or `None`",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_the_repr_and_the_datatypes() {
+        let item: ItemImpl = syn::parse_str(
+            r#"
+            impl canyon_sql::crud::types::CanyonType for Money {
+                type Repr = rust_decimal::Decimal;
+                const MYSQL_TYPE: Option<&'static str> = Some("DECIMAL(19,4)");
+                const SQLSERVER_TYPE: Option<&'static str> = None;
+
+                fn as_repr(&self) -> &Self::Repr {
+                    &self.0
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let register = parse_canyon_type_impl(&item).unwrap();
+        assert_eq!(register.type_name, "Money");
+        assert_eq!(register.repr_type, "Decimal");
+        assert_eq!(register.postgres_type, None);
+        assert_eq!(register.sqlserver_type, None);
+        assert_eq!(register.mysql_type.as_deref(), Some("DECIMAL(19,4)"));
+    }
+
+    #[test]
+    fn reject_other_impls_and_computed_datatypes() {
+        let item: ItemImpl = syn::parse_str("impl Display for Money {}").unwrap();
+        assert!(parse_canyon_type_impl(&item).is_err());
+
+        let item: ItemImpl = syn::parse_str(
+            "impl CanyonType for Money { const MYSQL_TYPE: Option<&'static str> = TYPE; }",
+        )
+        .unwrap();
+        assert!(parse_canyon_type_impl(&item).is_err());
+    }
+}
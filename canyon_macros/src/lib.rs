@@ -1,6 +1,8 @@
 extern crate proc_macro;
 
 mod canyon_entity_macro;
+mod canyon_enum_macro;
+mod canyon_type_macro;
 #[cfg(feature = "migrations")]
 use canyon_macro::main_with_queries;
 
@@ -9,18 +11,22 @@ mod query_operations;
 mod utils;
 
 use canyon_entity_macro::parse_canyon_entity_proc_macro_attr;
+use canyon_enum_macro::generate_canyon_enum_tokens;
+use canyon_type_macro::parse_canyon_type_impl;
 use proc_macro::TokenStream as CompilerTokenStream;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{DeriveInput, Fields, Type, Visibility};
+use syn::{DeriveInput, Fields, Visibility};
 
 use query_operations::{
-    delete::{generate_delete_query_tokens, generate_delete_tokens},
+    blob::generate_blob_tokens,
+    delete::{generate_delete_query_tokens, generate_delete_tokens, generate_soft_delete_tokens},
     insert::{generate_insert_tokens, generate_multiple_insert_tokens},
     select::{
         generate_count_tokens, generate_find_all_query_tokens, generate_find_all_tokens,
         generate_find_all_unchecked_tokens, generate_find_by_foreign_key_tokens,
-        generate_find_by_pk_tokens, generate_find_by_reverse_foreign_key_tokens,
+        generate_find_by_pk_tokens, generate_find_by_pks_tokens,
+        generate_find_by_reverse_foreign_key_tokens,
     },
     update::{generate_update_query_tokens, generate_update_tokens},
 };
@@ -32,7 +38,7 @@ use canyon_entities::{
         generate_enum_with_fields, generate_enum_with_fields_values, generate_user_struct,
     },
     register_types::{CanyonRegisterEntity, CanyonRegisterEntityField},
-    CANYON_REGISTER_ENTITIES,
+    CANYON_REGISTER_ENTITIES, CANYON_REGISTER_ENUMS, CANYON_REGISTER_TYPES,
 };
 
 /// Macro for handling the entry point to the program.
@@ -147,7 +153,7 @@ pub fn canyon_entity(
 ) -> CompilerTokenStream {
     let attrs = syn::parse_macro_input!(_meta as syn::AttributeArgs);
 
-    let (table_name, schema_name, parsing_attribute_error) =
+    let (table_name, schema_name, soft_delete_column, parsing_attribute_error) =
         parse_canyon_entity_proc_macro_attr(attrs);
 
     let entity_res = syn::parse::<CanyonEntity>(input);
@@ -189,6 +195,22 @@ pub fn canyon_entity(
         new_entity.entity_fields.push(new_entity_field);
     }
 
+    // The column that holds the logical deletion of the rows doesn't need to be
+    // declared as a field, but it must be created by the migrations anyway
+    if let Some(soft_delete_column) = soft_delete_column {
+        if !entity
+            .fields
+            .iter()
+            .any(|field| field.name == soft_delete_column)
+        {
+            new_entity.entity_fields.push(CanyonRegisterEntityField {
+                field_name: soft_delete_column.to_string(),
+                field_type: "Option<NaiveDateTime>".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
     // Fill the register with the data of the attached struct
     CANYON_REGISTER_ENTITIES
         .lock()
@@ -256,6 +278,8 @@ fn impl_crud_operations_trait_for_struct(
 
     // Builds the find_by_pk() query
     let _find_by_pk_tokens = generate_find_by_pk_tokens(macro_data, &table_schema_data);
+    // Builds the find_by_pks() query
+    let _find_by_pks_tokens = generate_find_by_pks_tokens(macro_data, &table_schema_data);
 
     // Builds the insert() query
     let _insert_tokens = generate_insert_tokens(macro_data, &table_schema_data);
@@ -273,6 +297,12 @@ fn impl_crud_operations_trait_for_struct(
     // Builds the delete() query as a QueryBuilder
     let _delete_query_tokens = generate_delete_query_tokens(macro_data, &table_schema_data);
 
+    // Builds the force_delete() and restore() queries
+    let _soft_delete_tokens = generate_soft_delete_tokens(macro_data, &table_schema_data);
+
+    // Builds the streaming access to the binary columns
+    let _blob_tokens = generate_blob_tokens(macro_data, &table_schema_data);
+
     // Search by foreign (d) key as Vec, cause Canyon supports multiple fields having FK annotation
     let _search_by_fk_tokens: Vec<(TokenStream, TokenStream)> =
         generate_find_by_foreign_key_tokens(macro_data);
@@ -287,6 +317,9 @@ fn impl_crud_operations_trait_for_struct(
     let rev_fk_method_implementations =
         _search_by_revese_fk_tokens.iter().map(|(_, m_impl)| m_impl);
 
+    // The access to the value of the primary key, that indexes the entities found by find_by_pks_map()
+    let _primary_key_tokens = helpers::primary_key_impl(macro_data);
+
     // The autogenerated name for the trait that holds the fk and rev fk searches
     let fk_trait_ident = Ident::new(
         &format!("{}FkOperations", &ty.to_string()),
@@ -309,6 +342,9 @@ fn impl_crud_operations_trait_for_struct(
         // The find_by_pk impl
         #_find_by_pk_tokens
 
+        // The find_by_pks impl
+        #_find_by_pks_tokens
+
         // The insert impl
         #_insert_tokens
 
@@ -326,6 +362,12 @@ fn impl_crud_operations_trait_for_struct(
 
         // The delete as querybuilder impl
         #_delete_query_tokens
+
+        // The force_delete and restore impl
+        #_soft_delete_tokens
+
+        // The blob impl
+        #_blob_tokens
     };
 
     let tokens = if !_search_by_fk_tokens.is_empty() {
@@ -337,6 +379,8 @@ fn impl_crud_operations_trait_for_struct(
 
             impl canyon_sql::crud::Transaction<#ty> for #ty {}
 
+            #_primary_key_tokens
+
             /// Hidden trait for generate the foreign key operations available
             /// in Canyon without have to define them before hand in CrudOperations
             /// because it's just impossible with the actual system (where the methods
@@ -366,6 +410,8 @@ fn impl_crud_operations_trait_for_struct(
             }
 
             impl canyon_sql::crud::Transaction<#ty> for #ty {}
+
+            #_primary_key_tokens
         }
     };
 
@@ -427,13 +473,71 @@ pub fn implement_foreignkeyable_for_type(
     }.into()
 }
 
+/// Allows the fieldless enums to be used as the type of the fields of the entities, implementing
+/// the `CanyonEnum` and `CanyonType` traits.
+///
+/// The enums are stored as the labels of their variants by default. The `#[canyon(...)]`
+/// attribute over the enum accepts:
+/// - `storage = "text" | "integer" | "native"`, to store the labels, the discriminants, or the
+///   labels as a `PostgreSQL` enumerated type created by the migrations
+/// - `type_name = "..."`, the name of the enumerated type, that defaults to the snake case name
+///   of the enum
+/// - `rename_all = "lowercase" | "UPPERCASE" | "snake_case" | "SCREAMING_SNAKE_CASE"`, the case of
+///   the labels, that defaults to the name of the variants
+///
+/// and `#[canyon(rename = "...")]` over a variant gives its label.
+#[proc_macro_derive(CanyonEnum, attributes(canyon))]
+pub fn implement_canyon_enum_for_type(input: CompilerTokenStream) -> CompilerTokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    match generate_canyon_enum_tokens(&ast) {
+        Ok((tokens, new_enum)) => {
+            // Fill the register with the data of the enum, for the migrations
+            CANYON_REGISTER_ENUMS
+                .lock()
+                .expect("Error acquiring Mutex guard on Canyon Enum macro")
+                .push(new_enum);
+
+            tokens.into()
+        }
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Placed over an implementation of the `CanyonType` trait, makes the migrations aware of the
+/// custom type, so they can create the columns that stores it.
+///
+/// The datatypes of the columns are the ones given with the `POSTGRES_TYPE`, `SQLSERVER_TYPE`
+/// and `MYSQL_TYPE` constants of the implementation, which must be written as literals, or
+/// the ones of the representation of the type otherwise.
+#[proc_macro_attribute]
+pub fn canyon_type(_meta: CompilerTokenStream, input: CompilerTokenStream) -> CompilerTokenStream {
+    let item: syn::ItemImpl = match syn::parse(input) {
+        Ok(item) => item,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    match parse_canyon_type_impl(&item) {
+        Ok(new_type) => {
+            // Fill the register with the data of the type, for the migrations
+            CANYON_REGISTER_TYPES
+                .lock()
+                .expect("Error acquiring Mutex guard on Canyon Type macro")
+                .push(new_type);
+
+            item.into_token_stream().into()
+        }
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
 #[proc_macro_derive(CanyonMapper)]
 pub fn implement_row_mapper_for_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // Gets the data from the AST
     let ast: DeriveInput = syn::parse(input).unwrap();
 
     // Recovers the identifiers of the structs members
-    let fields = fields_with_types(match ast.data {
+    let fields = filter_fields(match ast.data {
         syn::Data::Struct(ref s) => &s.fields,
         _ => {
             return syn::Error::new(ast.ident.span(), "CanyonMapper only works with Structs")
@@ -443,98 +547,26 @@ pub fn implement_row_mapper_for_type(input: proc_macro::TokenStream) -> proc_mac
     });
 
     // Here it's where the incoming values of the DatabaseResult are wired into a new
-    // instance, mapping the fields of the type against the columns
-    let init_field_values = fields.iter().map(|(_vis, ident, _ty)| {
+    // instance, mapping the fields of the type against the columns. Every field is decoded
+    // with its `FromColumn` implementation, inferred from the type of the field
+    let init_field_values = fields.iter().map(|(_vis, ident)| {
         let ident_name = ident.to_string();
         quote! {
-            #ident: row.try_get(#ident_name)
-                .expect(format!("Failed to retrieve the {} field", #ident_name).as_ref())
+            #ident: canyon_sql::crud::postgres_column(row, #ident_name)?
         }
     });
 
-    let init_field_values_sqlserver = fields.iter().map(|(_vis, ident, ty)| {
+    let init_field_values_sqlserver = fields.iter().map(|(_vis, ident)| {
         let ident_name = ident.to_string();
-
-        if get_field_type_as_string(ty) == "String" {
-            quote! {
-                #ident: row.get::<&str, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-                    .to_string()
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<i64>" {
-            quote! {
-                #ident: row.get::<i64, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<i32>" {
-            quote! {
-                #ident: row.get::<i32, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<i16>" {
-            quote! {
-                #ident: row.get::<i16, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<f32>" {
-            quote! {
-                #ident: row.get::<f32, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<f64>" {
-            quote! {
-                #ident: row.get::<f64, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<String>" {
-            quote! {
-                #ident: row.get::<&str, &str>(#ident_name)
-                    .map( |x| x.to_owned() )
-            }
-        } else if get_field_type_as_string(ty) == "NaiveDate" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDate, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<NaiveDate>" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDate, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty) == "NaiveTime" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveTime, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<NaiveTime>" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveTime, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty) == "NaiveDateTime" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDateTime, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<NaiveDateTime>" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDateTime, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty) == "DateTime" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::DateTime, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<DateTime>" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::DateTime, &str>(#ident_name)
-            }
-        } else {
-            quote! {
-                #ident: row.get::<#ty, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
+        quote! {
+            #ident: canyon_sql::crud::sqlserver_column(row, #ident_name)?
         }
     });
 
-    let init_field_values_mysql = fields.iter().map(|(_vis, ident, _ty)| {
+    let init_field_values_mysql = fields.iter().map(|(_vis, ident)| {
         let ident_name = ident.to_string();
         quote! {
-            #ident: row.get(#ident_name)
-                .expect(format!("Failed to retrieve the {} field", #ident_name).as_ref())
+            #ident: canyon_sql::crud::mysql_column(row, #ident_name)?
         }
     });
 
@@ -544,22 +576,28 @@ pub fn implement_row_mapper_for_type(input: proc_macro::TokenStream) -> proc_mac
     let tokens = quote! {
         impl canyon_sql::crud::RowMapper<Self> for #ty {
             #[cfg(feature="postgres")]
-            fn deserialize_postgresql(row: &canyon_sql::db_clients::tokio_postgres::Row) -> #ty {
-                Self {
+            fn deserialize_postgresql(row: &canyon_sql::db_clients::tokio_postgres::Row)
+                -> Result<#ty, Box<dyn std::error::Error + Send + Sync>>
+            {
+                Ok(Self {
                     #(#init_field_values),*
-                }
+                })
             }
             #[cfg(feature="mssql")]
-            fn deserialize_sqlserver(row: &canyon_sql::db_clients::tiberius::Row) -> #ty {
-                Self {
+            fn deserialize_sqlserver(row: &canyon_sql::db_clients::tiberius::Row)
+                -> Result<#ty, Box<dyn std::error::Error + Send + Sync>>
+            {
+                Ok(Self {
                     #(#init_field_values_sqlserver),*
-                }
+                })
             }
             #[cfg(feature="mysql")]
-            fn deserialize_mysql(row: &canyon_sql::db_clients::mysql_async::Row) -> #ty {
-                Self {
+            fn deserialize_mysql(row: &canyon_sql::db_clients::mysql_async::Row)
+                -> Result<#ty, Box<dyn std::error::Error + Send + Sync>>
+            {
+                Ok(Self {
                     #(#init_field_values_mysql),*
-                }
+                })
             }
         }
     };
@@ -574,37 +612,3 @@ fn filter_fields(fields: &Fields) -> Vec<(Visibility, Ident)> {
         .map(|field| (field.vis.clone(), field.ident.as_ref().unwrap().clone()))
         .collect::<Vec<_>>()
 }
-
-fn fields_with_types(fields: &Fields) -> Vec<(Visibility, Ident, Type)> {
-    fields
-        .iter()
-        .map(|field| {
-            (
-                field.vis.clone(),
-                field.ident.as_ref().unwrap().clone(),
-                field.ty.clone(),
-            )
-        })
-        .collect::<Vec<_>>()
-}
-
-fn get_field_type_as_string(typ: &Type) -> String {
-    match typ {
-        Type::Array(type_) => type_.to_token_stream().to_string(),
-        Type::BareFn(type_) => type_.to_token_stream().to_string(),
-        Type::Group(type_) => type_.to_token_stream().to_string(),
-        Type::ImplTrait(type_) => type_.to_token_stream().to_string(),
-        Type::Infer(type_) => type_.to_token_stream().to_string(),
-        Type::Macro(type_) => type_.to_token_stream().to_string(),
-        Type::Never(type_) => type_.to_token_stream().to_string(),
-        Type::Paren(type_) => type_.to_token_stream().to_string(),
-        Type::Path(type_) => type_.to_token_stream().to_string(),
-        Type::Ptr(type_) => type_.to_token_stream().to_string(),
-        Type::Reference(type_) => type_.to_token_stream().to_string(),
-        Type::Slice(type_) => type_.to_token_stream().to_string(),
-        Type::TraitObject(type_) => type_.to_token_stream().to_string(),
-        Type::Tuple(type_) => type_.to_token_stream().to_string(),
-        Type::Verbatim(type_) => type_.to_token_stream().to_string(),
-        _ => "".to_owned(),
-    }
-}
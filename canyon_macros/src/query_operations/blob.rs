@@ -0,0 +1,89 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::utils::macro_tokens::MacroTokens;
+
+/// Generates the TokenStream for the __blob() CRUD operations, that gives streaming
+/// access to the binary columns of the row that matches the current instance
+pub fn generate_blob_tokens(macro_data: &MacroTokens, table_schema_data: &String) -> TokenStream {
+    let ty = macro_data.ty;
+
+    let fields = macro_data.get_struct_fields();
+    // The blobs are located by a single column, so the composite primary keys aren't supported
+    let pk = macro_data
+        .get_primary_key_annotation()
+        .filter(|_| !macro_data.has_composite_primary_key());
+
+    let primary_key = if let Some(primary_key) = pk {
+        primary_key
+    } else {
+        return quote! {
+            fn blob<'a, Z: canyon_sql::crud::bounds::FieldIdentifier<#ty>>(&'a self, column: Z)
+                -> Result<canyon_sql::crud::blob::Blob<'a, #ty>, Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'blob' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation, \
+                    or that has a composite primary key."
+                ).into_inner().unwrap())
+            }
+
+            fn blob_datasource<'a, Z: canyon_sql::crud::bounds::FieldIdentifier<#ty>>(
+                &'a self,
+                column: Z,
+                datasource_name: &'a str
+            ) -> Result<canyon_sql::crud::blob::Blob<'a, #ty>, Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'blob_datasource' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation, \
+                    or that has a composite primary key."
+                ).into_inner().unwrap())
+            }
+        };
+    };
+
+    let pk_field = fields
+        .iter()
+        .find(|f| *f.to_string() == primary_key)
+        .expect("Something really bad happened finding the Ident for the pk field on the blob");
+    let pk_field_value = quote! {
+        &self.#pk_field as &dyn for<'q> canyon_sql::crud::bounds::QueryParameter<'q>
+    };
+
+    quote! {
+        /// Gives access to the content of the given binary column of the row that matches
+        /// the current instance of a T type, to read it or to write it in chunks
+        fn blob<'a, Z: canyon_sql::crud::bounds::FieldIdentifier<#ty>>(&'a self, column: Z)
+            -> Result<canyon_sql::crud::blob::Blob<'a, #ty>, Box<dyn std::error::Error + Sync + std::marker::Send>>
+        {
+            Ok(canyon_sql::crud::blob::Blob::new(
+                #table_schema_data,
+                column.as_str(),
+                #primary_key,
+                #pk_field_value,
+                ""
+            ))
+        }
+
+        /// Gives access to the content of the given binary column of the row that matches
+        /// the current instance of a T type, to read it or to write it in chunks with the
+        /// specified datasource
+        fn blob_datasource<'a, Z: canyon_sql::crud::bounds::FieldIdentifier<#ty>>(
+            &'a self,
+            column: Z,
+            datasource_name: &'a str
+        ) -> Result<canyon_sql::crud::blob::Blob<'a, #ty>, Box<dyn std::error::Error + Sync + std::marker::Send>>
+        {
+            Ok(canyon_sql::crud::blob::Blob::new(
+                #table_schema_data,
+                column.as_str(),
+                #primary_key,
+                #pk_field_value,
+                datasource_name
+            ))
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::utils::helpers::{
+    hook_call, operation_scope, primary_key_condition, primary_key_values,
+};
 use crate::utils::macro_tokens::MacroTokens;
 
 /// Generates the TokenStream for the __delete() CRUD operation
@@ -8,46 +11,83 @@ use crate::utils::macro_tokens::MacroTokens;
 pub fn generate_delete_tokens(macro_data: &MacroTokens, table_schema_data: &String) -> TokenStream {
     let ty = macro_data.ty;
 
-    let fields = macro_data.get_struct_fields();
-    let pk = macro_data.get_primary_key_annotation();
+    if macro_data.type_has_primary_key() {
+        // Matches the row by every column of the primary key, when it's a composite one
+        let pk_condition = primary_key_condition(macro_data, false);
+        let pk_field_value = primary_key_values(macro_data, quote! { self });
 
-    if let Some(primary_key) = pk {
-        let pk_field = fields
-            .iter()
-            .find(|f| *f.to_string() == primary_key)
-            .expect(
-                "Something really bad happened finding the Ident for the pk field on the delete",
-            );
-        let pk_field_value =
-            quote! { &self.#pk_field as &dyn canyon_sql::crud::bounds::QueryParameter<'_> };
+        let before_delete = hook_call(macro_data, "before_delete", quote! { self });
+        let after_delete = hook_call(macro_data, "after_delete", quote! { self });
+
+        // Entities with the `soft_delete` argument just mark the row as deleted
+        let stmt = if let Some(soft_delete_column) = macro_data.get_soft_delete_column() {
+            format!(
+                "UPDATE {} SET {} = CURRENT_TIMESTAMP WHERE {} AND {} IS NULL",
+                table_schema_data, soft_delete_column, pk_condition, soft_delete_column
+            )
+        } else {
+            format!("DELETE FROM {} WHERE {}", table_schema_data, pk_condition)
+        };
+
+        let output = quote! { Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+        let delete = |datasource_name: TokenStream| {
+            operation_scope(
+                macro_data,
+                "delete",
+                &output,
+                quote! {
+                    #before_delete
+                    let deleted_rows = <#ty as canyon_sql::crud::Transaction<#ty>>::execute(
+                        #stmt,
+                        &[#pk_field_value],
+                        #datasource_name
+                    ).await?;
+
+                    if deleted_rows == 0 {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::NotFound,
+                            format!(
+                                "There's no record on {} with the primary key of the instance to delete",
+                                #table_schema_data
+                            )
+                        ).into());
+                    }
+
+                    #after_delete
+                    Ok(())
+                },
+            )
+        };
+        let delete_default = delete(quote! { "" });
+        let delete_datasource = delete(quote! { datasource_name });
 
         quote! {
             /// Deletes from a database entity the row that matches
             /// the current instance of a T type, returning a result
-            /// indicating a possible failure querying the database.
-            async fn delete(&self) -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    format!("DELETE FROM {} WHERE {:?} = $1", #table_schema_data, #primary_key),
-                    &[#pk_field_value],
-                    ""
-                ).await?;
-
-                Ok(())
+            /// indicating a possible failure querying the database,
+            /// or a [`std::io::ErrorKind::NotFound`] error if there's no record to delete.
+            ///
+            /// If the entity is declared with the `soft_delete` argument, the row
+            /// is just marked as deleted instead of being removed from the table.
+            ///
+            /// For the entities declared with `#[canyon_entity(hooks)]`, the `before_delete`
+            /// and `after_delete` hooks are called around the delete.
+            async fn delete(&self) -> #output {
+                #delete_default
             }
 
             /// Deletes from a database entity the row that matches
             /// the current instance of a T type, returning a result
-            /// indicating a possible failure querying the database with the specified datasource.
-            async fn delete_datasource<'a>(&self, datasource_name: &'a str)
-                -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>>
-            {
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    format!("DELETE FROM {} WHERE {:?} = $1", #table_schema_data, #primary_key),
-                    &[#pk_field_value],
-                    datasource_name
-                ).await?;
-
-                Ok(())
+            /// indicating a possible failure querying the database with the specified datasource,
+            /// or a [`std::io::ErrorKind::NotFound`] error if there's no record to delete.
+            ///
+            /// If the entity is declared with the `soft_delete` argument, the row
+            /// is just marked as deleted instead of being removed from the table.
+            ///
+            /// For the entities declared with `#[canyon_entity(hooks)]`, the `before_delete`
+            /// and `after_delete` hooks are called around the delete.
+            async fn delete_datasource<'a>(&self, datasource_name: &'a str) -> #output {
+                #delete_datasource
             }
         }
     } else {
@@ -79,6 +119,206 @@ pub fn generate_delete_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
     }
 }
 
+/// Generates the TokenStream for the __force_delete() and the __restore() CRUD operations,
+/// that allows to handle the rows of the entities declared with the `soft_delete` argument
+pub fn generate_soft_delete_tokens(
+    macro_data: &MacroTokens,
+    table_schema_data: &String,
+) -> TokenStream {
+    let ty = macro_data.ty;
+
+    let soft_delete_column = macro_data.get_soft_delete_column();
+
+    if !macro_data.type_has_primary_key() {
+        return quote! {
+            async fn force_delete(&self)
+                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'force_delete' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                ).into_inner().unwrap())
+            }
+
+            async fn force_delete_datasource<'a>(&self, datasource_name: &'a str)
+                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'force_delete_datasource' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                ).into_inner().unwrap())
+            }
+
+            async fn restore(&self)
+                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'restore' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation."
+                ).into_inner().unwrap())
+            }
+
+            async fn restore_datasource<'a>(&self, datasource_name: &'a str)
+                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'restore_datasource' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation."
+                ).into_inner().unwrap())
+            }
+        };
+    }
+
+    // Matches the row by every column of the primary key, when it's a composite one
+    let pk_condition = primary_key_condition(macro_data, false);
+    let pk_field_value = primary_key_values(macro_data, quote! { self });
+
+    let before_delete = hook_call(macro_data, "before_delete", quote! { self });
+    let after_delete = hook_call(macro_data, "after_delete", quote! { self });
+
+    let force_delete_stmt = format!("DELETE FROM {} WHERE {}", table_schema_data, pk_condition);
+
+    let output = quote! { Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+    let force_delete = |datasource_name: TokenStream| {
+        operation_scope(
+            macro_data,
+            "force_delete",
+            &output,
+            quote! {
+                #before_delete
+                let deleted_rows = <#ty as canyon_sql::crud::Transaction<#ty>>::execute(
+                    #force_delete_stmt,
+                    &[#pk_field_value],
+                    #datasource_name
+                ).await?;
+
+                if deleted_rows == 0 {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!(
+                            "There's no record on {} with the primary key of the instance to delete",
+                            #table_schema_data
+                        )
+                    ).into());
+                }
+
+                #after_delete
+                Ok(())
+            },
+        )
+    };
+    let force_delete_default = force_delete(quote! { "" });
+    let force_delete_datasource = force_delete(quote! { datasource_name });
+
+    let restore_tokens = if let Some(soft_delete_column) = soft_delete_column {
+        let restore_stmt = format!(
+            "UPDATE {} SET {} = NULL WHERE {} AND {} IS NOT NULL",
+            table_schema_data, soft_delete_column, pk_condition, soft_delete_column
+        );
+        let restore = |datasource_name: TokenStream| {
+            operation_scope(
+                macro_data,
+                "restore",
+                &output,
+                quote! {
+                    let restored_rows = <#ty as canyon_sql::crud::Transaction<#ty>>::execute(
+                        #restore_stmt,
+                        &[#pk_field_value],
+                        #datasource_name
+                    ).await?;
+
+                    if restored_rows == 0 {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::NotFound,
+                            format!(
+                                "There's no deleted record on {} with the primary key of the instance to restore",
+                                #table_schema_data
+                            )
+                        ).into());
+                    }
+
+                    Ok(())
+                },
+            )
+        };
+        let restore_default = restore(quote! { "" });
+        let restore_datasource = restore(quote! { datasource_name });
+
+        quote! {
+            /// Restores the row that matches the current instance of a T type, previously
+            /// marked as deleted, returning a result indicating a possible failure querying
+            /// the database, or a [`std::io::ErrorKind::NotFound`] error if there's no
+            /// deleted record to restore.
+            async fn restore(&self) -> #output {
+                #restore_default
+            }
+
+            /// Restores the row that matches the current instance of a T type, previously
+            /// marked as deleted, returning a result indicating a possible failure querying
+            /// the database with the specified datasource, or a [`std::io::ErrorKind::NotFound`]
+            /// error if there's no deleted record to restore.
+            async fn restore_datasource<'a>(&self, datasource_name: &'a str) -> #output {
+                #restore_datasource
+            }
+        }
+    } else {
+        quote! {
+            async fn restore(&self)
+                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'restore' method on a \
+                    CanyonEntity that is not declared with the `soft_delete` argument."
+                ).into_inner().unwrap())
+            }
+
+            async fn restore_datasource<'a>(&self, datasource_name: &'a str)
+                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "You can't use the 'restore_datasource' method on a \
+                    CanyonEntity that is not declared with the `soft_delete` argument."
+                ).into_inner().unwrap())
+            }
+        }
+    };
+
+    quote! {
+        /// Removes from a database entity the row that matches the current instance
+        /// of a T type, even if the entity is declared with the `soft_delete` argument,
+        /// returning a result indicating a possible failure querying the database,
+        /// or a [`std::io::ErrorKind::NotFound`] error if there's no record to delete.
+        ///
+        /// It's the only way to remove the rows of those entities, since the `delete_query`
+        /// of them also just marks the matching rows as deleted.
+        async fn force_delete(&self) -> #output {
+            #force_delete_default
+        }
+
+        /// Removes from a database entity the row that matches the current instance
+        /// of a T type, even if the entity is declared with the `soft_delete` argument,
+        /// returning a result indicating a possible failure querying the database with
+        /// the specified datasource, or a [`std::io::ErrorKind::NotFound`] error if
+        /// there's no record to delete.
+        ///
+        /// It's the only way to remove the rows of those entities, since the `delete_query`
+        /// of them also just marks the matching rows as deleted.
+        async fn force_delete_datasource<'a>(&self, datasource_name: &'a str) -> #output {
+            #force_delete_datasource
+        }
+
+        #restore_tokens
+    }
+}
+
 /// Generates the TokenStream for the __delete() CRUD operation as a
 /// [`query_elements::query_builder::QueryBuilder<'a, #ty>`]
 pub fn generate_delete_query_tokens(
@@ -87,6 +327,30 @@ pub fn generate_delete_query_tokens(
 ) -> TokenStream {
     let ty = macro_data.ty;
 
+    // Entities with the `soft_delete` argument just mark the matching rows as deleted
+    let (delete_query, delete_query_datasource) =
+        if let Some(soft_delete_column) = macro_data.get_soft_delete_column() {
+            (
+                quote! {
+                    canyon_sql::query::DeleteQueryBuilder::new_soft_deletable(
+                        #table_schema_data, "", #soft_delete_column
+                    )
+                },
+                quote! {
+                    canyon_sql::query::DeleteQueryBuilder::new_soft_deletable(
+                        #table_schema_data, datasource_name, #soft_delete_column
+                    )
+                },
+            )
+        } else {
+            (
+                quote! { canyon_sql::query::DeleteQueryBuilder::new(#table_schema_data, "") },
+                quote! {
+                    canyon_sql::query::DeleteQueryBuilder::new(#table_schema_data, datasource_name)
+                },
+            )
+        };
+
     quote! {
         /// Generates a [`canyon_sql::query::DeleteQueryBuilder`]
         /// that allows you to customize the query by adding parameters and constrains dynamically.
@@ -95,8 +359,11 @@ pub fn generate_delete_query_tokens(
         /// entity but converted to the corresponding database convention,
         /// unless concrete values are set on the available parameters of the
         /// `canyon_macro(table_name = "table_name", schema = "schema")`
+        ///
+        /// If the entity is declared with the `soft_delete` argument, the matching rows
+        /// are just marked as deleted instead of being removed from the table.
         fn delete_query<'a>() -> canyon_sql::query::DeleteQueryBuilder<'a, #ty> {
-            canyon_sql::query::DeleteQueryBuilder::new(#table_schema_data, "")
+            #delete_query
         }
 
         /// Generates a [`canyon_sql::query::DeleteQueryBuilder`]
@@ -110,8 +377,11 @@ pub fn generate_delete_query_tokens(
         /// The query it's made against the database with the configured datasource
         /// described in the configuration file, and selected with the [`&str`]
         /// passed as parameter.
+        ///
+        /// If the entity is declared with the `soft_delete` argument, the matching rows
+        /// are just marked as deleted instead of being removed from the table.
         fn delete_query_datasource<'a>(datasource_name: &'a str) -> canyon_sql::query::DeleteQueryBuilder<'a, #ty> {
-            canyon_sql::query::DeleteQueryBuilder::new(#table_schema_data, datasource_name)
+            #delete_query_datasource
         }
     }
 }
@@ -1,29 +1,71 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::utils::helpers::{
+    hook_call, operation_scope, primary_key_generation, validation_checks,
+};
 use crate::utils::macro_tokens::MacroTokens;
 
 /// Generates the TokenStream for the _insert_result() CRUD operation
 pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &String) -> TokenStream {
     let ty = macro_data.ty;
 
+    // The primary keys generated by Canyon, and the values of the composite ones,
+    // are sent along with the rest of the columns
+    let generate_pk = primary_key_generation(macro_data, quote! { self });
+    let pk_is_generated = !generate_pk.is_empty() || macro_data.has_composite_primary_key();
+
+    // Retrieves the fields of the Struct
+    let fields = macro_data.get_struct_fields();
+
     // Retrieves the fields of the Struct as a collection of Strings, already parsed
     // the condition of remove the primary key if it's present and it's autoincremental
-    let insert_columns = macro_data.get_column_names_pk_parsed().join(", ");
+    let insert_columns = if pk_is_generated {
+        fields
+            .iter()
+            .map(|field| format!("\"{field}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        macro_data.get_column_names_pk_parsed().join(", ")
+    };
 
     // Returns a String with the generic $x placeholder for the query parameters.
-    let placeholders = macro_data.placeholders_generator();
-
-    // Retrieves the fields of the Struct
-    let fields = macro_data.get_struct_fields();
+    let placeholders = if pk_is_generated {
+        (1..=fields.len())
+            .map(|num| format!("${num}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        macro_data.placeholders_generator()
+    };
 
     let insert_values = fields.iter().map(|ident| {
         quote! { &self.#ident }
     });
-    let insert_values_cloned = insert_values.clone();
 
     let primary_key = macro_data.get_primary_key_annotation();
 
+    // The records of the entities with a `#[version]` field always starts on the first version
+    let init_version = if let Some((version_ident, _ty)) = macro_data.get_version_field() {
+        quote! { self.#version_ident = 1; }
+    } else {
+        quote! {}
+    };
+
+    // The fields annotated with `#[created_at]` and `#[updated_at]` holds the moment of the insert
+    let timestamp_fields = [
+        macro_data.get_created_at_field(),
+        macro_data.get_updated_at_field(),
+    ];
+    let init_timestamps = timestamp_fields.iter().flatten().map(|(ident, _ty)| {
+        quote! { self.#ident = canyon_sql::crud::bounds::Timestamp::now(); }
+    });
+
+    let before_insert = hook_call(macro_data, "before_insert", quote! { self });
+    let after_insert = hook_call(macro_data, "after_insert", quote! { self });
+    let validations = validation_checks(macro_data, quote! { self });
+
     let remove_pk_value_from_fn_entry = if let Some(pk_index) = macro_data.get_pk_index() {
         quote! { values.remove(#pk_index) }
     } else {
@@ -33,7 +75,7 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
     let pk_ident_type = macro_data
         ._fields_with_types()
         .into_iter()
-        .find(|(i, _t)| Some(i.to_string()) == primary_key);
+        .find(|(i, _t)| !pk_is_generated && Some(i.to_string()) == primary_key);
     let insert_transaction = if let Some(pk_data) = &pk_ident_type {
         let pk_ident = &pk_data.0;
         let pk_type = &pk_data.1;
@@ -58,19 +100,28 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
            match rows {
                 #[cfg(feature = "postgres")]
                 canyon_sql::crud::CanyonRows::Postgres(mut v) => {
-                    self.#pk_ident = v
+                    let row = v
                         .get(0)
-                        .ok_or("Failed getting the returned IDs for an insert")?
-                        .get::<&str, #pk_type>(#primary_key);
+                        .ok_or("Failed getting the returned IDs for an insert")?;
+                    self.#pk_ident = <#pk_type as canyon_sql::crud::FromColumn>::from_postgres(
+                        row,
+                        #primary_key
+                    )?
+                    .ok_or("The returned primary key is NULL")?;
+                    #after_insert
                     Ok(())
                 },
                 #[cfg(feature = "mssql")]
                 canyon_sql::crud::CanyonRows::Tiberius(mut v) => {
-                    self.#pk_ident = v
+                    let row = v
                         .get(0)
-                        .ok_or("Failed getting the returned IDs for a multi insert")?
-                        .get::<#pk_type, &str>(#primary_key)
-                        .ok_or("SQL Server primary key type failed to be set as value")?;
+                        .ok_or("Failed getting the returned IDs for a multi insert")?;
+                    self.#pk_ident = <#pk_type as canyon_sql::crud::FromColumn>::from_sqlserver(
+                        row,
+                        #primary_key
+                    )?
+                    .ok_or("SQL Server primary key type failed to be set as value")?;
+                    #after_insert
                     Ok(())
                 },
                 #[cfg(feature = "mysql")]
@@ -80,6 +131,7 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
                         .ok_or("Failed getting the returned IDs for a multi insert")?
                         .get::<#pk_type,usize>(0)
                         .ok_or("MYSQL primary key type failed to be set as value")?;
+                    #after_insert
                     Ok(())
                 },
                 _ => panic!("Reached the panic match arm of insert for the DatabaseConnection type") // TODO remove when the generics will be refactored
@@ -91,8 +143,7 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
                 "INSERT INTO {} ({}) VALUES ({})",
                 #table_schema_data,
                 #insert_columns,
-                #placeholders,
-                #primary_key
+                #placeholders
             );
 
             <#ty as canyon_sql::crud::Transaction<#ty>>::query(
@@ -101,10 +152,32 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
                 datasource_name
             ).await?;
 
+            #after_insert
             Ok(())
         }
     };
 
+    let output = quote! { Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>> };
+    let insert = quote! {
+        #before_insert
+        #generate_pk
+        #init_version
+        #(#init_timestamps)*
+        #validations
+        let mut values: Vec<&dyn canyon_sql::crud::bounds::QueryParameter<'_>> = vec![#(#insert_values),*];
+        #insert_transaction
+    };
+    let insert_default = operation_scope(
+        macro_data,
+        "insert",
+        &output,
+        quote! {
+            let datasource_name = "";
+            #insert
+        },
+    );
+    let insert_datasource = operation_scope(macro_data, "insert", &output, insert);
+
     quote! {
         /// Inserts into a database entity the current data in `self`, generating a new
         /// entry (row), returning the `PRIMARY KEY` = `self.<pk_field>` with the specified
@@ -118,6 +191,18 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         /// operation, you instance will have the correct value that is the *PRIMARY KEY*
         /// of the database row that represents.
         ///
+        /// If the entity has a field annotated with `#[version]`, it's set to `1` before the insert,
+        /// and the fields annotated with `#[created_at]` or `#[updated_at]` are set to the current time.
+        ///
+        /// The primary keys declared with `#[primary_key(generate = "uuid_v4" | "uuid_v7")]` are
+        /// generated by Canyon before the insert, unless they already hold a non nil UUID.
+        ///
+        /// For the entities declared with `#[canyon_entity(hooks)]`, the `before_insert` and
+        /// `after_insert` hooks are called around the insert.
+        ///
+        /// The fields annotated with `#[validate(...)]` are checked before the insert, returning
+        /// a [`canyon_sql::crud::validation::ValidationError`] with every failed rule.
+        ///
         /// This operation returns a result type, indicating a possible failure querying the database.
         ///
         /// ## *Examples*
@@ -144,12 +229,8 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         /// }
         /// ```
         ///
-        async fn insert<'a>(&mut self)
-            -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
-        {
-            let datasource_name = "";
-            let mut values: Vec<&dyn canyon_sql::crud::bounds::QueryParameter<'_>> = vec![#(#insert_values),*];
-            #insert_transaction
+        async fn insert<'a>(&mut self) -> #output {
+            #insert_default
         }
 
         /// Inserts into a database entity the current data in `self`, generating a new
@@ -164,6 +245,14 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         /// operation, you instance will have the correct value that is the *PRIMARY KEY*
         /// of the database row that represents.
         ///
+        /// If the entity has a field annotated with `#[version]`, it's set to `1` before the insert,
+        /// and the fields annotated with `#[created_at]` or `#[updated_at]` are set to the current time.
+        ///
+        /// The primary keys declared with `#[primary_key(generate = "uuid_v4" | "uuid_v7")]` are
+        /// generated by Canyon before the insert, unless they already hold a non nil UUID.
+        ///
+        /// As in `insert`, the fields annotated with `#[validate(...)]` are checked before the insert.
+        ///
         /// This operation returns a result type, indicating a possible failure querying the database.
         ///
         /// ## *Examples*
@@ -190,11 +279,8 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         /// }
         /// ```
         ///
-        async fn insert_datasource<'a>(&mut self, datasource_name: &'a str)
-            -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
-        {
-            let mut values: Vec<&dyn canyon_sql::crud::bounds::QueryParameter<'_>> = vec![#(#insert_values_cloned),*];
-            #insert_transaction
+        async fn insert_datasource<'a>(&mut self, datasource_name: &'a str) -> #output {
+            #insert_datasource
         }
 
     }
@@ -219,14 +305,59 @@ pub fn generate_multiple_insert_tokens(
     let fields = macro_data.get_struct_fields();
 
     let macro_fields = fields.iter().map(|field| quote! { &instance.#field });
-    let macro_fields_cloned = macro_fields.clone();
 
     let pk = macro_data.get_primary_key_annotation().unwrap_or_default();
 
+    let init_version = macro_data
+        .get_version_field()
+        .map(|(version_ident, _ty)| quote! { instance.#version_ident = 1; });
+    let init_timestamps = [
+        macro_data.get_created_at_field(),
+        macro_data.get_updated_at_field(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|(ident, _ty)| quote! { instance.#ident = canyon_sql::crud::bounds::Timestamp::now(); })
+    .collect::<Vec<_>>();
+    let before_insert = hook_call(macro_data, "before_insert", quote! { &mut **instance });
+    let generate_pk = primary_key_generation(macro_data, quote! { instance });
+    let validations = validation_checks(macro_data, quote! { instance });
+    let init_managed_fields = if init_version.is_some()
+        || !init_timestamps.is_empty()
+        || !before_insert.is_empty()
+        || !generate_pk.is_empty()
+        || !validations.is_empty()
+    {
+        quote! {
+            for instance in instances.iter_mut() {
+                #before_insert
+                #generate_pk
+                #init_version
+                #(#init_timestamps)*
+                #validations
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let after_insert = hook_call(macro_data, "after_insert", quote! { &mut **instance });
+    let after_insert_hooks = if after_insert.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            for instance in instances.iter_mut() {
+                #after_insert
+            }
+        }
+    };
+
+    // The primary keys generated by Canyon, and the composite ones, are inserted as any other column
+    let pk_is_inserted = !generate_pk.is_empty() || macro_data.has_composite_primary_key();
     let pk_ident_type = macro_data
         ._fields_with_types()
         .into_iter()
-        .find(|(i, _t)| *i == pk);
+        .find(|(i, _t)| !pk_is_inserted && *i == pk);
 
     let multi_insert_transaction = if let Some(pk_data) = &pk_ident_type {
         let pk_ident = &pk_data.0;
@@ -304,24 +435,25 @@ pub fn generate_multiple_insert_tokens(
                 #[cfg(feature="postgres")]
                 canyon_sql::crud::CanyonRows::Postgres(mut v) => {
                     for (idx, instance) in instances.iter_mut().enumerate() {
-                        instance.#pk_ident = v
-                            .get(idx)
-                            .expect("Failed getting the returned IDs for a multi insert")
-                            .get::<&str, #pk_type>(#pk);
+                        instance.#pk_ident = canyon_sql::crud::postgres_column::<#pk_type>(
+                            v.get(idx).expect("Failed getting the returned IDs for a multi insert"),
+                            #pk
+                        )?;
                     }
 
+                    #after_insert_hooks
                     Ok(())
                 },
                 #[cfg(feature="mssql")]
                 canyon_sql::crud::CanyonRows::Tiberius(mut v) => {
                     for (idx, instance) in instances.iter_mut().enumerate() {
-                        instance.#pk_ident = v
-                            .get(idx)
-                            .expect("Failed getting the returned IDs for a multi insert")
-                            .get::<#pk_type, &str>(#pk)
-                            .expect("SQL Server primary key type failed to be set as value");
+                        instance.#pk_ident = canyon_sql::crud::sqlserver_column::<#pk_type>(
+                            v.get(idx).expect("Failed getting the returned IDs for a multi insert"),
+                            #pk
+                        )?;
                     }
 
+                    #after_insert_hooks
                     Ok(())
                 },
                 #[cfg(feature="mysql")]
@@ -333,6 +465,7 @@ pub fn generate_multiple_insert_tokens(
                             .get::<#pk_type,usize>(0)
                             .expect("MYSQL primary key type failed to be set as value");
                     }
+                    #after_insert_hooks
                     Ok(())
                 },
                 _ => panic!() // TODO remove when the generics will be refactored
@@ -399,13 +532,51 @@ pub fn generate_multiple_insert_tokens(
                 datasource_name
             ).await?;
 
+            #after_insert_hooks
             Ok(())
         }
     };
 
+    let output = quote! { Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>> };
+    let multi_insert = quote! {
+        use canyon_sql::crud::bounds::QueryParameter;
+        #init_managed_fields
+
+        let mut final_values: Vec<Vec<&dyn QueryParameter<'_>>> = Vec::new();
+        for instance in instances.iter() {
+            let intermediate: &[&dyn QueryParameter<'_>] = &[#(#macro_fields),*];
+
+            let mut longer_lived: Vec<&dyn QueryParameter<'_>> = Vec::new();
+            for value in intermediate.into_iter() {
+                longer_lived.push(*value)
+            }
+
+            final_values.push(longer_lived)
+        }
+
+        let mut mapped_fields: String = String::new();
+
+        #multi_insert_transaction
+    };
+    let multi_insert_default = operation_scope(
+        macro_data,
+        "multi_insert",
+        &output,
+        quote! {
+            let datasource_name = "";
+            #multi_insert
+        },
+    );
+    let multi_insert_datasource =
+        operation_scope(macro_data, "multi_insert", &output, multi_insert);
+
     quote! {
         /// Inserts multiple instances of some type `T` into its related table.
         ///
+        /// The fields annotated with `#[validate(...)]` are checked for every instance before
+        /// the insert, returning the [`canyon_sql::crud::validation::ValidationError`] of the
+        /// first instance that doesn't pass them, so no instance is inserted.
+        ///
         /// ```
         /// let mut new_league = League {
         ///     id: Default::default(),
@@ -437,27 +608,8 @@ pub fn generate_multiple_insert_tokens(
         /// ).await
         /// .ok();
         /// ```
-        async fn multi_insert<'a>(instances: &'a mut [&'a mut #ty]) -> (
-            Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
-        ) {
-            use canyon_sql::crud::bounds::QueryParameter;
-            let datasource_name = "";
-
-            let mut final_values: Vec<Vec<&dyn QueryParameter<'_>>> = Vec::new();
-            for instance in instances.iter() {
-                let intermediate: &[&dyn QueryParameter<'_>] = &[#(#macro_fields),*];
-
-                let mut longer_lived: Vec<&dyn QueryParameter<'_>> = Vec::new();
-                for value in intermediate.into_iter() {
-                    longer_lived.push(*value)
-                }
-
-                final_values.push(longer_lived)
-            }
-
-            let mut mapped_fields: String = String::new();
-
-            #multi_insert_transaction
+        async fn multi_insert<'a>(instances: &'a mut [&'a mut #ty]) -> #output {
+            #multi_insert_default
         }
 
         /// Inserts multiple instances of some type `T` into its related table with the specified
@@ -494,26 +646,8 @@ pub fn generate_multiple_insert_tokens(
         /// ).await
         /// .ok();
         /// ```
-        async fn multi_insert_datasource<'a>(instances: &'a mut [&'a mut #ty], datasource_name: &'a str) -> (
-            Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
-        ) {
-            use canyon_sql::crud::bounds::QueryParameter;
-
-            let mut final_values: Vec<Vec<&dyn QueryParameter<'_>>> = Vec::new();
-            for instance in instances.iter() {
-                let intermediate: &[&dyn QueryParameter<'_>] = &[#(#macro_fields_cloned),*];
-
-                let mut longer_lived: Vec<&dyn QueryParameter<'_>> = Vec::new();
-                for value in intermediate.into_iter() {
-                    longer_lived.push(*value)
-                }
-
-                final_values.push(longer_lived)
-            }
-
-            let mut mapped_fields: String = String::new();
-
-            #multi_insert_transaction
+        async fn multi_insert_datasource<'a>(instances: &'a mut [&'a mut #ty], datasource_name: &'a str) -> #output {
+            #multi_insert_datasource
         }
     }
 }
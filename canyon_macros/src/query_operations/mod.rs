@@ -1,3 +1,4 @@
+pub mod blob;
 pub mod delete;
 pub mod insert;
 pub mod select;
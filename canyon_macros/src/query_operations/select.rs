@@ -1,10 +1,8 @@
-use canyon_entities::field_annotation::EntityFieldAnnotation;
-
 use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::utils::helpers::*;
-use crate::utils::macro_tokens::MacroTokens;
+use crate::utils::macro_tokens::{ForeignKey, MacroTokens};
 
 /// Generates the TokenStream for build the __find_all() CRUD
 /// associated function
@@ -13,21 +11,52 @@ pub fn generate_find_all_unchecked_tokens(
     table_schema_data: &String,
 ) -> TokenStream {
     let ty = macro_data.ty;
-    let stmt = format!("SELECT * FROM {table_schema_data}");
+    let stmt = format!(
+        "SELECT * FROM {table_schema_data}{}",
+        soft_delete_filter(macro_data)
+    );
+
+    // As the query errors, the errors of the hooks makes the unchecked variants panic
+    let after_load = if macro_data.has_hooks() {
+        quote! {
+            for entity in entities.iter_mut() {
+                <#ty as canyon_sql::crud::CanyonHooks>::after_load(entity).await.unwrap();
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let output = quote! { Vec<#ty> };
+    let find_all_unchecked = |datasource_name: TokenStream| {
+        operation_scope(
+            macro_data,
+            "find_all_unchecked",
+            &output,
+            quote! {
+                let mut entities = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+                    #stmt,
+                    &[],
+                    #datasource_name
+                ).await
+                .unwrap()
+                .into_results::<#ty>()
+                .unwrap();
+                #after_load
+                entities
+            },
+        )
+    };
+    let find_all_unchecked_default = find_all_unchecked(quote! { "" });
+    let find_all_unchecked_datasource = find_all_unchecked(quote! { datasource_name });
 
     quote! {
         /// Performs a `SELECT * FROM table_name`, where `table_name` it's
         /// the name of your entity but converted to the corresponding
         /// database convention. P.ej. PostgreSQL prefers table names declared
         /// with snake_case identifiers.
-        async fn find_all_unchecked<'a>() -> Vec<#ty> {
-            <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                #stmt,
-                &[],
-                ""
-            ).await
-            .unwrap()
-            .into_results::<#ty>()
+        async fn find_all_unchecked<'a>() -> #output {
+            #find_all_unchecked_default
         }
 
         /// Performs a `SELECT * FROM table_name`, where `table_name` it's
@@ -38,14 +67,8 @@ pub fn generate_find_all_unchecked_tokens(
         /// The query it's made against the database with the configured datasource
         /// described in the configuration file, and selected with the [`&str`]
         /// passed as parameter.
-        async fn find_all_unchecked_datasource<'a>(datasource_name: &'a str) -> Vec<#ty> {
-            <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                #stmt,
-                &[],
-                datasource_name
-            ).await
-            .unwrap()
-            .into_results::<#ty>()
+        async fn find_all_unchecked_datasource<'a>(datasource_name: &'a str) -> #output {
+            #find_all_unchecked_datasource
         }
     }
 }
@@ -57,24 +80,41 @@ pub fn generate_find_all_tokens(
     table_schema_data: &String,
 ) -> TokenStream {
     let ty = macro_data.ty;
-    let stmt = format!("SELECT * FROM {table_schema_data}");
+    let stmt = format!(
+        "SELECT * FROM {table_schema_data}{}",
+        soft_delete_filter(macro_data)
+    );
+
+    let after_load = after_load_hooks(macro_data, quote! { entities });
+
+    let output = quote! { Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+    let find_all = |datasource_name: TokenStream| {
+        operation_scope(
+            macro_data,
+            "find_all",
+            &output,
+            quote! {
+                let mut entities = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+                    #stmt,
+                    &[],
+                    #datasource_name
+                ).await?
+                .into_results::<#ty>()?;
+                #after_load
+                Ok(entities)
+            },
+        )
+    };
+    let find_all_default = find_all(quote! { "" });
+    let find_all_datasource = find_all(quote! { datasource_name });
 
     quote! {
         /// Performs a `SELECT * FROM table_name`, where `table_name` it's
         /// the name of your entity but converted to the corresponding
         /// database convention. P.ej. PostgreSQL prefers table names declared
         /// with snake_case identifiers.
-        async fn find_all<'a>() ->
-            Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-        {
-            Ok(
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    #stmt,
-                    &[],
-                    ""
-                ).await?
-                .into_results::<#ty>()
-            )
+        async fn find_all<'a>() -> #output {
+            #find_all_default
         }
 
         /// Performs a `SELECT * FROM table_name`, where `table_name` it's
@@ -89,17 +129,8 @@ pub fn generate_find_all_tokens(
         /// Also, returns a [`Vec<T>, Error>`], wrapping a possible failure
         /// querying the database, or, if no errors happens, a Vec<T> containing
         /// the data found.
-        async fn find_all_datasource<'a>(datasource_name: &'a str) ->
-            Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-        {
-            Ok(
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    #stmt,
-                    &[],
-                    datasource_name
-                ).await?
-                .into_results::<#ty>()
-            )
+        async fn find_all_datasource<'a>(datasource_name: &'a str) -> #output {
+            #find_all_datasource
         }
     }
 }
@@ -111,6 +142,22 @@ pub fn generate_find_all_query_tokens(
 ) -> TokenStream {
     let ty = macro_data.ty;
 
+    let new_query_builder = |datasource_name: TokenStream| {
+        if let Some(soft_delete_column) = macro_data.get_soft_delete_column() {
+            quote! {
+                canyon_sql::query::SelectQueryBuilder::new_soft_deletable(
+                    #table_schema_data, #datasource_name, #soft_delete_column
+                )
+            }
+        } else {
+            quote! {
+                canyon_sql::query::SelectQueryBuilder::new(#table_schema_data, #datasource_name)
+            }
+        }
+    };
+    let select_query_builder = new_query_builder(quote! { "" });
+    let select_query_builder_datasource = new_query_builder(quote! { datasource_name });
+
     quote! {
         /// Generates a [`canyon_sql::query::SelectQueryBuilder`]
         /// that allows you to customize the query by adding parameters and constrains dynamically.
@@ -120,7 +167,7 @@ pub fn generate_find_all_query_tokens(
         /// unless concrete values are set on the available parameters of the
         /// `canyon_macro(table_name = "table_name", schema = "schema")`
         fn select_query<'a>() -> canyon_sql::query::SelectQueryBuilder<'a, #ty> {
-            canyon_sql::query::SelectQueryBuilder::new(#table_schema_data, "")
+            #select_query_builder
         }
 
         /// Generates a [`canyon_sql::query::SelectQueryBuilder`]
@@ -135,7 +182,7 @@ pub fn generate_find_all_query_tokens(
         /// described in the configuration file, and selected with the [`&str`]
         /// passed as parameter.
         fn select_query_datasource<'a>(datasource_name: &'a str) -> canyon_sql::query::SelectQueryBuilder<'a, #ty> {
-            canyon_sql::query::SelectQueryBuilder::new(#table_schema_data, datasource_name)
+            #select_query_builder_datasource
         }
     }
 }
@@ -148,7 +195,10 @@ pub fn generate_count_tokens(
 ) -> TokenStream {
     let ty = macro_data.ty;
     let ty_str = &ty.to_string();
-    let stmt = format!("SELECT COUNT(*) FROM {table_schema_data}");
+    let stmt = format!(
+        "SELECT COUNT(*) FROM {table_schema_data}{}",
+        soft_delete_filter(macro_data)
+    );
 
     let result_handling = quote! {
         #[cfg(feature="postgres")]
@@ -169,33 +219,39 @@ pub fn generate_count_tokens(
             _ => panic!() // TODO remove when the generics will be refactored
     };
 
+    let output = quote! { Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+    let count = |datasource_name: TokenStream| {
+        operation_scope(
+            macro_data,
+            "count",
+            &output,
+            quote! {
+                let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+                    #stmt,
+                    &[],
+                    #datasource_name
+                ).await?;
+
+                match count {
+                    #result_handling
+                }
+            },
+        )
+    };
+    let count_default = count(quote! { "" });
+    let count_datasource = count(quote! { datasource_name });
+
     quote! {
         /// Performs a COUNT(*) query over some table, returning a [`Result`] rather than panicking,
         /// wrapping a possible success or error coming from the database
-        async fn count() -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-            let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                #stmt,
-                &[],
-                ""
-            ).await?;
-
-            match count {
-                #result_handling
-            }
+        async fn count() -> #output {
+            #count_default
         }
 
         /// Performs a COUNT(*) query over some table, returning a [`Result`] rather than panicking,
         /// wrapping a possible success or error coming from the database with the specified datasource
-        async fn count_datasource<'a>(datasource_name: &'a str) -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-            let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                #stmt,
-                &[],
-                datasource_name
-            ).await?;
-
-            match count {
-                #result_handling
-            }
+        async fn count_datasource<'a>(datasource_name: &'a str) -> #output {
+            #count_datasource
         }
     }
 }
@@ -206,13 +262,17 @@ pub fn generate_find_by_pk_tokens(
     table_schema_data: &String,
 ) -> TokenStream {
     let ty = macro_data.ty;
-    let pk = macro_data.get_primary_key_annotation().unwrap_or_default();
-    let stmt = format!("SELECT * FROM {table_schema_data} WHERE {pk} = $1");
+    let pk_columns = macro_data.get_primary_key_fields().len();
+    let stmt = format!(
+        "SELECT * FROM {table_schema_data} WHERE {}{}",
+        primary_key_condition(macro_data, false),
+        soft_delete_filter(macro_data).replace(" WHERE ", " AND ")
+    );
 
     // Disabled if there's no `primary_key` annotation
-    if pk.is_empty() {
+    if pk_columns == 0 {
         return quote! {
-            async fn find_by_pk<'a>(value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>)
+            async fn find_by_pk<'a>(value: &'a dyn canyon_sql::crud::bounds::PrimaryKeyValue<'a>)
                 -> Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
             {
                 Err(
@@ -226,7 +286,7 @@ pub fn generate_find_by_pk_tokens(
             }
 
             async fn find_by_pk_datasource<'a>(
-                value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>,
+                value: &'a dyn canyon_sql::crud::bounds::PrimaryKeyValue<'a>,
                 datasource_name: &'a str
             ) -> Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
                 Err(
@@ -241,21 +301,62 @@ pub fn generate_find_by_pk_tokens(
         };
     }
 
+    // The composite primary keys are given as a tuple with a value for every column
+    let pk_values = quote! {
+        let values = value.primary_key_values();
+        if values.len() != #pk_columns {
+            return Err(format!(
+                "The primary key of {} has {} columns, but {} values were given",
+                #table_schema_data,
+                #pk_columns,
+                values.len()
+            ).into());
+        }
+    };
+
+    let after_load = hook_call(macro_data, "after_load", quote! { &mut entity });
     let result_handling = quote! {
         match result {
             n if n.len() == 0 => Ok(None),
-            _ => Ok(
-                Some(result.into_results::<#ty>().remove(0))
-            )
+            _ => {
+                let mut entity = result.into_results::<#ty>()?.remove(0);
+                #after_load
+                Ok(Some(entity))
+            }
         }
     };
 
+    let output =
+        quote! { Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+    let find_by_pk = |datasource_name: TokenStream| {
+        operation_scope(
+            macro_data,
+            "find_by_pk",
+            &output,
+            quote! {
+                #pk_values
+                let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+                    #stmt,
+                    values,
+                    #datasource_name
+                ).await?;
+
+                #result_handling
+            },
+        )
+    };
+    let find_by_pk_default = find_by_pk(quote! { "" });
+    let find_by_pk_datasource = find_by_pk(quote! { datasource_name });
+
     quote! {
         /// Finds an element on the queried table that matches the
         /// value of the field annotated with the `primary_key` attribute,
         /// filtering by the column that it's declared as the primary
         /// key on the database.
         ///
+        /// For the entities with a composite primary key, the value is a tuple
+        /// with the values of every `#[primary_key]` field, in the order of declaration.
+        ///
         /// This operation it's only available if the [`CanyonEntity`] contains
         /// some field declared as primary key.
         ///
@@ -263,16 +364,8 @@ pub fn generate_find_by_pk_tokens(
         /// querying the database, or, if no errors happens, a success containing
         /// and Option<T> with the data found wrapped in the Some(T) variant,
         /// or None if the value isn't found on the table.
-        async fn find_by_pk<'a>(value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>) ->
-            Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-        {
-            let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                #stmt,
-                vec![value],
-                ""
-            ).await?;
-
-            #result_handling
+        async fn find_by_pk<'a>(value: &'a dyn canyon_sql::crud::bounds::PrimaryKeyValue<'a>) -> #output {
+            #find_by_pk_default
         }
 
         /// Finds an element on the queried table that matches the
@@ -284,6 +377,9 @@ pub fn generate_find_by_pk_tokens(
         /// described in the configuration file, and selected with the [`&str`]
         /// passed as parameter.
         ///
+        /// For the entities with a composite primary key, the value is a tuple
+        /// with the values of every `#[primary_key]` field, in the order of declaration.
+        ///
         /// This operation it's only available if the [`CanyonEntity`] contains
         /// some field declared as primary key.
         ///
@@ -292,17 +388,145 @@ pub fn generate_find_by_pk_tokens(
         /// and Option<T> with the data found wrapped in the Some(T) variant,
         /// or None if the value isn't found on the table.
         async fn find_by_pk_datasource<'a>(
-            value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>,
+            value: &'a dyn canyon_sql::crud::bounds::PrimaryKeyValue<'a>,
             datasource_name: &'a str
-        ) -> Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
+        ) -> #output {
+            #find_by_pk_datasource
+        }
+    }
+}
+
+/// Generates the TokenStream for build the __find_by_pks() CRUD operation, that looks for
+/// many primary keys with a single statement for every chunk of keys
+pub fn generate_find_by_pks_tokens(
+    macro_data: &MacroTokens<'_>,
+    table_schema_data: &String,
+) -> TokenStream {
+    let ty = macro_data.ty;
+    let pk_fields = macro_data.get_primary_key_fields();
+    let pk_columns = pk_fields.len();
+
+    // Disabled if there's no `primary_key` annotation
+    if pk_columns == 0 {
+        return quote! {
+            async fn find_by_pks<'a, P: canyon_sql::crud::bounds::PrimaryKeyValue<'a>>(values: &'a [P])
+                -> Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+            {
+                Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::Unsupported,
+                        "You can't use the 'find_by_pks' associated function on a \
+                        CanyonEntity that does not have a #[primary_key] annotation. \
+                        If you need to perform an specific search, use the Querybuilder instead."
+                    ).into_inner().unwrap()
+                )
+            }
+
+            async fn find_by_pks_datasource<'a, P: canyon_sql::crud::bounds::PrimaryKeyValue<'a>>(
+                values: &'a [P],
+                datasource_name: &'a str
+            ) -> Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
+                Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::Unsupported,
+                        "You can't use the 'find_by_pks_datasource' associated function on a \
+                        CanyonEntity that does not have a #[primary_key] annotation. \
+                        If you need to perform an specific search, use the Querybuilder instead."
+                    ).into_inner().unwrap()
+                )
+            }
+        };
+    }
+
+    let columns = pk_fields
+        .iter()
+        .map(|(_idx, ident, _ty)| ident.to_string())
+        .collect::<Vec<_>>();
+    let soft_delete_filter = soft_delete_filter(macro_data).replace(" WHERE ", " AND ");
+    let after_load = after_load_hooks(macro_data, quote! { entities });
+
+    // Every chunk of keys is looked up with its own statement, so the parameters bound
+    // never exceeds the limit of the database
+    let find_by_pks = quote! {
+        let mut keys = Vec::with_capacity(values.len());
+        for value in values.iter() {
+            let key = value.primary_key_values();
+            if key.len() != #pk_columns {
+                return Err(format!(
+                    "The primary key of {} has {} columns, but {} values were given",
+                    #table_schema_data,
+                    #pk_columns,
+                    key.len()
+                ).into());
+            }
+            keys.push(key);
+        }
 
-            let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                #stmt,
-                vec![value],
-                datasource_name
-            ).await?;
+        let mut entities = Vec::with_capacity(keys.len());
+        let chunk_len = canyon_sql::crud::batch::chunk_len(datasource_name, #pk_columns);
+        for chunk in keys.chunks(chunk_len) {
+            let stmt = format!(
+                "SELECT * FROM {} WHERE {}{}",
+                #table_schema_data,
+                canyon_sql::crud::batch::primary_keys_condition(&[#(#columns),*], chunk.len()),
+                #soft_delete_filter
+            );
+            let params = chunk.iter().flatten().copied().collect::<Vec<_>>();
+            entities.extend(
+                <#ty as canyon_sql::crud::Transaction<#ty>>::query(stmt, params, datasource_name)
+                    .await?
+                    .into_results::<#ty>()?
+            );
+        }
+        #after_load
+        Ok(entities)
+    };
+    let output = quote! { Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+    let find_by_pks_default = operation_scope(
+        macro_data,
+        "find_by_pks",
+        &output,
+        quote! {
+            let datasource_name = "";
+            #find_by_pks
+        },
+    );
+    let find_by_pks_datasource = operation_scope(macro_data, "find_by_pks", &output, find_by_pks);
+
+    quote! {
+        /// Finds the elements on the queried table that matches any of the values
+        /// of the field annotated with the `primary_key` attribute, with a single
+        /// `WHERE pk IN (...)` statement instead of one per value.
+        ///
+        /// For the entities with a composite primary key, every value is a tuple
+        /// with the values of every `#[primary_key]` field, in the order of declaration.
+        ///
+        /// The values are split in chunks that stay under the limit of parameters of
+        /// the database, with an statement for each one, and the entities are returned
+        /// in no particular order. The values not found on the table are just left out.
+        async fn find_by_pks<'a, P: canyon_sql::crud::bounds::PrimaryKeyValue<'a>>(values: &'a [P]) -> #output {
+            #find_by_pks_default
+        }
 
-            #result_handling
+        /// Finds the elements on the queried table that matches any of the values
+        /// of the field annotated with the `primary_key` attribute, with a single
+        /// `WHERE pk IN (...)` statement instead of one per value.
+        ///
+        /// The query it's made against the database with the configured datasource
+        /// described in the configuration file, and selected with the [`&str`]
+        /// passed as parameter.
+        ///
+        /// For the entities with a composite primary key, every value is a tuple
+        /// with the values of every `#[primary_key]` field, in the order of declaration.
+        ///
+        /// The values are split in chunks that stay under the limit of parameters of
+        /// the database, with an statement for each one, and the entities are returned
+        /// in no particular order. The values not found on the table are just left out.
+        async fn find_by_pks_datasource<'a, P: canyon_sql::crud::bounds::PrimaryKeyValue<'a>>(
+            values: &'a [P],
+            datasource_name: &'a str
+        ) -> #output {
+            #find_by_pks_datasource
         }
     }
 }
@@ -316,77 +540,94 @@ pub fn generate_find_by_foreign_key_tokens(
 ) -> Vec<(TokenStream, TokenStream)> {
     let mut fk_quotes: Vec<(TokenStream, TokenStream)> = Vec::new();
 
-    for (field_ident, fk_annot) in macro_data.get_fk_annotations().iter() {
-        if let EntityFieldAnnotation::ForeignKey(table, column) = fk_annot {
-            let method_name = "search_".to_owned() + table;
-
-            // TODO this is not a good implementation. We must try to capture the
-            // related entity in some way, and compare it with something else
-            let fk_ty = database_table_name_to_struct_ident(table);
-
-            // Generate and identifier for the method based on the convention of "search_related_types"
-            // where types is a placeholder for the plural name of the type referenced
-            let method_name_ident =
-                proc_macro2::Ident::new(&method_name, proc_macro2::Span::call_site());
-            let method_name_ident_ds = proc_macro2::Ident::new(
-                &format!("{}_datasource", &method_name),
-                proc_macro2::Span::call_site(),
-            );
-            let quoted_method_signature: TokenStream = quote! {
-                async fn #method_name_ident(&self) ->
-                    Result<Option<#fk_ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-            };
-            let quoted_datasource_method_signature: TokenStream = quote! {
-                async fn #method_name_ident_ds<'a>(&self, datasource_name: &'a str) ->
-                    Result<Option<#fk_ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-            };
-
-            let stmt = format!(
-                "SELECT * FROM {} WHERE {} = $1",
-                table,
-                format!("\"{column}\"").as_str(),
-            );
-            let result_handler = quote! {
-                match result {
-                    n if n.len() == 0 => Ok(None),
-                    _ => Ok(Some(
-                        result.into_results::<#fk_ty>().remove(0)
-                    ))
-                }
-            };
+    let foreign_keys = macro_data.get_fk_annotations_by_table();
+    for (table, fk_columns) in foreign_keys.iter() {
+        let method_name = format!(
+            "search_{table}{}",
+            foreign_key_method_suffix(&foreign_keys, table, fk_columns)
+        );
+
+        // TODO this is not a good implementation. We must try to capture the
+        // related entity in some way, and compare it with something else
+        let fk_ty = database_table_name_to_struct_ident(table);
+
+        // Generate and identifier for the method based on the convention of "search_related_types"
+        // where types is a placeholder for the plural name of the type referenced
+        let method_name_ident =
+            proc_macro2::Ident::new(&method_name, proc_macro2::Span::call_site());
+        let method_name_ident_ds = proc_macro2::Ident::new(
+            &format!("{}_datasource", &method_name),
+            proc_macro2::Span::call_site(),
+        );
+        let output =
+            quote! { Result<Option<#fk_ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+        let quoted_method_signature: TokenStream = quote! {
+            async fn #method_name_ident(&self) -> #output
+        };
+        let quoted_datasource_method_signature: TokenStream = quote! {
+            async fn #method_name_ident_ds<'a>(&self, datasource_name: &'a str) -> #output
+        };
 
-            fk_quotes.push((
-                quote! { #quoted_method_signature; },
+        let stmt = format!(
+            "SELECT * FROM {} WHERE {}",
+            table,
+            fk_columns
+                .iter()
+                .enumerate()
+                .map(|(idx, (_field, column))| format!("\"{column}\" = ${}", idx + 1))
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        );
+        let fk_values = fk_columns.iter().map(|(field_ident, _column)| {
+            quote! { &self.#field_ident as &dyn canyon_sql::crud::bounds::QueryParameter<'_> }
+        });
+        let result_handler = quote! {
+            match result {
+                n if n.len() == 0 => Ok(None),
+                _ => Ok(Some(
+                    result.into_results::<#fk_ty>()?.remove(0)
+                ))
+            }
+        };
+        let search = |datasource_name: TokenStream| {
+            let fk_values = fk_values.clone();
+            operation_scope(
+                macro_data,
+                &method_name,
+                &output,
                 quote! {
-                    /// Searches the parent entity (if exists) for this type
-                    #quoted_method_signature {
-                        let result = <#fk_ty as canyon_sql::crud::Transaction<#fk_ty>>::query(
-                            #stmt,
-                            &[&self.#field_ident as &dyn canyon_sql::crud::bounds::QueryParameter<'_>],
-                            ""
-                        ).await?;
-
-                        #result_handler
-                    }
-                }
-            ));
+                    let result = <#fk_ty as canyon_sql::crud::Transaction<#fk_ty>>::query(
+                        #stmt,
+                        &[#(#fk_values),*],
+                        #datasource_name
+                    ).await?;
 
-            fk_quotes.push((
-                quote! { #quoted_datasource_method_signature; },
-                quote! {
-                    /// Searches the parent entity (if exists) for this type with the specified datasource
-                    #quoted_datasource_method_signature {
-                        let result = <#fk_ty as canyon_sql::crud::Transaction<#fk_ty>>::query(
-                            #stmt,
-                            &[&self.#field_ident as &dyn canyon_sql::crud::bounds::QueryParameter<'_>],
-                            datasource_name
-                        ).await?;
-
-                        #result_handler
-                    }
+                    #result_handler
+                },
+            )
+        };
+        let search_default = search(quote! { "" });
+        let search_datasource = search(quote! { datasource_name });
+
+        fk_quotes.push((
+            quote! { #quoted_method_signature; },
+            quote! {
+                /// Searches the parent entity (if exists) for this type
+                #quoted_method_signature {
+                    #search_default
                 }
-            ));
-        }
+            },
+        ));
+
+        fk_quotes.push((
+            quote! { #quoted_datasource_method_signature; },
+            quote! {
+                /// Searches the parent entity (if exists) for this type with the specified datasource
+                #quoted_datasource_method_signature {
+                    #search_datasource
+                }
+            },
+        ));
     }
 
     fk_quotes
@@ -402,87 +643,216 @@ pub fn generate_find_by_reverse_foreign_key_tokens(
 ) -> Vec<(TokenStream, TokenStream)> {
     let mut rev_fk_quotes: Vec<(TokenStream, TokenStream)> = Vec::new();
     let ty = macro_data.ty;
+    let rev_fk_soft_delete_filter = soft_delete_filter(macro_data).replace(" WHERE ", " AND ");
+    let after_load = after_load_hooks(macro_data, quote! { entities });
+
+    let foreign_keys = macro_data.get_fk_annotations_by_table();
+    for (table, fk_columns) in foreign_keys.iter() {
+        let method_name = format!(
+            "search_{table}_childrens{}",
+            foreign_key_method_suffix(&foreign_keys, table, fk_columns)
+        );
+
+        // Generate and identifier for the method based on the convention of "search_by__" (note the double underscore)
+        // plus the 'table_name' property of the ForeignKey annotation
+        let method_name_ident =
+            proc_macro2::Ident::new(&method_name, proc_macro2::Span::call_site());
+        let method_name_ident_ds = proc_macro2::Ident::new(
+            &format!("{}_datasource", &method_name),
+            proc_macro2::Span::call_site(),
+        );
+        let output =
+            quote! { Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> };
+        let quoted_method_signature: TokenStream = quote! {
+            async fn #method_name_ident<'a, F: canyon_sql::crud::bounds::ForeignKeyable<F> + Sync + Send>(value: &F) ->
+                #output
+        };
+        let quoted_datasource_method_signature: TokenStream = quote! {
+            async fn #method_name_ident_ds<'a, F: canyon_sql::crud::bounds::ForeignKeyable<F> + Sync + Send>
+                (value: &F, datasource_name: &'a str) ->
+                #output
+        };
+
+        // The columns of the parent referenced by the foreign key, and the condition that
+        // compares them with the fields of the children
+        let columns = fk_columns
+            .iter()
+            .map(|(_field, column)| column)
+            .collect::<Vec<_>>();
+        let fk_condition = fk_columns
+            .iter()
+            .enumerate()
+            .map(|(idx, (field_ident, _column))| format!("\"{field_ident}\" = ${}", idx + 1))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let lookage_values = quote! {
+            let lookage_values = value.get_fk_columns(&[#(#columns),*])
+                .expect(format!(
+                    "Columns: {:?} not found in type: {:?}", [#(#columns),*], #table
+                ).as_str());
 
-    for (field_ident, fk_annot) in macro_data.get_fk_annotations().iter() {
-        if let EntityFieldAnnotation::ForeignKey(table, column) = fk_annot {
-            let method_name = format!("search_{table}_childrens");
-
-            // Generate and identifier for the method based on the convention of "search_by__" (note the double underscore)
-            // plus the 'table_name' property of the ForeignKey annotation
-            let method_name_ident =
-                proc_macro2::Ident::new(&method_name, proc_macro2::Span::call_site());
-            let method_name_ident_ds = proc_macro2::Ident::new(
-                &format!("{}_datasource", &method_name),
-                proc_macro2::Span::call_site(),
+            let stmt = format!(
+                "SELECT * FROM {} WHERE {}{}",
+                #table_schema_data,
+                #fk_condition,
+                #rev_fk_soft_delete_filter
             );
-            let quoted_method_signature: TokenStream = quote! {
-                async fn #method_name_ident<'a, F: canyon_sql::crud::bounds::ForeignKeyable<F> + Sync + Send>(value: &F) ->
-                    Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-            };
-            let quoted_datasource_method_signature: TokenStream = quote! {
-                async fn #method_name_ident_ds<'a, F: canyon_sql::crud::bounds::ForeignKeyable<F> + Sync + Send>
-                    (value: &F, datasource_name: &'a str) ->
-                    Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
-            };
-
-            let f_ident = field_ident.to_string();
-
-            rev_fk_quotes.push((
-                quote! { #quoted_method_signature; },
+        };
+        let search = |datasource_name: TokenStream| {
+            operation_scope(
+                macro_data,
+                &method_name,
+                &output,
                 quote! {
-                    /// Given a parent entity T annotated with the derive proc macro `ForeignKeyable`,
-                    /// performns a search to find the children that belong to that concrete parent.
-                    #quoted_method_signature
-                    {
-                        let lookage_value = value.get_fk_column(#column)
-                            .expect(format!(
-                                "Column: {:?} not found in type: {:?}", #column, #table
-                            ).as_str());
-
-                        let stmt = format!(
-                            "SELECT * FROM {} WHERE {} = $1",
-                            #table_schema_data,
-                            format!("\"{}\"", #f_ident).as_str()
-                        );
-
-                        Ok(<#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                            stmt,
-                            &[lookage_value],
-                            ""
-                        ).await?.into_results::<#ty>())
-                    }
+                    #lookage_values
+
+                    let mut entities = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+                        stmt,
+                        lookage_values,
+                        #datasource_name
+                    ).await?.into_results::<#ty>()?;
+                    #after_load
+                    Ok(entities)
                 },
-            ));
+            )
+        };
+        let search_default = search(quote! { "" });
+        let search_datasource = search(quote! { datasource_name });
+
+        rev_fk_quotes.push((
+            quote! { #quoted_method_signature; },
+            quote! {
+                /// Given a parent entity T annotated with the derive proc macro `ForeignKeyable`,
+                /// performns a search to find the children that belong to that concrete parent.
+                #quoted_method_signature
+                {
+                    #search_default
+                }
+            },
+        ));
+
+        rev_fk_quotes.push((
+            quote! { #quoted_datasource_method_signature; },
+            quote! {
+                /// Given a parent entity T annotated with the derive proc macro `ForeignKeyable`,
+                /// performns a search to find the children that belong to that concrete parent
+                /// with the specified datasource.
+                #quoted_datasource_method_signature
+                {
+                    #search_datasource
+                }
+            },
+        ));
+    }
 
-            rev_fk_quotes.push((
-                quote! { #quoted_datasource_method_signature; },
-                quote! {
-                    /// Given a parent entity T annotated with the derive proc macro `ForeignKeyable`,
-                    /// performns a search to find the children that belong to that concrete parent
-                    /// with the specified datasource.
-                    #quoted_datasource_method_signature
-                    {
-                        let lookage_value = value.get_fk_column(#column)
-                            .expect(format!(
-                                "Column: {:?} not found in type: {:?}", #column, #table
-                            ).as_str());
-
-                        let stmt = format!(
-                            "SELECT * FROM {} WHERE {} = $1",
-                            #table_schema_data,
-                            format!("\"{}\"", #f_ident).as_str()
-                        );
-
-                        Ok(<#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                            stmt,
-                            &[lookage_value],
-                            datasource_name
-                        ).await?.into_results::<#ty>())
-                    }
-                },
-            ));
+    rev_fk_quotes
+}
+
+/// Generates the `WHERE` clause that discards the rows logically deleted, for the
+/// entities declared with the `soft_delete` argument of the `canyon_entity` macro.
+///
+/// Returns an empty [`String`] for every other entity
+fn soft_delete_filter(macro_data: &MacroTokens<'_>) -> String {
+    macro_data
+        .get_soft_delete_column()
+        .map(|column| format!(" WHERE {column} IS NULL"))
+        .unwrap_or_default()
+}
+
+/// The suffix of the names of the search methods of a foreign key, that tells apart the
+/// foreign keys that references the same table with the fields that they're made of,
+/// like `search_team_by_home_team` and `search_team_by_away_team`
+fn foreign_key_method_suffix(
+    foreign_keys: &[ForeignKey<'_>],
+    table: &str,
+    fk_columns: &[(&proc_macro2::Ident, String)],
+) -> String {
+    let same_table = foreign_keys
+        .iter()
+        .filter(|(fk_table, _)| fk_table == table)
+        .count();
+    if same_table == 1 {
+        return String::new();
+    }
+
+    let fields = fk_columns
+        .iter()
+        .map(|(field, _column)| field.to_string())
+        .collect::<Vec<_>>();
+    format!("_by_{}", fields.join("_"))
+}
+
+/// Generates the calls to the `after_load` hook over every entity of the `entities`
+/// collection, for the entities declared with `#[canyon_entity(hooks)]`
+fn after_load_hooks(macro_data: &MacroTokens<'_>, entities: TokenStream) -> TokenStream {
+    let after_load = hook_call(macro_data, "after_load", quote! { entity });
+    if after_load.is_empty() {
+        return after_load;
+    }
+
+    quote! {
+        for entity in #entities.iter_mut() {
+            #after_load
         }
     }
+}
 
-    rev_fk_quotes
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::DeriveInput;
+
+    /// The names of the search methods generated for the entity
+    fn search_methods(entity: &str) -> Vec<String> {
+        let ast: DeriveInput = syn::parse_str(entity).unwrap();
+        let macro_data = MacroTokens::new(&ast);
+        generate_find_by_foreign_key_tokens(&macro_data)
+            .iter()
+            .map(|(signature, _)| {
+                let method: syn::TraitItemMethod = syn::parse2(signature.clone()).unwrap();
+                method.sig.ident.to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn foreign_keys_to_the_same_table_are_kept_apart() {
+        let methods = search_methods(
+            r#"
+            struct Game {
+                #[foreign_key(table = "team", column = "id")]
+                home_team: i32,
+                #[foreign_key(table = "team", column = "id")]
+                away_team: i32,
+            }
+            "#,
+        );
+        assert_eq!(
+            methods,
+            [
+                "search_team_by_home_team",
+                "search_team_by_home_team_datasource",
+                "search_team_by_away_team",
+                "search_team_by_away_team_datasource"
+            ]
+        );
+    }
+
+    #[test]
+    fn composite_foreign_keys_shares_the_constraint() {
+        let methods = search_methods(
+            r#"
+            struct SeasonStanding {
+                #[foreign_key(table = "league_season", column = "league", constraint = "fk")]
+                league: i32,
+                #[foreign_key(table = "league_season", column = "season", constraint = "fk")]
+                season: i32,
+            }
+            "#,
+        );
+        assert_eq!(
+            methods,
+            ["search_league_season", "search_league_season_datasource"]
+        );
+    }
 }
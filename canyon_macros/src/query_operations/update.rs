@@ -1,72 +1,174 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::utils::helpers::{hook_call, operation_scope, primary_key_condition, validation_checks};
 use crate::utils::macro_tokens::MacroTokens;
 
 /// Generates the TokenStream for the __update() CRUD operation
 pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &String) -> TokenStream {
     let ty = macro_data.ty;
 
-    let update_columns = macro_data.get_column_names_pk_parsed();
+    // Every primary key column is left out of the `SET` clause, even the ones of a
+    // composite key that are also a foreign key
+    let pk_columns = macro_data
+        .get_primary_key_fields()
+        .into_iter()
+        .map(|(_idx, ident, _ty)| format!("\"{ident}\""))
+        .collect::<Vec<_>>();
+    let update_columns = macro_data
+        .get_column_names_pk_parsed()
+        .into_iter()
+        .filter(|column| !pk_columns.contains(column))
+        .collect::<Vec<_>>();
 
     // Retrieves the fields of the Struct
     let fields = macro_data.get_struct_fields();
 
+    // The column annotated with `#[version]` is incremented by the database itself,
+    // while the value of the instance is used to check that the record wasn't modified
+    let version_column = macro_data
+        .get_version_field()
+        .map(|(ident, _ty)| format!("\"{ident}\""));
+    let mut version_condition = String::new();
+
+    // Every field is bound, so each column takes the placeholder of its field
+    let placeholder = |column_name: &String| {
+        fields
+            .iter()
+            .position(|field| format!("\"{field}\"") == *column_name)
+            .expect("Update method failed to retrieve the index of a column")
+            + 1
+    };
+
     let mut vec_columns_values: Vec<String> = Vec::new();
-    for (i, column_name) in update_columns.iter().enumerate() {
-        let column_equal_value = format!("{} = ${}", column_name.to_owned(), i + 2);
+    for column_name in update_columns.iter() {
+        let column_equal_value = if Some(column_name) == version_column.as_ref() {
+            version_condition = format!(" AND {} = ${}", column_name, placeholder(column_name));
+            format!("{column_name} = {column_name} + 1")
+        } else {
+            format!("{} = ${}", column_name.to_owned(), placeholder(column_name))
+        };
         vec_columns_values.push(column_equal_value)
     }
 
     let str_columns_values = vec_columns_values.join(", ");
 
-    let update_values = fields.iter().map(|ident| {
-        quote! { &self.#ident }
+    let not_updated_err = if version_column.is_some() {
+        quote! {
+            canyon_sql::crud::VersionConflictError {
+                table: #table_schema_data.to_string()
+            }.into()
+        }
+    } else {
+        quote! {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!(
+                    "There's no record on {} with the primary key of the instance to update",
+                    #table_schema_data
+                )
+            ).into()
+        }
+    };
+
+    // The field annotated with `#[updated_at]` is updated with the current time,
+    // instead of with the value that holds the instance
+    let updated_at_field = macro_data.get_updated_at_field();
+    let updated_at_now = updated_at_field.as_ref().map(|(_ident, ty)| {
+        quote! { let updated_at_now = <#ty as canyon_sql::crud::bounds::Timestamp>::now(); }
+    });
+
+    // Once the record is updated, the instance takes the new values of the columns
+    // written by Canyon itself
+    let increment_version = macro_data
+        .get_version_field()
+        .map(|(version_ident, _ty)| quote! { self.#version_ident += 1; });
+    let write_updated_at = updated_at_field
+        .as_ref()
+        .map(|(updated_at, _ty)| quote! { self.#updated_at = updated_at_now; });
+
+    let before_update = hook_call(macro_data, "before_update", quote! { self });
+    let after_update = hook_call(macro_data, "after_update", quote! { self });
+    let validations = validation_checks(macro_data, quote! { self });
+
+    let update_values = fields.iter().map(|ident| match &updated_at_field {
+        Some((updated_at, _ty)) if updated_at == ident => quote! { &updated_at_now },
+        _ => quote! { &self.#ident },
     });
-    let update_values_cloned = update_values.clone();
+    let output = quote! { Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>> };
 
-    if let Some(primary_key) = macro_data.get_primary_key_annotation() {
-        let pk_index = macro_data
-            .get_pk_index()
-            .expect("Update method failed to retrieve the index of the primary key");
+    if macro_data.type_has_primary_key() {
+        // Matches the row by every column of the primary key, when it's a composite one
+        let pk_condition = primary_key_condition(macro_data, true);
+
+        let update = |datasource_name: TokenStream| {
+            let update_values = update_values.clone();
+            operation_scope(
+                macro_data,
+                "update",
+                &output,
+                quote! {
+                    #before_update
+                    #validations
+                    let stmt = format!(
+                        "UPDATE {} SET {} WHERE {}{}",
+                        #table_schema_data, #str_columns_values, #pk_condition, #version_condition
+                    );
+                    #updated_at_now
+                    let update_values: &[&dyn canyon_sql::crud::bounds::QueryParameter<'_>] = &[#(#update_values),*];
+
+                    let updated_rows = <#ty as canyon_sql::crud::Transaction<#ty>>::execute(
+                        stmt, update_values, #datasource_name
+                    ).await?;
+
+                    if updated_rows == 0 {
+                        return Err(#not_updated_err);
+                    }
+                    #increment_version
+                    #write_updated_at
+
+                    #after_update
+                    Ok(())
+                },
+            )
+        };
+        let update_default = update(quote! { "" });
+        let update_datasource = update(quote! { datasource_name });
 
         quote! {
             /// Updates a database record that matches
             /// the current instance of a T type, returning a result
-            /// indicating a possible failure querying the database.
-            async fn update(&self) -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>> {
-                let stmt = format!(
-                    "UPDATE {} SET {} WHERE {} = ${:?}",
-                    #table_schema_data, #str_columns_values, #primary_key, #pk_index + 1
-                );
-                let update_values: &[&dyn canyon_sql::crud::bounds::QueryParameter<'_>] = &[#(#update_values),*];
-
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    stmt, update_values, ""
-                ).await?;
-
-                Ok(())
+            /// indicating a possible failure querying the database,
+            /// or a [`std::io::ErrorKind::NotFound`] error if there's no record to update.
+            ///
+            /// If the entity has a field annotated with `#[version]`, the record is only
+            /// updated if its version matches the one of the instance, incrementing it,
+            /// and a [`canyon_sql::crud::VersionConflictError`] is returned otherwise.
+            /// The version of `self` is incremented along with the one of the record.
+            ///
+            /// The field annotated with `#[updated_at]` (if any) is set to the current
+            /// time, both in the record and in `self`.
+            ///
+            /// For the entities declared with `#[canyon_entity(hooks)]`, the `before_update`
+            /// and `after_update` hooks are called around the update.
+            ///
+            /// The fields annotated with `#[validate(...)]` are checked before the update, returning
+            /// a [`canyon_sql::crud::validation::ValidationError`] with every failed rule.
+            async fn update(&mut self) -> #output {
+                #update_default
             }
 
 
             /// Updates a database record that matches
             /// the current instance of a T type, returning a result
             /// indicating a possible failure querying the database with the
-            /// specified datasource
-            async fn update_datasource<'a>(&self, datasource_name: &'a str)
-                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
-            {
-                let stmt = format!(
-                    "UPDATE {} SET {} WHERE {} = ${:?}",
-                    #table_schema_data, #str_columns_values, #primary_key, #pk_index + 1
-                );
-                let update_values: &[&dyn canyon_sql::crud::bounds::QueryParameter<'_>] = &[#(#update_values_cloned),*];
-
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    stmt, update_values, datasource_name
-                ).await?;
-
-                Ok(())
+            /// specified datasource, or a [`std::io::ErrorKind::NotFound`] error
+            /// if there's no record to update.
+            ///
+            /// As in `update`, a [`canyon_sql::crud::VersionConflictError`] is returned
+            /// for the entities with a `#[version]` field if the record was modified meanwhile.
+            async fn update_datasource<'a>(&mut self, datasource_name: &'a str) -> #output {
+                #update_datasource
             }
         }
     } else {
@@ -75,7 +177,7 @@ pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
 
         // TODO Returning an error should be a provisional way of doing this
         quote! {
-            async fn update(&self)
+            async fn update(&mut self)
                 -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
             {
                 Err(
@@ -88,7 +190,7 @@ pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
                 )
             }
 
-            async fn update_datasource<'a>(&self, datasource_name: &'a str)
+            async fn update_datasource<'a>(&mut self, datasource_name: &'a str)
                 -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
             {
                 Err(
@@ -112,6 +214,39 @@ pub fn generate_update_query_tokens(
 ) -> TokenStream {
     let ty = macro_data.ty;
 
+    // The column of the `#[updated_at]` field is bumped by the querybuilder itself
+    let new_update_query_builder = |datasource_name: TokenStream| {
+        if let Some((updated_at, _ty)) = macro_data.get_updated_at_field() {
+            let updated_at = updated_at.to_string();
+            quote! {
+                canyon_sql::query::UpdateQueryBuilder::new_with_updated_at(
+                    #table_schema_data, #datasource_name, #updated_at
+                )
+            }
+        } else {
+            quote! { canyon_sql::query::UpdateQueryBuilder::new(#table_schema_data, #datasource_name) }
+        }
+    };
+    // MySQL looks for the updated rows by their primary keys
+    let primary_key = macro_data
+        .get_primary_key_fields()
+        .into_iter()
+        .map(|(_idx, ident, _ty)| ident.to_string())
+        .collect::<Vec<_>>();
+    let new_update_query_builder = |datasource_name: TokenStream| {
+        let update_query_builder = new_update_query_builder(datasource_name);
+        if primary_key.is_empty() {
+            return update_query_builder;
+        }
+        quote! {{
+            let mut update_query_builder = #update_query_builder;
+            update_query_builder.with_primary_key(&[#(#primary_key),*]);
+            update_query_builder
+        }}
+    };
+    let update_query_builder = new_update_query_builder(quote! { "" });
+    let update_query_builder_ds = new_update_query_builder(quote! { datasource_name });
+
     quote! {
         /// Generates a [`canyon_sql::query::UpdateQueryBuilder`]
         /// that allows you to customize the query by adding parameters and constrains dynamically.
@@ -121,7 +256,7 @@ pub fn generate_update_query_tokens(
         /// unless concrete values are set on the available parameters of the
         /// `canyon_macro(table_name = "table_name", schema = "schema")`
         fn update_query<'a>() -> canyon_sql::query::UpdateQueryBuilder<'a, #ty> {
-            canyon_sql::query::UpdateQueryBuilder::new(#table_schema_data, "")
+            #update_query_builder
         }
 
         /// Generates a [`canyon_sql::query::UpdateQueryBuilder`]
@@ -136,7 +271,7 @@ pub fn generate_update_query_tokens(
         /// described in the configuration file, and selected with the [`&str`]
         /// passed as parameter.
         fn update_query_datasource<'a>(datasource_name: &'a str) -> canyon_sql::query::UpdateQueryBuilder<'a, #ty> {
-            canyon_sql::query::UpdateQueryBuilder::new(#table_schema_data, datasource_name)
+            #update_query_builder_ds
         }
     }
 }
@@ -1,5 +1,7 @@
+use canyon_entities::field_annotation::{FieldValidation, KeyGenerator};
 use proc_macro2::{Ident, Span, TokenStream};
-use syn::{punctuated::Punctuated, MetaNameValue, Token};
+use quote::quote;
+use syn::{punctuated::Punctuated, Meta, NestedMeta, Token};
 
 use super::macro_tokens::MacroTokens;
 
@@ -18,11 +20,16 @@ pub fn table_schema_parser(macro_data: &MacroTokens<'_>) -> Result<String, Token
             .iter()
             .any(|seg| seg.ident == "canyon_macros" || seg.ident == "canyon_entity")
         {
-            let name_values_result: Result<Punctuated<MetaNameValue, Token![,]>, syn::Error> =
+            let nested_metas_result: Result<Punctuated<NestedMeta, Token![,]>, syn::Error> =
                 attr.parse_args_with(Punctuated::parse_terminated);
 
-            if let Ok(meta_name_values) = name_values_result {
-                for nv in meta_name_values {
+            if let Ok(nested_metas) = nested_metas_result {
+                for nested_meta in nested_metas {
+                    let nv = match nested_meta {
+                        NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                        // Flags, like `hooks`, are handled by `MacroTokens`
+                        _ => continue,
+                    };
                     let ident = nv.path.get_ident();
                     if let Some(i) = ident {
                         let identifier = i;
@@ -32,6 +39,9 @@ pub fn table_schema_parser(macro_data: &MacroTokens<'_>) -> Result<String, Token
                                     table_name = Some(s.value())
                                 } else if identifier == "schema" {
                                     schema = Some(s.value())
+                                } else if identifier == "soft_delete" {
+                                    // Handled by `MacroTokens::get_soft_delete_column`
+                                    continue;
                                 } else {
                                     return Err(
                                         syn::Error::new_spanned(
@@ -77,6 +87,199 @@ pub fn table_schema_parser(macro_data: &MacroTokens<'_>) -> Result<String, Token
     Ok(macro_data.ty.to_string())
 }
 
+/// Generates the call to the `CanyonHooks` method named `hook` over the `receiver`, propagating
+/// its error, for the entities declared with the `hooks` argument of the `canyon_entity` macro.
+///
+/// Returns an empty [`TokenStream`] for every other entity
+pub fn hook_call(macro_data: &MacroTokens<'_>, hook: &str, receiver: TokenStream) -> TokenStream {
+    if !macro_data.has_hooks() {
+        return quote! {};
+    }
+
+    let ty = macro_data.ty;
+    let hook = Ident::new(hook, Span::call_site());
+    quote! { <#ty as canyon_sql::crud::CanyonHooks>::#hook(#receiver).await?; }
+}
+
+/// Generates the assignment of a new key to the primary key of the `receiver` declared with
+/// a `generate` argument, like `#[primary_key(generate = "uuid_v4")]`, unless it already
+/// holds a key that isn't the nil one.
+///
+/// Returns an empty [`TokenStream`] for every other entity
+pub fn primary_key_generation(macro_data: &MacroTokens<'_>, receiver: TokenStream) -> TokenStream {
+    let Some((pk_ident, generator)) = macro_data.get_primary_key_generator() else {
+        return quote! {};
+    };
+
+    let new_key = match generator {
+        KeyGenerator::UuidV4 => quote! { canyon_sql::uuid::Uuid::new_v4() },
+        KeyGenerator::UuidV7 => quote! { canyon_sql::uuid::Uuid::now_v7() },
+    };
+    quote! {
+        if #receiver.#pk_ident.is_nil() {
+            #receiver.#pk_ident = #new_key;
+        }
+    }
+}
+
+/// Generates the condition that matches the row of an instance, comparing every column of
+/// its primary key (one or many, for the composite ones) with a placeholder.
+///
+/// When `fields_bound` is true, every field of the instance is bound in the order of
+/// declaration, so each column gets the placeholder of its field. Otherwise, only the
+/// primary key fields are bound, starting on `$1`
+pub fn primary_key_condition(macro_data: &MacroTokens<'_>, fields_bound: bool) -> String {
+    macro_data
+        .get_primary_key_fields()
+        .iter()
+        .enumerate()
+        .map(|(pk_idx, (field_idx, ident, _ty))| {
+            let bound_idx = if fields_bound { *field_idx } else { pk_idx };
+            format!("\"{ident}\" = ${}", bound_idx + 1)
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// Generates the values of the primary key fields of the `receiver`, bound to the
+/// placeholders of the [`primary_key_condition`] when the fields aren't bound
+pub fn primary_key_values(macro_data: &MacroTokens<'_>, receiver: TokenStream) -> TokenStream {
+    let values = macro_data
+        .get_primary_key_fields()
+        .into_iter()
+        .map(|(_idx, ident, _ty)| {
+            quote! { &#receiver.#ident as &dyn canyon_sql::crud::bounds::QueryParameter<'_> }
+        });
+
+    quote! { #(#values),* }
+}
+
+/// Generates the implementation of [`canyon_sql::crud::bounds::PrimaryKey`] for the entities
+/// with some `#[primary_key]` field, whose key is a tuple for the composite primary keys.
+///
+/// Returns an empty [`TokenStream`] for every other entity
+pub fn primary_key_impl(macro_data: &MacroTokens<'_>) -> TokenStream {
+    let pk_fields = macro_data.get_primary_key_fields();
+    let (key_ty, key) = match pk_fields.as_slice() {
+        [] => return quote! {},
+        [(_idx, ident, ty)] => (quote! { #ty }, quote! { self.#ident.clone() }),
+        _ => {
+            let types = pk_fields.iter().map(|(_idx, _ident, ty)| ty);
+            let values = pk_fields.iter().map(|(_idx, ident, _ty)| ident);
+            (
+                quote! { (#(#types),*) },
+                quote! { (#(self.#values.clone()),*) },
+            )
+        }
+    };
+
+    let ty = macro_data.ty;
+    quote! {
+        impl canyon_sql::crud::bounds::PrimaryKey for #ty {
+            type Key = #key_ty;
+
+            fn primary_key(&self) -> Self::Key {
+                #key
+            }
+        }
+    }
+}
+
+/// Generates the checks of the rules declared with the `#[validate(...)]` attribute over
+/// the fields of the `receiver`, returning a [`canyon_sql::crud::validation::ValidationError`]
+/// with every failed one before any statement is sent to the database.
+///
+/// Returns an empty [`TokenStream`] for the entities without validations
+pub fn validation_checks(macro_data: &MacroTokens<'_>, receiver: TokenStream) -> TokenStream {
+    let field_validations = macro_data.get_field_validations();
+    if field_validations.is_empty() {
+        return quote! {};
+    }
+
+    let checks = field_validations
+        .iter()
+        .flat_map(|(field, ty, validations)| {
+            let field_name = field.to_string();
+            let receiver = &receiver;
+            validations.iter().map(move |validation| match validation {
+                FieldValidation::Length { min, max } => {
+                    let min = optional_tokens(min.map(|v| quote! { #v }));
+                    let max = optional_tokens(max.map(|v| quote! { #v }));
+                    quote! {
+                        canyon_sql::crud::validation::check_length(
+                            &mut validation_errors, #field_name, &#receiver.#field, #min, #max
+                        );
+                    }
+                }
+                FieldValidation::Range { min, max } => {
+                    let bound = |v: &String| {
+                        let v: TokenStream = v.parse().expect("Invalid `range` limit");
+                        quote! {
+                            (#v) as <#ty as canyon_sql::crud::validation::ValidateRange>::Value
+                        }
+                    };
+                    let min = optional_tokens(min.as_ref().map(bound));
+                    let max = optional_tokens(max.as_ref().map(bound));
+                    quote! {
+                        canyon_sql::crud::validation::check_range(
+                            &mut validation_errors, #field_name, &#receiver.#field, #min, #max
+                        );
+                    }
+                }
+                FieldValidation::Regex(pattern) => quote! {
+                    canyon_sql::crud::validation::check_regex(
+                        &mut validation_errors, #field_name, &#receiver.#field, #pattern
+                    );
+                },
+                FieldValidation::Custom(function) => {
+                    let function: syn::Path =
+                        syn::parse_str(function).expect("Invalid `custom` validation function");
+                    quote! {
+                        canyon_sql::crud::validation::check_custom(
+                            &mut validation_errors, #field_name, #function(&#receiver.#field)
+                        );
+                    }
+                }
+            })
+        });
+
+    quote! {
+        {
+            let mut validation_errors = canyon_sql::crud::validation::ValidationError::new();
+            #(#checks)*
+            validation_errors.into_result()?;
+        }
+    }
+}
+
+/// Wraps the body of a generated `async` operation within the scope of the operation, so
+/// the metrics of the statements launched by it are accounted to the entity and to the
+/// operation, named as the method without the `_datasource` suffix.
+///
+/// The `async` block isn't typed with the `output` of the method, so the errors returned
+/// early by the body must be converted with `into`, instead of being coerced to it
+pub fn operation_scope(
+    macro_data: &MacroTokens<'_>,
+    operation: &str,
+    output: &TokenStream,
+    body: TokenStream,
+) -> TokenStream {
+    let entity = macro_data.ty.to_string();
+    quote! {
+        let operation = async move { #body };
+        canyon_sql::crud::metrics::with_operation::<_, #output>(#entity, #operation, operation)
+            .await
+    }
+}
+
+/// Wraps the received tokens (if any) as an `Option` value
+fn optional_tokens(tokens: Option<TokenStream>) -> TokenStream {
+    match tokens {
+        Some(tokens) => quote! { Some(#tokens) },
+        None => quote! { None },
+    }
+}
+
 /// Parses a syn::Identifier to get a snake case database name from the type identifier
 pub fn _database_table_name_from_struct(ty: &Ident) -> String {
     let struct_name: String = ty.to_string();
@@ -169,7 +372,8 @@ pub fn database_table_name_to_struct_ident(name: &str) -> Ident {
                 }
                 char if char.is_ascii_lowercase() => {
                     if previous_was_underscore {
-                        struct_name.push(char.to_ascii_lowercase())
+                        struct_name.push(char.to_ascii_uppercase());
+                        previous_was_underscore = false;
                     } else {
                         struct_name.push(char)
                     }
@@ -181,3 +385,17 @@ pub fn database_table_name_to_struct_ident(name: &str) -> Ident {
 
     Ident::new(&struct_name, proc_macro2::Span::call_site())
 }
+
+/// Parses a snake case database table name to get the identifier of its entity
+#[test]
+fn test_struct_ident_from_database_table_name() {
+    assert_eq!(database_table_name_to_struct_ident("league"), "League");
+    assert_eq!(
+        database_table_name_to_struct_ident("league_season"),
+        "LeagueSeason"
+    );
+    assert_eq!(
+        database_table_name_to_struct_ident("major_league_tournament"),
+        "MajorLeagueTournament"
+    );
+}
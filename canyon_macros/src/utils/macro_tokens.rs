@@ -1,11 +1,18 @@
 use std::convert::TryFrom;
 
-use canyon_entities::field_annotation::EntityFieldAnnotation;
+use canyon_entities::field_annotation::{EntityFieldAnnotation, FieldValidation, KeyGenerator};
 use proc_macro2::Ident;
-use syn::{Attribute, DeriveInput, Fields, Generics, Type, Visibility};
+use syn::{
+    punctuated::Punctuated, Attribute, DeriveInput, Fields, Generics, Meta, MetaNameValue,
+    NestedMeta, Token, Type, Visibility,
+};
 
 /// Provides a convenient way of store the data for the TokenStream
 /// received on a macro
+/// The table referenced by a foreign key, along with its fields and the column
+/// referenced by each one
+pub type ForeignKey<'a> = (String, Vec<(&'a Ident, String)>);
+
 pub struct MacroTokens<'a> {
     pub vis: &'a Visibility,
     pub ty: &'a Ident,
@@ -143,6 +150,104 @@ impl<'a> MacroTokens<'a> {
         f.map(|v| v.ident.clone().unwrap().to_string())
     }
 
+    /// Utility for find every field annotated with the `#[primary_key]` attribute, in the
+    /// order of declaration, along with its index on the struct and its type
+    pub fn get_primary_key_fields(&self) -> Vec<(usize, Ident, Type)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(_idx, field)| {
+                field
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path.segments[0].ident == "primary_key")
+            })
+            .map(|(idx, field)| (idx, field.ident.clone().unwrap(), field.ty.clone()))
+            .collect()
+    }
+
+    /// Boolean that returns true if the type has more than one field annotated with
+    /// `#[primary_key]`, so the row of every instance is identified by all of them.
+    ///
+    /// The values of a composite primary key are always given by the user, so they
+    /// are inserted as any other column
+    pub fn has_composite_primary_key(&self) -> bool {
+        self.get_primary_key_fields().len() > 1
+    }
+
+    /// Utility for find the primary key declared with a `generate` argument (if exists),
+    /// like `#[primary_key(generate = "uuid_v7")]`, along with the strategy that Canyon
+    /// uses to generate its value before inserting the record
+    pub fn get_primary_key_generator(&self) -> Option<(Ident, KeyGenerator)> {
+        self.fields.iter().find_map(|field| {
+            field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.segments[0].ident == "primary_key")
+                .find_map(|attr| match EntityFieldAnnotation::try_from(&attr) {
+                    Ok(EntityFieldAnnotation::PrimaryKey(_, Some(generator))) => {
+                        Some((field.ident.clone().unwrap(), generator))
+                    }
+                    _ => None,
+                })
+        })
+    }
+
+    /// Utility for find the field annotated with the `#[version]` attribute (if exists),
+    /// along with its type, that holds the version used for the optimistic locking
+    pub fn get_version_field(&self) -> Option<(Ident, Type)> {
+        self.get_field_annotated_with("version")
+    }
+
+    /// Utility for find the field annotated with the `#[created_at]` attribute (if exists),
+    /// along with its type
+    pub fn get_created_at_field(&self) -> Option<(Ident, Type)> {
+        self.get_field_annotated_with("created_at")
+    }
+
+    /// Utility for find the field annotated with the `#[updated_at]` attribute (if exists),
+    /// along with its type
+    pub fn get_updated_at_field(&self) -> Option<(Ident, Type)> {
+        self.get_field_annotated_with("updated_at")
+    }
+
+    /// Finds the first field annotated with the attribute which name is the one received,
+    /// giving back its name and its type
+    fn get_field_annotated_with(&self, attribute: &str) -> Option<(Ident, Type)> {
+        self.fields
+            .iter()
+            .find(|field| {
+                field
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path.segments[0].ident == attribute)
+            })
+            .map(|field| (field.ident.clone().unwrap(), field.ty.clone()))
+    }
+
+    /// Utility for find the rules declared with the `#[validate(...)]` attribute over
+    /// the fields of the entity, along with the name and the type of every annotated field
+    pub fn get_field_validations(&self) -> Vec<(Ident, Type, Vec<FieldValidation>)> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let validations: Vec<FieldValidation> = field
+                    .attrs
+                    .iter()
+                    .filter(|attr| attr.path.segments[0].ident == "validate")
+                    .filter_map(|attr| match EntityFieldAnnotation::try_from(&attr) {
+                        Ok(EntityFieldAnnotation::Validate(validations)) => Some(validations),
+                        _ => None,
+                    })
+                    .flatten()
+                    .collect();
+
+                (!validations.is_empty())
+                    .then(|| (field.ident.clone().unwrap(), field.ty.clone(), validations))
+            })
+            .collect()
+    }
+
     /// Utility for find the `foreign_key` attributes (if exists)
     pub fn get_fk_annotations(&self) -> Vec<(&Ident, EntityFieldAnnotation)> {
         let mut foreign_key_annotations = Vec::new();
@@ -163,6 +268,74 @@ impl<'a> MacroTokens<'a> {
         foreign_key_annotations
     }
 
+    /// Utility for find the foreign keys declared with the `foreign_key` attributes
+    /// (if exists), in the order of declaration, along with the table that they references.
+    ///
+    /// Every field is a foreign key on its own, unless it shares the `constraint` name
+    /// with the other fields that references the same table, being the columns of
+    /// a composite foreign key. Every foreign key holds the column referenced by each field
+    pub fn get_fk_annotations_by_table(&self) -> Vec<ForeignKey<'_>> {
+        // Every foreign key is kept with the name of its constraint (if any)
+        let mut foreign_keys: Vec<(Option<String>, ForeignKey<'_>)> = Vec::new();
+
+        for (field_ident, fk_annotation) in self.get_fk_annotations() {
+            if let EntityFieldAnnotation::ForeignKey(table, column, constraint) = fk_annotation {
+                let composite_foreign_key =
+                    foreign_keys.iter_mut().find(|(name, (fk_table, _))| {
+                        constraint.is_some() && *name == constraint && *fk_table == table
+                    });
+                match composite_foreign_key {
+                    Some((_, (_, columns))) => columns.push((field_ident, column)),
+                    None => foreign_keys.push((constraint, (table, vec![(field_ident, column)]))),
+                }
+            }
+        }
+
+        foreign_keys
+            .into_iter()
+            .map(|(_constraint, foreign_key)| foreign_key)
+            .collect()
+    }
+
+    /// Utility for find the column declared with the `soft_delete` argument
+    /// of the `canyon_entity` macro (if exists), that holds the moment when
+    /// a row was logically deleted
+    pub fn get_soft_delete_column(&self) -> Option<String> {
+        self.get_canyon_entity_args().find_map(|arg| match arg {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: syn::Lit::Str(column),
+                ..
+            })) if path.is_ident("soft_delete") => Some(column.value()),
+            _ => None,
+        })
+    }
+
+    /// Boolean that returns true if the type is declared with the `hooks` argument
+    /// of the `canyon_entity` macro, so the CRUD operations must call the
+    /// `CanyonHooks` implemented for it
+    pub fn has_hooks(&self) -> bool {
+        self.get_canyon_entity_args()
+            .any(|arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hooks")))
+    }
+
+    /// Retrieves the arguments passed to the `canyon_entity` macro (if any)
+    fn get_canyon_entity_args(&self) -> impl Iterator<Item = NestedMeta> + '_ {
+        self.attrs
+            .iter()
+            .filter(|attr| {
+                attr.path
+                    .segments
+                    .iter()
+                    .any(|seg| seg.ident == "canyon_macros" || seg.ident == "canyon_entity")
+            })
+            .filter_map(|attr| {
+                attr.parse_args_with(Punctuated::<NestedMeta, Token![,]>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+    }
+
     /// Boolean that returns true if the type contains a `#[primary_key]`
     /// annotation. False otherwise.
     pub fn type_has_primary_key(&self) -> bool {
@@ -32,6 +32,13 @@ pub mod postgresql_queries {
             gi.column_name = split_part(split_part(CAST(pg_catalog.pg_get_constraintdef(oid) AS TEXT),')',1),'(',2)
         WHERE
             table_schema = 'public';";
+
+    pub static FETCH_ENUM_TYPES: &str = "SELECT t.typname, e.enumlabel
+        FROM pg_catalog.pg_type AS t
+        JOIN pg_catalog.pg_enum AS e ON e.enumtypid = t.oid
+        JOIN pg_catalog.pg_namespace AS n ON n.oid = t.typnamespace
+        WHERE n.nspname = 'public'
+        ORDER BY t.typname, e.enumsortorder;";
 }
 
 #[cfg(feature = "mssql")]
@@ -122,9 +129,9 @@ pub mod mysql_queries {
 
 /// Constant string values that holds regex patterns
 pub mod regex_patterns {
-    pub const EXTRACT_RUST_OPT_REGEX: &str = r"[Oo][Pp][Tt][Ii][Oo][Nn]<(?P<rust_type>[\w<>]+)>";
+    pub const EXTRACT_RUST_OPT_REGEX: &str = r"[Oo][Pp][Tt][Ii][Oo][Nn]<(?P<rust_type>[\w<>:]+)>";
     pub const EXTRACT_FOREIGN_KEY_INFO: &str =
-        r"\w+\s\w+\s\((?P<current_column>\w+)\)\s\w+\s(?P<ref_table>\w+)\((?P<ref_column>\w+)\)";
+        r"\w+\s\w+\s\((?P<current_column>[\w, ]+)\)\s\w+\s(?P<ref_table>\w+)\((?P<ref_column>[\w, ]+)\)";
 }
 
 /// Constant values that maps the string representation of the Rust
@@ -137,9 +144,9 @@ pub mod rust_type {
     pub const OPT_U8: &str = "Option<u8>";
 
     pub const I16: &str = "i16";
-    pub const OPT_U16: &str = "Option<i16>";
+    pub const OPT_I16: &str = "Option<i16>";
     pub const U16: &str = "u16";
-    pub const OPT_I16: &str = "Option<u16>";
+    pub const OPT_U16: &str = "Option<u16>";
 
     pub const I32: &str = "i32";
     pub const OPT_I32: &str = "Option<i32>";
@@ -170,25 +177,46 @@ pub mod rust_type {
 
     pub const NAIVE_DATE_TIME: &str = "NaiveDateTime";
     pub const OPT_NAIVE_DATE_TIME: &str = "Option<NaiveDateTime>";
+
+    pub const UUID: &str = "Uuid";
+    pub const OPT_UUID: &str = "Option<Uuid>";
+
+    pub const DECIMAL: &str = "Decimal";
+    pub const OPT_DECIMAL: &str = "Option<Decimal>";
+
+    pub const BYTES: &str = "Vec<u8>";
+    pub const OPT_BYTES: &str = "Option<Vec<u8>>";
+
+    pub const JSON_VALUE: &str = "Value";
+    pub const OPT_JSON_VALUE: &str = "Option<Value>";
+    pub const JSON: &str = "Json<"; // Prefix of the Json<T> wrapper, for any T
+
+    pub const ARRAY: &str = "Vec<"; // Prefix of the arrays, for the supported elements
 }
 
 #[cfg(feature = "postgres")]
 pub mod postgresql_type {
-    pub const INT_8: &str = "int8";
     pub const SMALL_INT: &str = "smallint";
     pub const INTEGER: &str = "integer";
     pub const BIGINT: &str = "bigint";
+    pub const REAL: &str = "real";
+    pub const DOUBLE: &str = "double precision";
     pub const TEXT: &str = "text";
     pub const BOOLEAN: &str = "boolean";
     pub const DATE: &str = "date";
     pub const TIME: &str = "time";
     pub const DATETIME: &str = "timestamp without time zone";
+    pub const UTC_NOW: &str = "(now() AT TIME ZONE 'utc')";
+    pub const UUID: &str = "uuid";
+    pub const NUMERIC: &str = "numeric";
+    pub const JSONB: &str = "jsonb";
+    pub const BYTEA: &str = "bytea";
 }
 
 #[cfg(feature = "mssql")]
 pub mod sqlserver_type {
-    pub const TINY_INT: &str = "TINY INT";
-    pub const SMALL_INT: &str = "SMALL INT";
+    pub const TINY_INT: &str = "TINYINT";
+    pub const SMALL_INT: &str = "SMALLINT";
     pub const INT: &str = "INT";
     pub const BIGINT: &str = "BIGINT";
     // TODO More information needed, the number of characters may need to be variable and user-defined
@@ -197,6 +225,10 @@ pub mod sqlserver_type {
     pub const DATE: &str = "DATE";
     pub const TIME: &str = "TIME";
     pub const DATETIME: &str = "DATETIME2";
+    pub const UTC_NOW: &str = "SYSUTCDATETIME()";
+    pub const UNIQUEIDENTIFIER: &str = "UNIQUEIDENTIFIER";
+    pub const DECIMAL: &str = "DECIMAL";
+    pub const VARBINARY: &str = "varbinary(max)";
 }
 
 #[cfg(feature = "mysql")]
@@ -205,11 +237,21 @@ pub mod mysql_type {
     pub const SMALL_INT: &str = "smallint"; // Same as PostgreSQL
     pub const INTEGER: &str = "int"; // Same as PostgreSQL's integer
     pub const BIGINT: &str = "bigint"; // Same as PostgreSQL
+    pub const INT_8_UNSIGNED: &str = "tinyint unsigned"; // PostgreSQL has no unsigned integers
+    pub const SMALL_INT_UNSIGNED: &str = "smallint unsigned";
+    pub const INTEGER_UNSIGNED: &str = "int unsigned";
+    pub const BIGINT_UNSIGNED: &str = "bigint unsigned";
     pub const TEXT: &str = "text"; // Same as PostgreSQL
     pub const BOOLEAN: &str = "tinyint(1)"; // MySQL uses tinyint(1) to represent boolean
     pub const DATE: &str = "date"; // Same as PostgreSQL
     pub const TIME: &str = "time"; // Same as PostgreSQL
     pub const DATETIME: &str = "datetime"; // MySQL's equivalent for PostgreSQL's timestamp without time zone
+    pub const DATETIME_MICROS: &str = "datetime(6)"; // The plain datetime discards the fractional seconds
+    pub const UTC_NOW: &str = "(UTC_TIMESTAMP(6))"; // Expressions as defaults requires MySQL 8.0.13
+    pub const BINARY_16: &str = "binary(16)"; // MySQL doesn't have a native type for the UUIDs
+    pub const DECIMAL: &str = "decimal"; // Same as PostgreSQL's numeric
+    pub const JSON: &str = "json"; // Stored in a binary format, like PostgreSQL's jsonb
+    pub const LONGBLOB: &str = "longblob"; // Up to 4GB, unlike the 64KB of the blob type
 }
 
 pub mod mocked_data {
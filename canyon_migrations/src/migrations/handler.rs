@@ -1,7 +1,11 @@
 use canyon_connection::{datasources::Migrations as MigrationsStatus, DATASOURCES};
 use canyon_crud::rows::CanyonRows;
 use canyon_entities::CANYON_REGISTER_ENTITIES;
+#[cfg(feature = "postgres")]
+use canyon_entities::CANYON_REGISTER_ENUMS;
 use partialdebug::placeholder::PartialDebug;
+#[cfg(feature = "postgres")]
+use std::collections::HashMap;
 
 use crate::{
     canyon_crud::{
@@ -14,6 +18,7 @@ use crate::{
         information_schema::{ColumnMetadata, ColumnMetadataTypeValue, TableMetadata},
         memory::CanyonMemory,
         processor::MigrationsProcessor,
+        transforms::UnsupportedTypeError,
     },
 };
 
@@ -25,8 +30,11 @@ impl Transaction<Self> for Migrations {}
 impl Migrations {
     /// Launches the mechanism to parse the Database schema, the Canyon register
     /// and the database table with the memory of Canyon to perform the
-    /// migrations over the targeted database
-    pub async fn migrate() {
+    /// migrations over the targeted database.
+    ///
+    /// Fails with an [`UnsupportedTypeError`] when some field of an entity has a type
+    /// without a datatype for the columns of the database
+    pub async fn migrate() -> Result<(), UnsupportedTypeError> {
         for datasource in DATASOURCES.iter() {
             if datasource
                 .properties
@@ -34,15 +42,15 @@ impl Migrations {
                 .filter(|status| !status.eq(&MigrationsStatus::Disabled))
                 .is_none()
             {
-                println!(
-                    "Skipped datasource: {:?} for being disabled (or not configured)",
-                    datasource.name
+                tracing::info!(
+                    datasource = datasource.name.as_str(),
+                    "Skipped datasource for being disabled (or not configured)"
                 );
                 continue;
             }
-            println!(
-                "Processing migrations for datasource: {:?}",
-                datasource.name
+            tracing::info!(
+                datasource = datasource.name.as_str(),
+                "Processing migrations for datasource"
             );
 
             let mut migrations_processor = MigrationsProcessor::default();
@@ -50,6 +58,15 @@ impl Migrations {
             let canyon_entities = CANYON_REGISTER_ENTITIES.lock().unwrap().to_vec();
             let canyon_memory = CanyonMemory::remember(datasource, &canyon_entities).await;
 
+            // The enumerated types must exist before the columns that uses them
+            #[cfg(feature = "postgres")]
+            if datasource.get_db_type() == DatabaseType::PostgreSql {
+                let canyon_enums = CANYON_REGISTER_ENUMS.lock().unwrap().to_vec();
+                let database_enum_types = Self::fetch_enum_types(&datasource.name).await;
+                migrations_processor
+                    .create_or_extend_enum_types(&canyon_enums, &database_enum_types);
+            }
+
             // Tracked entities that must be migrated whenever Canyon starts
             let schema_status =
                 Self::fetch_database(&datasource.name, datasource.get_db_type()).await;
@@ -79,8 +96,10 @@ impl Migrations {
                     user_database_tables,
                     datasource,
                 )
-                .await;
+                .await?;
         }
+
+        Ok(())
     }
 
     /// Fetches a concrete schema metadata by target the database
@@ -107,6 +126,31 @@ impl Migrations {
             })
     }
 
+    /// Fetches the labels of the enumerated types of a `PostgreSQL` datasource, by type name
+    #[cfg(feature = "postgres")]
+    async fn fetch_enum_types(datasource_name: &str) -> HashMap<String, Vec<String>> {
+        let rows = Self::query(
+            constants::postgresql_queries::FETCH_ENUM_TYPES,
+            [],
+            datasource_name,
+        )
+        .await
+        .unwrap_or_else(|_| {
+            panic!("Error querying the enumerated types for the datasource: {datasource_name}")
+        });
+
+        let mut enum_types: HashMap<String, Vec<String>> = HashMap::new();
+        if let CanyonRows::Postgres(rows) = rows {
+            for row in rows.iter() {
+                enum_types
+                    .entry(row.get::<_, String>("typname"))
+                    .or_default()
+                    .push(row.get::<_, String>("enumlabel"));
+            }
+        }
+        enum_types
+    }
+
     /// Handler for parse the result of query the information of some database schema,
     /// and extract the content of the returned rows into custom structures with
     /// the data well organized for every entity present on that schema
@@ -9,16 +9,21 @@ use std::ops::Not;
 
 use crate::canyon_crud::{crud::Transaction, DatasourceConfig};
 use crate::constants::regex_patterns;
+use crate::migrations::transforms::UnsupportedTypeError;
 use crate::save_migrations_query_to_execute;
 
 use super::information_schema::{ColumnMetadata, TableMetadata};
 use super::memory::CanyonMemory;
 #[cfg(feature = "postgres")]
-use crate::migrations::transforms::{to_postgres_alter_syntax, to_postgres_syntax};
+use crate::migrations::transforms::{
+    is_array_type, native_enum_type, to_postgres_alter_syntax, to_postgres_syntax,
+};
 #[cfg(feature = "mssql")]
 use crate::migrations::transforms::{to_sqlserver_alter_syntax, to_sqlserver_syntax};
 #[cfg(feature = "mysql")]
 use crate::migrations::transforms::{to_mysql_alter_syntax, to_mysql_syntax};
+#[cfg(feature = "postgres")]
+use canyon_entities::register_types::{CanyonRegisterEnum, CanyonRegisterEnumStorage};
 use canyon_entities::register_types::{CanyonRegisterEntity, CanyonRegisterEntityField};
 
 /// Responsible of generating the queries to sync the database status with the
@@ -26,26 +31,30 @@ use canyon_entities::register_types::{CanyonRegisterEntity, CanyonRegisterEntity
 #[derive(Debug, Default)]
 pub struct MigrationsProcessor {
     operations: Vec<Box<dyn DatabaseOperation>>,
-    set_primary_key_operations: Vec<Box<dyn DatabaseOperation>>,
+    // The columns of the composite keys are gathered on the same operation
+    set_primary_key_operations: Vec<TableOperation>,
     drop_primary_key_operations: Vec<Box<dyn DatabaseOperation>>,
     constraints_operations: Vec<Box<dyn DatabaseOperation>>,
+    foreign_key_operations: Vec<TableOperation>,
 }
 impl Transaction<Self> for MigrationsProcessor {}
 
 impl MigrationsProcessor {
+    /// Generates the statements that migrates the schema of the datasource to the entities,
+    /// failing on the first field whose type has no datatype for the database
     pub async fn process<'a>(
         &'a mut self,
         canyon_memory: CanyonMemory,
         canyon_entities: Vec<CanyonRegisterEntity<'a>>,
         database_tables: Vec<&'a TableMetadata>,
         datasource: &'_ DatasourceConfig,
-    ) {
+    ) -> Result<(), UnsupportedTypeError> {
         // The database type formally represented in Canyon
         let db_type = datasource.get_db_type();
         // For each entity (table) on the register (Rust structs)
         for canyon_register_entity in canyon_entities {
             let entity_name = canyon_register_entity.entity_db_table_name;
-            println!("Processing migrations for entity: {entity_name}");
+            tracing::info!(entity = entity_name, "Processing migrations for entity");
 
             // 1st operation ->
             self.create_or_rename_tables(
@@ -109,20 +118,57 @@ impl MigrationsProcessor {
         }
 
         for operation in &self.operations {
-            operation.generate_sql(datasource).await; // This should be moved again to runtime
+            operation.generate_sql(datasource).await?; // This should be moved again to runtime
         }
         for operation in &self.drop_primary_key_operations {
-            operation.generate_sql(datasource).await; // This should be moved again to runtime
+            operation.generate_sql(datasource).await?; // This should be moved again to runtime
         }
         for operation in &self.set_primary_key_operations {
-            operation.generate_sql(datasource).await; // This should be moved again to runtime
+            operation.generate_sql(datasource).await?; // This should be moved again to runtime
         }
         for operation in &self.constraints_operations {
-            operation.generate_sql(datasource).await; // This should be moved again to runtime
+            operation.generate_sql(datasource).await?; // This should be moved again to runtime
+        }
+        for operation in &self.foreign_key_operations {
+            operation.generate_sql(datasource).await?; // This should be moved again to runtime
         }
         // TODO Still pending to decouple de executions of cargo check to skip the process if this
         // code is not processed by cargo build or cargo run
         // Self::from_query_register(datasource_name).await;
+        Ok(())
+    }
+
+    /// Creates the `PostgreSQL` enumerated types that stores the enums derived with
+    /// `CanyonEnum`, or adds to them the labels that aren't on the database yet. It must
+    /// be called before processing the entities, given that their columns uses the types
+    #[cfg(feature = "postgres")]
+    pub fn create_or_extend_enum_types(
+        &mut self,
+        canyon_enums: &[CanyonRegisterEnum],
+        database_enum_types: &HashMap<String, Vec<String>>,
+    ) {
+        for canyon_enum in canyon_enums {
+            let CanyonRegisterEnumStorage::Native(type_name) = &canyon_enum.storage else {
+                continue;
+            };
+
+            match database_enum_types.get(type_name) {
+                None => self.operations.push(Box::new(TypeOperation::CreateEnumType(
+                    type_name.to_string(),
+                    canyon_enum.labels.clone(),
+                ))),
+                Some(database_labels) => {
+                    for label in canyon_enum.labels.iter() {
+                        if !database_labels.contains(label) {
+                            self.operations.push(Box::new(TypeOperation::AddEnumValue(
+                                type_name.to_string(),
+                                label.to_string(),
+                            )))
+                        }
+                    }
+                }
+            }
+        }
     }
 
     /// The operation that checks if an entity must be update is name in the database
@@ -308,9 +354,10 @@ impl MigrationsProcessor {
                 let table_to_reference = annotation_data.0;
                 let column_to_reference = annotation_data.1;
 
-                let foreign_key_name = format!(
-                    "{entity_name}_{}_fkey",
-                    &canyon_register_entity_field.field_name
+                let foreign_key_name = MigrationsHelper::foreign_key_name(
+                    entity_name,
+                    &canyon_register_entity_field.field_name,
+                    annotation_data.2.as_deref(),
                 );
 
                 Self::add_foreign_key(
@@ -335,6 +382,8 @@ impl MigrationsProcessor {
         }
     }
 
+    /// Adds the foreign key of the field, or adds the field to the foreign key with the
+    /// same constraint name, as the columns of a composite foreign key
     fn add_foreign_key(
         &mut self,
         entity_name: &'_ str,
@@ -343,26 +392,69 @@ impl MigrationsProcessor {
         column_to_reference: String,
         canyon_register_entity_field: &CanyonRegisterEntityField,
     ) {
-        self.constraints_operations
-            .push(Box::new(TableOperation::AddTableForeignKey(
-                entity_name.to_string(),
-                foreign_key_name,
-                canyon_register_entity_field.field_name.clone(),
-                table_to_reference,
-                column_to_reference,
-            )));
+        let field_name = canyon_register_entity_field.field_name.clone();
+        let composite_foreign_key =
+            self.foreign_key_operations
+                .iter_mut()
+                .find_map(|op| match op {
+                    TableOperation::AddTableForeignKey(
+                        table,
+                        name,
+                        columns,
+                        ref_table,
+                        ref_columns,
+                    ) if table == entity_name
+                        && *name == foreign_key_name
+                        && *ref_table == table_to_reference =>
+                    {
+                        Some((columns, ref_columns))
+                    }
+                    _ => None,
+                });
+
+        match composite_foreign_key {
+            Some((columns, ref_columns)) => {
+                columns.push(field_name);
+                ref_columns.push(column_to_reference);
+            }
+            None => self
+                .foreign_key_operations
+                .push(TableOperation::AddTableForeignKey(
+                    entity_name.to_string(),
+                    foreign_key_name,
+                    vec![field_name],
+                    table_to_reference,
+                    vec![column_to_reference],
+                )),
+        }
     }
 
+    /// Adds the primary key of the field, or adds the field to the primary key already
+    /// added for the table, as the columns of a composite primary key
     fn add_primary_key(
         &mut self,
         entity_name: &str,
         canyon_register_entity_field: CanyonRegisterEntityField,
     ) {
-        self.set_primary_key_operations
-            .push(Box::new(TableOperation::AddTablePrimaryKey(
-                entity_name.to_string(),
-                canyon_register_entity_field,
-            )));
+        let composite_primary_key =
+            self.set_primary_key_operations
+                .iter_mut()
+                .find_map(|op| match op {
+                    TableOperation::AddTablePrimaryKey(table, fields) if table == entity_name => {
+                        Some(fields)
+                    }
+                    _ => None,
+                });
+
+        match composite_primary_key {
+            Some(fields) => fields.push(canyon_register_entity_field),
+            None => self
+                .set_primary_key_operations
+                .push(TableOperation::AddTablePrimaryKey(
+                    entity_name.to_string(),
+                    vec![canyon_register_entity_field],
+                )),
+        }
     }
 
     #[cfg(feature = "postgres")]
@@ -449,9 +541,10 @@ impl MigrationsProcessor {
                     &canyon_register_entity_field.annotations,
                 );
 
-                let foreign_key_name = format!(
-                    "{entity_name}_{}_fkey",
-                    &canyon_register_entity_field.field_name
+                let foreign_key_name = MigrationsHelper::foreign_key_name(
+                    entity_name,
+                    &canyon_register_entity_field.field_name,
+                    annotation_data.2.as_deref(),
                 );
 
                 Self::add_foreign_key(
@@ -471,9 +564,10 @@ impl MigrationsProcessor {
                 &canyon_register_entity_field.annotations,
             );
 
-            let foreign_key_name = format!(
-                "{entity_name}_{}_fkey",
-                &canyon_register_entity_field.field_name
+            let foreign_key_name = MigrationsHelper::foreign_key_name(
+                entity_name,
+                &canyon_register_entity_field.field_name,
+                annotation_data.2.as_deref(),
             );
 
             // Example of information in foreign_key_info: FOREIGN KEY (league) REFERENCES leagues(id)
@@ -488,26 +582,33 @@ impl MigrationsProcessor {
                 )
                 .expect("Regex - foreign key info not found");
 
-            let current_column = captures_references
+            // The composite foreign keys lists all their columns, like FOREIGN KEY (a, b)
+            let current_columns = captures_references
                 .name("current_column")
                 .expect("Regex - Current column not found")
                 .as_str()
-                .to_string();
+                .split(", ")
+                .collect::<Vec<_>>();
             let ref_table = captures_references
                 .name("ref_table")
                 .expect("Regex - Ref tablenot found")
                 .as_str()
                 .to_string();
-            let ref_column = captures_references
+            let ref_columns = captures_references
                 .name("ref_column")
                 .expect("Regex - Ref column not found")
                 .as_str()
-                .to_string();
+                .split(", ")
+                .collect::<Vec<_>>();
+            let ref_column = current_columns
+                .iter()
+                .position(|column| *column == canyon_register_entity_field.field_name)
+                .and_then(|idx| ref_columns.get(idx));
 
             // If entity foreign key is not equal to the one on database, a constrains_operations is added to delete it and add a new one.
-            if canyon_register_entity_field.field_name != current_column
+            if ref_column.is_none()
                 || annotation_data.0 != ref_table
-                || annotation_data.1 != ref_column
+                || Some(&annotation_data.1.as_str()) != ref_column
             {
                 Self::delete_foreign_key(
                     self,
@@ -580,13 +681,16 @@ impl MigrationsProcessor {
                 let res = Self::query(query_to_execute, [], datasource.0).await;
 
                 match res {
-                    Ok(_) => println!(
-                        "\t[OK] - {:?} - Query: {:?}",
-                        datasource.0, &query_to_execute
+                    Ok(_) => tracing::info!(
+                        datasource = datasource.0,
+                        query = query_to_execute,
+                        "Migration applied"
                     ),
-                    Err(e) => println!(
-                        "\t[ERR] - {:?} - Query: {:?}\nCause: {:?}",
-                        datasource.0, &query_to_execute, e
+                    Err(e) => tracing::error!(
+                        datasource = datasource.0,
+                        query = query_to_execute,
+                        cause = ?e,
+                        "Migration failed"
                     ),
                 }
                 // TODO Ask for user input?
@@ -660,6 +764,8 @@ impl MigrationsHelper {
         }
     }
 
+    /// The columns of the unsupported types are never the same, so their alteration
+    /// reports the [`UnsupportedTypeError`]
     fn is_same_datatype(
         db_type: DatabaseType,
         canyon_register_entity_field: &CanyonRegisterEntityField,
@@ -668,38 +774,53 @@ impl MigrationsHelper {
         #[cfg(feature = "postgres")]
         {
             if db_type == DatabaseType::PostgreSql {
-                return to_postgres_alter_syntax(canyon_register_entity_field).to_lowercase()
-                    == current_column_metadata.datatype;
+                // The information schema doesn't give the name of the enumerated types
+                if native_enum_type(canyon_register_entity_field).is_some() {
+                    return current_column_metadata.datatype == "USER-DEFINED";
+                }
+                // Neither the datatype of the elements of the arrays
+                if is_array_type(canyon_register_entity_field) {
+                    return current_column_metadata.datatype == "ARRAY";
+                }
+                return to_postgres_alter_syntax(canyon_register_entity_field).is_ok_and(
+                    |datatype| datatype.to_lowercase() == current_column_metadata.datatype,
+                );
             }
         }
         #[cfg(feature = "mssql")]
         {
             if db_type == DatabaseType::SqlServer {
                 // TODO Search a better way to get the datatype without useless info (like "VARCHAR(MAX)")
-                return to_sqlserver_alter_syntax(canyon_register_entity_field).to_lowercase()
-                    == current_column_metadata.datatype;
+                return to_sqlserver_alter_syntax(canyon_register_entity_field).is_ok_and(
+                    |datatype| datatype.to_lowercase() == current_column_metadata.datatype,
+                );
             }
         }
 
         #[cfg(feature = "mysql")]
         {
             if db_type == DatabaseType::MySql {
-                return to_mysql_alter_syntax(canyon_register_entity_field).to_lowercase()
-                    == current_column_metadata.datatype;
+                return to_mysql_alter_syntax(canyon_register_entity_field).is_ok_and(|datatype| {
+                    datatype.to_lowercase() == current_column_metadata.datatype
+                });
             }
         }
 
         false
     }
 
-    fn extract_foreign_key_annotation(field_annotations: &[String]) -> (String, String) {
+    /// The table and the column referenced by the foreign key annotation of the field,
+    /// along with the name of the constraint shared by the columns of a composite foreign key
+    fn extract_foreign_key_annotation(
+        field_annotations: &[String],
+    ) -> (String, String, Option<String>) {
         let opt_fk_annotation = field_annotations
             .iter()
             .find(|anno| anno.starts_with("Annotation: ForeignKey"));
         if let Some(fk_annotation) = opt_fk_annotation {
             let annotation_data = fk_annotation
                 .split(',')
-                .filter(|x| !x.contains("Annotation: ForeignKey")) // After here, we only have the "table" and the "column" attribute values
+                .filter(|x| !x.contains("Annotation: ForeignKey")) // After here, we only have the "table", the "column" and the optional "constraint" attribute values
                 .map(|x| {
                     x.split(':')
                         .collect::<Vec<&str>>()
@@ -719,11 +840,24 @@ impl MigrationsHelper {
                 .expect("Error extracting column ref from FK annotation")
                 .to_string();
 
-            (table_to_reference, column_to_reference)
+            (
+                table_to_reference,
+                column_to_reference,
+                annotation_data.get(2).cloned(),
+            )
         } else {
             panic!("Detected a Foreign Key attribute when does not exists on the user's code");
         }
     }
+
+    /// The name of the constraint of a foreign key, which is the one declared for the
+    /// composite foreign keys, or a name made of the table and the field otherwise
+    fn foreign_key_name(entity_name: &str, field_name: &str, constraint: Option<&str>) -> String {
+        match constraint {
+            Some(constraint) => constraint.to_string(),
+            None => format!("{entity_name}_{field_name}_fkey"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -754,12 +888,96 @@ mod migrations_helper_tests {
         );
         assert!(!mocked_league_entity_on_database)
     }
+
+    #[test]
+    fn test_composite_keys_are_gathered_on_one_constraint() {
+        let field = |name: &str| CanyonRegisterEntityField {
+            field_name: name.to_string(),
+            field_type: "i32".to_string(),
+            ..Default::default()
+        };
+        let mut processor = MigrationsProcessor::default();
+        for (column, ref_column) in [("season", "season"), ("team_id", "id")] {
+            processor.add_primary_key("team_season", field(column));
+            processor.add_foreign_key(
+                "team_season",
+                "team_season_fkey".to_string(),
+                "team".to_string(),
+                ref_column.to_string(),
+                &field(column),
+            );
+        }
+
+        assert!(matches!(
+            processor.set_primary_key_operations.as_slice(),
+            [TableOperation::AddTablePrimaryKey(table, fields)]
+                if table == "team_season" && fields.len() == 2
+        ));
+        assert!(matches!(
+            processor.foreign_key_operations.as_slice(),
+            [TableOperation::AddTableForeignKey(_, name, columns, _, ref_columns)]
+                if name == "team_season_fkey"
+                    && *columns == ["season", "team_id"]
+                    && *ref_columns == ["season", "id"]
+        ));
+    }
+
+    #[test]
+    fn test_foreign_keys_to_the_same_table_are_kept_apart() {
+        let annotations = |column: &str| {
+            vec![format!(
+                "Annotation: ForeignKey, Table: team, Column: {column}"
+            )]
+        };
+        let mut processor = MigrationsProcessor::default();
+        for field_name in ["home_team_id", "away_team_id"] {
+            let field = CanyonRegisterEntityField {
+                field_name: field_name.to_string(),
+                field_type: "i32".to_string(),
+                annotations: annotations("id"),
+                ..Default::default()
+            };
+            let (table, column, constraint) =
+                MigrationsHelper::extract_foreign_key_annotation(&field.annotations);
+            processor.add_foreign_key(
+                "game",
+                MigrationsHelper::foreign_key_name("game", field_name, constraint.as_deref()),
+                table,
+                column,
+                &field,
+            );
+        }
+
+        assert!(matches!(
+            processor.foreign_key_operations.as_slice(),
+            [
+                TableOperation::AddTableForeignKey(_, home, home_columns, _, _),
+                TableOperation::AddTableForeignKey(_, away, away_columns, _, _),
+            ] if home == "game_home_team_id_fkey"
+                && *home_columns == ["home_team_id"]
+                && away == "game_away_team_id_fkey"
+                && *away_columns == ["away_team_id"]
+        ));
+    }
+
+    #[test]
+    fn test_extract_composite_foreign_key_info() {
+        let references_regex = Regex::new(regex_patterns::EXTRACT_FOREIGN_KEY_INFO).unwrap();
+        let captures = references_regex
+            .captures("FOREIGN KEY (season, team_id) REFERENCES team(season, id)")
+            .unwrap();
+
+        assert_eq!(&captures["current_column"], "season, team_id");
+        assert_eq!(&captures["ref_table"], "team");
+        assert_eq!(&captures["ref_column"], "season, id");
+    }
 }
 
 /// Trait that enables implementors to generate the migration queries
 #[async_trait]
 trait DatabaseOperation: Debug {
-    async fn generate_sql(&self, datasource: &DatasourceConfig);
+    async fn generate_sql(&self, datasource: &DatasourceConfig)
+        -> Result<(), UnsupportedTypeError>;
 }
 
 /// Helper to relate the operations that Canyon should do when it's managing a schema
@@ -769,12 +987,12 @@ enum TableOperation {
     CreateTable(String, Vec<CanyonRegisterEntityField>),
     // old table_name, new table_name
     AlterTableName(String, String),
-    // table_name, foreign_key_name, column_foreign_key, table_to_reference, column_to_reference
-    AddTableForeignKey(String, String, String, String, String),
+    // table_name, foreign_key_name, columns_foreign_key, table_to_reference, columns_to_reference
+    AddTableForeignKey(String, String, Vec<String>, String, Vec<String>),
     // table_with_foreign_key, constraint_name
     DeleteTableForeignKey(String, String),
-    // table_name, entity_field, column_name
-    AddTablePrimaryKey(String, CanyonRegisterEntityField),
+    // table_name, entity_fields
+    AddTablePrimaryKey(String, Vec<CanyonRegisterEntityField>),
     // table_name, constraint_name
     DeleteTablePrimaryKey(String, String),
 }
@@ -783,7 +1001,10 @@ impl<T: Debug> Transaction<T> for TableOperation {}
 
 #[async_trait]
 impl DatabaseOperation for TableOperation {
-    async fn generate_sql(&self, datasource: &DatasourceConfig) {
+    async fn generate_sql(
+        &self,
+        datasource: &DatasourceConfig,
+    ) -> Result<(), UnsupportedTypeError> {
         let db_type = datasource.get_db_type();
 
         let stmt = match self {
@@ -794,12 +1015,12 @@ impl DatabaseOperation for TableOperation {
                             "CREATE TABLE \"{table_name}\" ({});",
                             table_fields
                                 .iter()
-                                .map(|entity_field| format!(
+                                .map(|entity_field| Ok(format!(
                                     "\"{}\" {}",
                                     entity_field.field_name,
-                                    to_postgres_syntax(entity_field)
-                                ))
-                                .collect::<Vec<String>>()
+                                    to_postgres_syntax(entity_field)?
+                                )))
+                                .collect::<Result<Vec<String>, UnsupportedTypeError>>()?
                                 .join(", ")
                         )
                     }
@@ -809,12 +1030,12 @@ impl DatabaseOperation for TableOperation {
                             table_name,
                             table_fields
                                 .iter()
-                                .map(|entity_field| format!(
+                                .map(|entity_field| Ok(format!(
                                     "{} {}",
                                     entity_field.field_name,
-                                    to_sqlserver_syntax(entity_field)
-                                ))
-                                .collect::<Vec<String>>()
+                                    to_sqlserver_syntax(entity_field)?
+                                )))
+                                .collect::<Result<Vec<String>, UnsupportedTypeError>>()?
                                 .join(", ")
                         )
                             .replace('"', "")
@@ -824,12 +1045,12 @@ impl DatabaseOperation for TableOperation {
                             "CREATE TABLE `{table_name}` ({});",
                             table_fields
                                 .iter()
-                                .map(|entity_field| format!(
+                                .map(|entity_field| Ok(format!(
                                     "`{}` {}",
                                     entity_field.field_name,
-                                    to_mysql_syntax(entity_field)
-                                ))
-                                .collect::<Vec<String>>()
+                                    to_mysql_syntax(entity_field)?
+                                )))
+                                .collect::<Result<Vec<String>, UnsupportedTypeError>>()?
                                 .join(", ")
                         )
                     }
@@ -866,22 +1087,26 @@ impl DatabaseOperation for TableOperation {
             TableOperation::AddTableForeignKey(
                 _table_name,
                 _foreign_key_name,
-                _column_foreign_key,
+                _columns_foreign_key,
                 _table_to_reference,
-                _column_to_reference,
+                _columns_to_reference,
             ) => {
                 match db_type {
                     #[cfg(feature = "postgres")] DatabaseType::PostgreSql =>
                         format!(
                             "ALTER TABLE {_table_name} ADD CONSTRAINT {_foreign_key_name} \
-                            FOREIGN KEY ({_column_foreign_key}) REFERENCES {_table_to_reference} ({_column_to_reference});"
+                            FOREIGN KEY ({}) REFERENCES {_table_to_reference} ({});",
+                            _columns_foreign_key.join(", "),
+                            _columns_to_reference.join(", ")
                         ),
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
                         todo!("[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"),
                     #[cfg(feature = "mysql")] DatabaseType::MySQL =>
                         format!(
                             "ALTER TABLE `{_table_name}` ADD CONSTRAINT `{_foreign_key_name}` \
-                                FOREIGN KEY (`{_column_foreign_key}`) REFERENCES `{_table_to_reference}` (`{_column_to_reference}`);"
+                                FOREIGN KEY (`{}`) REFERENCES `{_table_to_reference}` (`{}`);",
+                            _columns_foreign_key.join("`, `"),
+                            _columns_to_reference.join("`, `")
                         ),
                 }
             }
@@ -902,18 +1127,24 @@ impl DatabaseOperation for TableOperation {
                 }
             }
 
-            TableOperation::AddTablePrimaryKey(_table_name, _entity_field) => {
+            TableOperation::AddTablePrimaryKey(_table_name, _entity_fields) => {
+                let _columns = _entity_fields
+                    .iter()
+                    .map(|entity_field| entity_field.field_name.as_str())
+                    .collect::<Vec<_>>();
+
                 match db_type {
                     #[cfg(feature = "postgres")] DatabaseType::PostgreSql =>
                         format!(
                             "ALTER TABLE \"{_table_name}\" ADD PRIMARY KEY (\"{}\");",
-                            _entity_field.field_name
+                            _columns.join("\", \"")
                         ),
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
                         todo!("[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"),
                     #[cfg(feature = "mysql")] DatabaseType::MySQL =>
                         format!(
-                            "ALTER TABLE `{_table_name}` ADD PRIMARY KEY (`{_entity_field.field_name}`);",
+                            "ALTER TABLE `{_table_name}` ADD PRIMARY KEY (`{}`);",
+                            _columns.join("`, `")
                         ),
 
                 }
@@ -933,6 +1164,7 @@ impl DatabaseOperation for TableOperation {
         };
 
         save_migrations_query_to_execute(stmt, &datasource.name);
+        Ok(())
     }
 }
 
@@ -960,7 +1192,10 @@ impl Transaction<Self> for ColumnOperation {}
 
 #[async_trait]
 impl DatabaseOperation for ColumnOperation {
-    async fn generate_sql(&self, datasource: &DatasourceConfig) {
+    async fn generate_sql(
+        &self,
+        datasource: &DatasourceConfig,
+    ) -> Result<(), UnsupportedTypeError> {
         let db_type = datasource.get_db_type();
 
         let stmt = match self {
@@ -971,21 +1206,21 @@ impl DatabaseOperation for ColumnOperation {
                             "ALTER TABLE \"{}\" ADD COLUMN \"{}\" {};",
                             table_name,
                             entity_field.field_name,
-                            to_postgres_syntax(entity_field)
+                            to_postgres_syntax(entity_field)?
                         ),
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
                         format!(
                             "ALTER TABLE {} ADD \"{}\" {};",
                             table_name,
                             entity_field.field_name,
-                            to_sqlserver_syntax(entity_field)
+                            to_sqlserver_syntax(entity_field)?
                         ),
                     #[cfg(feature = "mysql")] DatabaseType::MySQL =>
                         format!(
                             "ALTER TABLE `{}` ADD COLUMN `{}` {};",
                             table_name,
                             entity_field.field_name,
-                            to_mysql_syntax(entity_field)
+                            to_mysql_syntax(entity_field)?
                         ),
 
                 }
@@ -1004,11 +1239,19 @@ impl DatabaseOperation for ColumnOperation {
                 },
             ColumnOperation::AlterColumnType(_table_name, _entity_field) =>
                 match db_type {
+                    // The values are casted to the enumerated types through their labels
                     #[cfg(feature = "postgres")] DatabaseType::PostgreSql =>
-                        format!(
-                            "ALTER TABLE \"{_table_name}\" ALTER COLUMN \"{}\" TYPE {};",
-                            _entity_field.field_name, to_postgres_alter_syntax(_entity_field)
-                        ),
+                        match native_enum_type(_entity_field) {
+                            Some(type_name) => format!(
+                                "ALTER TABLE \"{_table_name}\" ALTER COLUMN \"{0}\" TYPE {type_name} \
+                                USING CAST(CAST(\"{0}\" AS text) AS {type_name});",
+                                _entity_field.field_name
+                            ),
+                            None => format!(
+                                "ALTER TABLE \"{_table_name}\" ALTER COLUMN \"{}\" TYPE {};",
+                                _entity_field.field_name, to_postgres_alter_syntax(_entity_field)?
+                            ),
+                        },
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
                         todo!("[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"),
                     #[cfg(feature = "mysql")] DatabaseType::MySQL =>
@@ -1016,7 +1259,7 @@ impl DatabaseOperation for ColumnOperation {
                             "ALTER TABLE `{}` MODIFY COLUMN `{}` {};",
                             _table_name,
                             _entity_field.field_name,
-                            to_mysql_alter_syntax(_entity_field)
+                            to_mysql_alter_syntax(_entity_field)?
                         ),
 
 
@@ -1028,7 +1271,7 @@ impl DatabaseOperation for ColumnOperation {
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
                         format!(
                             "ALTER TABLE \"{table_name}\" ALTER COLUMN {} {} NULL",
-                            entity_field.field_name, to_sqlserver_alter_syntax(entity_field)
+                            entity_field.field_name, to_sqlserver_alter_syntax(entity_field)?
                         ),
                     #[cfg(feature = "mysql")] DatabaseType::MySQL =>
                         format!("ALTER TABLE `{}` MODIFY COLUMN `{}` DROP NOT NULL;", table_name, entity_field.field_name),
@@ -1057,7 +1300,7 @@ impl DatabaseOperation for ColumnOperation {
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer => format!(
                         "ALTER TABLE \"{table_name}\" ALTER COLUMN {} {} NOT NULL",
                         entity_field.field_name,
-                        to_sqlserver_alter_syntax(entity_field)
+                        to_sqlserver_alter_syntax(entity_field)?
                     ),
                     #[cfg(feature = "mysql")] DatabaseType::MySQL =>
                         format!("ALTER TABLE `{}` MODIFY COLUMN `{}` SET NOT NULL;", table_name, entity_field.field_name),
@@ -1075,6 +1318,45 @@ impl DatabaseOperation for ColumnOperation {
         };
 
         save_migrations_query_to_execute(stmt, &datasource.name);
+        Ok(())
+    }
+}
+
+/// Helper for operations involving the `PostgreSQL` enumerated types
+#[cfg(feature = "postgres")]
+#[derive(Debug)]
+enum TypeOperation {
+    // type_name, labels
+    CreateEnumType(String, Vec<String>),
+    // type_name, label
+    AddEnumValue(String, String),
+}
+#[cfg(feature = "postgres")]
+impl Transaction<Self> for TypeOperation {}
+
+#[cfg(feature = "postgres")]
+#[async_trait]
+impl DatabaseOperation for TypeOperation {
+    async fn generate_sql(
+        &self,
+        datasource: &DatasourceConfig,
+    ) -> Result<(), UnsupportedTypeError> {
+        let quote = |label: &str| format!("'{}'", label.replace('\'', "''"));
+        let stmt = match self {
+            TypeOperation::CreateEnumType(type_name, labels) => format!(
+                "CREATE TYPE {type_name} AS ENUM ({});",
+                labels
+                    .iter()
+                    .map(|label| quote(label))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            TypeOperation::AddEnumValue(type_name, label) => {
+                format!("ALTER TYPE {type_name} ADD VALUE {};", quote(label))
+            }
+        };
+        save_migrations_query_to_execute(stmt, &datasource.name);
+        Ok(())
     }
 }
 
@@ -1090,7 +1372,10 @@ impl Transaction<Self> for SequenceOperation {}
 #[cfg(feature = "postgres")]
 #[async_trait]
 impl DatabaseOperation for SequenceOperation {
-    async fn generate_sql(&self, datasource: &DatasourceConfig) {
+    async fn generate_sql(
+        &self,
+        datasource: &DatasourceConfig,
+    ) -> Result<(), UnsupportedTypeError> {
         let stmt = match self {
             SequenceOperation::ModifySequence(table_name, entity_field) => {
                 format!(
@@ -1100,5 +1385,6 @@ impl DatabaseOperation for SequenceOperation {
             }
         };
         save_migrations_query_to_execute(stmt, &datasource.name);
+        Ok(())
     }
 }
@@ -6,34 +6,251 @@ use crate::constants::sqlserver_type;
 use crate::constants::mysql_type;
 use crate::constants::{regex_patterns, rust_type};
 
-use canyon_entities::register_types::CanyonRegisterEntityField;
+use canyon_entities::register_types::{
+    CanyonRegisterEntityField, CanyonRegisterEnumStorage, CanyonRegisterType,
+};
+use canyon_entities::{CANYON_REGISTER_ENUMS, CANYON_REGISTER_TYPES};
 use regex::Regex;
+use std::fmt;
+
+/// The type of a field has no datatype for the columns of the databases, so the
+/// migrations can't create nor alter its column
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsupportedTypeError {
+    pub field_name: String,
+    pub field_type: String,
+}
+
+impl UnsupportedTypeError {
+    fn new(field: &CanyonRegisterEntityField) -> Self {
+        Self {
+            field_name: field.field_name.clone(),
+            field_type: field.field_type.clone(),
+        }
+    }
+}
+
+impl fmt::Display for UnsupportedTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The type `{}` of the field `{}` isn't supported by the migrations. Implement \
+            `CanyonType` for it, registering the implementation with `#[canyon_type]`",
+            self.field_type, self.field_name
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedTypeError {}
+
+/// The precision and the scale of the exact numeric columns without a `#[column(...)]`
+/// annotation, for the databases that doesn't support unconstrained ones. The precision
+/// is the number of significant digits that a `Decimal` can hold
+#[cfg(any(feature = "mssql", feature = "mysql"))]
+const DEFAULT_DECIMAL_PRECISION: (u32, u32) = (28, 10);
+
+/// Writes the exact numeric datatype with the precision and the scale declared with
+/// `#[column(precision = p, scale = s)]` over the field, or with the default ones (if any)
+fn decimal_syntax(
+    datatype: &str,
+    field: &CanyonRegisterEntityField,
+    default: Option<(u32, u32)>,
+) -> String {
+    let declared = field
+        .precision_and_scale()
+        .or(default.map(|(precision, scale)| (precision, Some(scale))));
+    match declared {
+        Some((precision, Some(scale))) => format!("{datatype}({precision},{scale})"),
+        Some((precision, None)) => format!("{datatype}({precision})"),
+        None => datatype.to_string(),
+    }
+}
+
+/// Checks if the Rust type, without whitespaces, holds a JSON document, which are
+/// the `serde_json::Value` and the `Json<T>` wrapper, optional or not
+fn is_json(rust_type: &str) -> bool {
+    let rust_type = rust_type
+        .strip_prefix("Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(rust_type);
+    let rust_type = rust_type.rsplit("::").next().unwrap_or(rust_type);
+
+    rust_type == rust_type::JSON_VALUE || rust_type.starts_with(rust_type::JSON)
+}
+
+/// Return the element of a Rust type, without whitespaces, that is stored as an array,
+/// optional or not. The `Vec<u8>` are binary columns instead
+fn array_element(rust_type: &str) -> Option<&str> {
+    let rust_type = rust_type
+        .strip_prefix("Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(rust_type);
+    let element = rust_type
+        .strip_prefix(rust_type::ARRAY)
+        .and_then(|inner| inner.strip_suffix('>'))?;
+
+    [
+        rust_type::I16,
+        rust_type::I32,
+        rust_type::I64,
+        rust_type::F32,
+        rust_type::F64,
+        rust_type::STRING,
+        rust_type::BOOL,
+        rust_type::NAIVE_DATE,
+        rust_type::NAIVE_TIME,
+        rust_type::NAIVE_DATE_TIME,
+        rust_type::UUID,
+        rust_type::DECIMAL,
+    ]
+    .contains(&element)
+    .then_some(element)
+}
+
+/// Checks if the Rust type, without whitespaces, is stored as an array. Only `PostgreSQL`
+/// has them, so the rest of the databases stores them as JSON documents
+fn is_array(rust_type: &str) -> bool {
+    array_element(rust_type).is_some()
+}
+
+/// Return the `PostgreSQL` array datatype of a Rust type that is stored as an array
+#[cfg(feature = "postgres")]
+fn postgres_array_type(rust_type: &str) -> String {
+    let element = match array_element(rust_type) {
+        Some(rust_type::I16) => postgresql_type::SMALL_INT,
+        Some(rust_type::I32) => postgresql_type::INTEGER,
+        Some(rust_type::I64) => postgresql_type::BIGINT,
+        Some(rust_type::F32) => postgresql_type::REAL,
+        Some(rust_type::F64) => postgresql_type::DOUBLE,
+        Some(rust_type::STRING) => postgresql_type::TEXT,
+        Some(rust_type::BOOL) => postgresql_type::BOOLEAN,
+        Some(rust_type::NAIVE_DATE) => postgresql_type::DATE,
+        Some(rust_type::NAIVE_TIME) => postgresql_type::TIME,
+        Some(rust_type::NAIVE_DATE_TIME) => postgresql_type::DATETIME,
+        Some(rust_type::UUID) => postgresql_type::UUID,
+        Some(rust_type::DECIMAL) => postgresql_type::NUMERIC,
+        _ => unreachable!("`{rust_type}` isn't stored as an array"),
+    };
+
+    format!("{element}[]")
+}
+
+/// Return how an enum derived with `CanyonEnum` is stored, if the Rust type, without
+/// whitespaces, is one of them, optional or not
+fn enum_storage(rust_type: &str) -> Option<CanyonRegisterEnumStorage> {
+    let rust_type = rust_type
+        .strip_prefix("Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(rust_type);
+    let rust_type = rust_type.rsplit("::").next().unwrap_or(rust_type);
+
+    CANYON_REGISTER_ENUMS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|canyon_enum| canyon_enum.enum_name == rust_type)
+        .map(|canyon_enum| canyon_enum.storage.clone())
+}
+
+/// Return the datatype of a column that stores an enum derived with `CanyonEnum`, given
+/// the datatypes for its labels and for its discriminants. The enumerated types are only
+/// native on `PostgreSQL`, so the rest of the databases stores their labels
+fn enum_datatype(rust_type: &str, text: &str, integer: &str, native: bool) -> Option<String> {
+    match enum_storage(rust_type)? {
+        CanyonRegisterEnumStorage::Integer => Some(integer.to_string()),
+        CanyonRegisterEnumStorage::Native(type_name) if native => Some(type_name),
+        CanyonRegisterEnumStorage::Text | CanyonRegisterEnumStorage::Native(_) => {
+            Some(text.to_string())
+        }
+    }
+}
+
+/// Return the datatype of a column that stores a custom type registered with `#[canyon_type]`:
+/// the one declared for the database, or the one of its representation, written by
+/// `repr_syntax`. The `NOT NULL` constraint is only added to the declared ones when
+/// `with_nullability` is set, given that `repr_syntax` already adds it.
+///
+/// Returns [`None`] when the type isn't registered
+fn custom_datatype(
+    field: &CanyonRegisterEntityField,
+    declared: impl Fn(&CanyonRegisterType) -> Option<String>,
+    repr_syntax: fn(&CanyonRegisterEntityField) -> Result<String, UnsupportedTypeError>,
+    with_nullability: bool,
+) -> Option<Result<String, UnsupportedTypeError>> {
+    let rust_type = field.field_type.replace(' ', "");
+    let rust_type = rust_type
+        .strip_prefix("Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(&rust_type);
+    let rust_type = rust_type.rsplit("::").next().unwrap_or(rust_type);
+
+    let custom_type = CANYON_REGISTER_TYPES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|custom_type| custom_type.type_name == rust_type)
+        .cloned()?;
+
+    match declared(&custom_type) {
+        Some(datatype) if with_nullability && !field.is_nullable() => {
+            Some(Ok(format!("{datatype} NOT NULL")))
+        }
+        Some(datatype) => Some(Ok(datatype)),
+        None => {
+            let mut repr_field = field.clone();
+            repr_field.field_type = if field.is_nullable() {
+                format!("Option<{}>", custom_type.repr_type)
+            } else {
+                custom_type.repr_type
+            };
+            Some(repr_syntax(&repr_field))
+        }
+    }
+}
+
+/// Return the name of the `PostgreSQL` enumerated type that stores the field, if any
+#[cfg(feature = "postgres")]
+pub fn native_enum_type(field: &CanyonRegisterEntityField) -> Option<String> {
+    match enum_storage(&field.field_type.replace(' ', ""))? {
+        CanyonRegisterEnumStorage::Native(type_name) => Some(type_name),
+        _ => None,
+    }
+}
+
+/// Checks if the field is stored as a `PostgreSQL` array
+#[cfg(feature = "postgres")]
+pub fn is_array_type(field: &CanyonRegisterEntityField) -> bool {
+    is_array(&field.field_type.replace(' ', ""))
+}
 
 /// Return the postgres datatype and parameters to create a column for a given rust type
 #[cfg(feature = "postgres")]
-pub fn to_postgres_syntax(field: &CanyonRegisterEntityField) -> String {
+pub fn to_postgres_syntax(
+    field: &CanyonRegisterEntityField,
+) -> Result<String, UnsupportedTypeError> {
     let rust_type_clean = field.field_type.replace(' ', "");
 
-    match rust_type_clean.as_str() {
-        rust_type::I8 | rust_type::U8 => {
-            String::from(&format!("{} NOT NULL", postgresql_type::INTEGER))
+    Ok(match rust_type_clean.as_str() {
+        rust_type::I8 | rust_type::U8 | rust_type::I16 => {
+            String::from(&format!("{} NOT NULL", postgresql_type::SMALL_INT))
         }
-        rust_type::OPT_I8 | rust_type::OPT_U8 => String::from(postgresql_type::INTEGER),
-
-        rust_type::I16 | rust_type::U16 => {
-            String::from(&format!("{} NOT NULL", postgresql_type::INTEGER))
+        rust_type::OPT_I8 | rust_type::OPT_U8 | rust_type::OPT_I16 => {
+            String::from(postgresql_type::SMALL_INT)
         }
-        rust_type::OPT_I16 | rust_type::OPT_U16 => String::from(postgresql_type::INTEGER),
 
-        rust_type::I32 | rust_type::U32 => {
+        // PostgreSQL has no unsigned integers, so they're widened to the next signed column
+        rust_type::U16 | rust_type::I32 => {
             String::from(&format!("{} NOT NULL", postgresql_type::INTEGER))
         }
-        rust_type::OPT_I32 | rust_type::OPT_U32 => String::from(postgresql_type::INTEGER),
+        rust_type::OPT_U16 | rust_type::OPT_I32 => String::from(postgresql_type::INTEGER),
 
-        rust_type::I64 | rust_type::U64 => {
+        // The `u64` values above `i64::MAX` are rejected when they're bound
+        rust_type::U32 | rust_type::I64 | rust_type::U64 => {
             String::from(&format!("{} NOT NULL", postgresql_type::BIGINT))
         }
-        rust_type::OPT_I64 | rust_type::OPT_U64 => String::from(postgresql_type::BIGINT),
+        rust_type::OPT_U32 | rust_type::OPT_I64 | rust_type::OPT_U64 => {
+            String::from(postgresql_type::BIGINT)
+        }
 
         rust_type::STRING => String::from(&format!("{} NOT NULL", postgresql_type::TEXT)),
         rust_type::OPT_STRING => String::from(postgresql_type::TEXT),
@@ -47,38 +264,82 @@ pub fn to_postgres_syntax(field: &CanyonRegisterEntityField) -> String {
         rust_type::NAIVE_TIME => String::from(&format!("{} NOT NULL", postgresql_type::TIME)),
         rust_type::OPT_NAIVE_TIME => String::from(postgresql_type::TIME),
 
+        // The moments managed by Canyon defaults to the moment when the row is created
+        rust_type::NAIVE_DATE_TIME if field.is_managed_timestamp() => String::from(&format!(
+            "{} NOT NULL DEFAULT {}",
+            postgresql_type::DATETIME,
+            postgresql_type::UTC_NOW
+        )),
+        rust_type::OPT_NAIVE_DATE_TIME if field.is_managed_timestamp() => String::from(&format!(
+            "{} DEFAULT {}",
+            postgresql_type::DATETIME,
+            postgresql_type::UTC_NOW
+        )),
         rust_type::NAIVE_DATE_TIME => {
             String::from(&format!("{} NOT NULL", postgresql_type::DATETIME))
         }
         rust_type::OPT_NAIVE_DATE_TIME => String::from(postgresql_type::DATETIME),
-        &_ => todo!("Not supported datatype for this migrations version"),
-    }
+        rust_type::UUID => String::from(&format!("{} NOT NULL", postgresql_type::UUID)),
+        rust_type::OPT_UUID => String::from(postgresql_type::UUID),
+        rust_type::DECIMAL => format!(
+            "{} NOT NULL",
+            decimal_syntax(postgresql_type::NUMERIC, field, None)
+        ),
+        rust_type::OPT_DECIMAL => decimal_syntax(postgresql_type::NUMERIC, field, None),
+        rust_type::BYTES => format!("{} NOT NULL", postgresql_type::BYTEA),
+        rust_type::OPT_BYTES => String::from(postgresql_type::BYTEA),
+        ty if is_json(ty) && !ty.starts_with("Option<") => {
+            format!("{} NOT NULL", postgresql_type::JSONB)
+        }
+        ty if is_json(ty) => String::from(postgresql_type::JSONB),
+        ty if is_array(ty) && !ty.starts_with("Option<") => {
+            format!("{} NOT NULL", postgres_array_type(ty))
+        }
+        ty if is_array(ty) => postgres_array_type(ty),
+        ty => match enum_datatype(ty, postgresql_type::TEXT, postgresql_type::INTEGER, true) {
+            Some(datatype) if field.is_nullable() => datatype,
+            Some(datatype) => format!("{datatype} NOT NULL"),
+            None => custom_datatype(
+                field,
+                |custom| custom.postgres_type.clone(),
+                to_postgres_syntax,
+                true,
+            )
+            .unwrap_or_else(|| Err(UnsupportedTypeError::new(field)))?,
+        },
+    })
 }
 
 /// Return the postgres datatype and parameters to create a column for a given rust type
 /// for Microsoft SQL Server
 #[cfg(feature = "mssql")]
-pub fn to_sqlserver_syntax(field: &CanyonRegisterEntityField) -> String {
+pub fn to_sqlserver_syntax(
+    field: &CanyonRegisterEntityField,
+) -> Result<String, UnsupportedTypeError> {
     let rust_type_clean = field.field_type.replace(' ', "");
 
-    match rust_type_clean.as_str() {
-        rust_type::I8 | rust_type::U8 => String::from(&format!("{} NOT NULL", sqlserver_type::INT)),
-        rust_type::OPT_I8 | rust_type::OPT_U8 => String::from(sqlserver_type::INT),
+    Ok(match rust_type_clean.as_str() {
+        rust_type::U8 => String::from(&format!("{} NOT NULL", sqlserver_type::TINY_INT)),
+        rust_type::OPT_U8 => String::from(sqlserver_type::TINY_INT),
 
-        rust_type::I16 | rust_type::U16 => {
-            String::from(&format!("{} NOT NULL", sqlserver_type::INT))
+        rust_type::I8 | rust_type::I16 => {
+            String::from(&format!("{} NOT NULL", sqlserver_type::SMALL_INT))
         }
-        rust_type::OPT_I16 | rust_type::OPT_U16 => String::from(sqlserver_type::INT),
+        rust_type::OPT_I8 | rust_type::OPT_I16 => String::from(sqlserver_type::SMALL_INT),
 
-        rust_type::I32 | rust_type::U32 => {
+        // SqlServer has no unsigned integers, so they're widened to the next signed column
+        rust_type::U16 | rust_type::I32 => {
             String::from(&format!("{} NOT NULL", sqlserver_type::INT))
         }
-        rust_type::OPT_I32 | rust_type::OPT_U32 => String::from(sqlserver_type::INT),
+        rust_type::OPT_U16 | rust_type::OPT_I32 => String::from(sqlserver_type::INT),
 
-        rust_type::I64 | rust_type::U64 => {
+        // The `u64` values above `i64::MAX` are rejected by the database
+        rust_type::U32 | rust_type::I64 | rust_type::U64 => {
             String::from(&format!("{} NOT NULL", sqlserver_type::BIGINT))
         }
-        rust_type::OPT_I64 | rust_type::OPT_U64 => String::from(sqlserver_type::BIGINT),
+        rust_type::OPT_U32 | rust_type::OPT_I64 | rust_type::OPT_U64 => {
+            String::from(sqlserver_type::BIGINT)
+        }
 
         rust_type::STRING => {
             String::from(&format!("{} NOT NULL DEFAULT ''", sqlserver_type::NVARCHAR))
@@ -94,41 +355,79 @@ pub fn to_sqlserver_syntax(field: &CanyonRegisterEntityField) -> String {
         rust_type::NAIVE_TIME => String::from(&format!("{} NOT NULL", sqlserver_type::TIME)),
         rust_type::OPT_NAIVE_TIME => String::from(sqlserver_type::TIME),
 
+        // The moments managed by Canyon defaults to the moment when the row is created
+        rust_type::NAIVE_DATE_TIME if field.is_managed_timestamp() => String::from(&format!(
+            "{} NOT NULL DEFAULT {}",
+            sqlserver_type::DATETIME,
+            sqlserver_type::UTC_NOW
+        )),
+        rust_type::OPT_NAIVE_DATE_TIME if field.is_managed_timestamp() => String::from(&format!(
+            "{} DEFAULT {}",
+            sqlserver_type::DATETIME,
+            sqlserver_type::UTC_NOW
+        )),
         rust_type::NAIVE_DATE_TIME => {
             String::from(&format!("{} NOT NULL", sqlserver_type::DATETIME))
         }
         rust_type::OPT_NAIVE_DATE_TIME => String::from(sqlserver_type::DATETIME),
-        &_ => todo!("Not supported datatype for this migrations version"),
-    }
+        rust_type::UUID => String::from(&format!("{} NOT NULL", sqlserver_type::UNIQUEIDENTIFIER)),
+        rust_type::OPT_UUID => String::from(sqlserver_type::UNIQUEIDENTIFIER),
+        rust_type::DECIMAL => format!(
+            "{} NOT NULL",
+            decimal_syntax(sqlserver_type::DECIMAL, field, Some(DEFAULT_DECIMAL_PRECISION))
+        ),
+        rust_type::OPT_DECIMAL => {
+            decimal_syntax(sqlserver_type::DECIMAL, field, Some(DEFAULT_DECIMAL_PRECISION))
+        }
+        rust_type::BYTES => format!("{} NOT NULL", sqlserver_type::VARBINARY),
+        rust_type::OPT_BYTES => String::from(sqlserver_type::VARBINARY),
+        ty if (is_json(ty) || is_array(ty)) && !ty.starts_with("Option<") => {
+            format!("{} NOT NULL", sqlserver_type::NVARCHAR)
+        }
+        ty if is_json(ty) || is_array(ty) => String::from(sqlserver_type::NVARCHAR),
+        ty => match enum_datatype(ty, sqlserver_type::NVARCHAR, sqlserver_type::INT, false) {
+            Some(datatype) if field.is_nullable() => datatype,
+            Some(datatype) => format!("{datatype} NOT NULL"),
+            None => custom_datatype(
+                field,
+                |custom| custom.sqlserver_type.clone(),
+                to_sqlserver_syntax,
+                true,
+            )
+            .unwrap_or_else(|| Err(UnsupportedTypeError::new(field)))?,
+        },
+    })
 }
 
 /// Return the MySQL datatype and parameters to create a column for a given Rust type
 #[cfg(feature = "mysql")]
-pub fn to_mysql_syntax(field: &CanyonRegisterEntityField) -> String {
+pub fn to_mysql_syntax(field: &CanyonRegisterEntityField) -> Result<String, UnsupportedTypeError> {
     let rust_type_clean = field.field_type.replace(' ', "");
 
-    match rust_type_clean.as_str() {
+    Ok(match rust_type_clean.as_str() {
         rust_type::I8 => String::from(&format!("{} NOT NULL", mysql_type::INT_8)),
         rust_type::OPT_I8 => String::from(mysql_type::INT_8),
 
-        rust_type::U8 => String::from(&format!("{} NOT NULL", mysql_type::SMALL_INT)),
-        rust_type::OPT_U8 => String::from(mysql_type::SMALL_INT),
+        rust_type::U8 => String::from(&format!("{} NOT NULL", mysql_type::INT_8_UNSIGNED)),
+        rust_type::OPT_U8 => String::from(mysql_type::INT_8_UNSIGNED),
 
         rust_type::I16 => String::from(&format!("{} NOT NULL", mysql_type::SMALL_INT)),
         rust_type::OPT_I16 => String::from(mysql_type::SMALL_INT),
 
-        rust_type::U16 => String::from(&format!("{} NOT NULL", mysql_type::INT_8)),
-        rust_type::OPT_U16 => String::from(mysql_type::INT_8),
+        rust_type::U16 => String::from(&format!("{} NOT NULL", mysql_type::SMALL_INT_UNSIGNED)),
+        rust_type::OPT_U16 => String::from(mysql_type::SMALL_INT_UNSIGNED),
 
-        rust_type::I32 | rust_type::U32 => {
-            String::from(&format!("{} NOT NULL", mysql_type::INTEGER))
-        }
-        rust_type::OPT_I32 | rust_type::OPT_U32 => String::from(mysql_type::INTEGER),
+        rust_type::I32 => String::from(&format!("{} NOT NULL", mysql_type::INTEGER)),
+        rust_type::OPT_I32 => String::from(mysql_type::INTEGER),
 
-        rust_type::I64 | rust_type::U64 => {
-            String::from(&format!("{} NOT NULL", mysql_type::BIGINT))
-        }
-        rust_type::OPT_I64 | rust_type::OPT_U64 => String::from(mysql_type::BIGINT),
+        rust_type::U32 => String::from(&format!("{} NOT NULL", mysql_type::INTEGER_UNSIGNED)),
+        rust_type::OPT_U32 => String::from(mysql_type::INTEGER_UNSIGNED),
+
+        rust_type::I64 => String::from(&format!("{} NOT NULL", mysql_type::BIGINT)),
+        rust_type::OPT_I64 => String::from(mysql_type::BIGINT),
+
+        rust_type::U64 => String::from(&format!("{} NOT NULL", mysql_type::BIGINT_UNSIGNED)),
+        rust_type::OPT_U64 => String::from(mysql_type::BIGINT_UNSIGNED),
 
         rust_type::F32 | rust_type::F64 => {
             String::from(&format!("{} NOT NULL", mysql_type::FLOAT))
@@ -147,16 +446,55 @@ pub fn to_mysql_syntax(field: &CanyonRegisterEntityField) -> String {
         rust_type::NAIVE_TIME => String::from(&format!("{} NOT NULL", mysql_type::TIME)),
         rust_type::OPT_NAIVE_TIME => String::from(mysql_type::TIME),
 
+        // The moments managed by Canyon defaults to the moment when the row is created, and
+        // keeps the microseconds of the moments written back into the entities
+        rust_type::NAIVE_DATE_TIME if field.is_managed_timestamp() => String::from(&format!(
+            "{} NOT NULL DEFAULT {}",
+            mysql_type::DATETIME_MICROS,
+            mysql_type::UTC_NOW
+        )),
+        rust_type::OPT_NAIVE_DATE_TIME if field.is_managed_timestamp() => String::from(&format!(
+            "{} DEFAULT {}",
+            mysql_type::DATETIME_MICROS,
+            mysql_type::UTC_NOW
+        )),
         rust_type::NAIVE_DATE_TIME => {
             String::from(&format!("{} NOT NULL", mysql_type::DATETIME))
         }
         rust_type::OPT_NAIVE_DATE_TIME => String::from(mysql_type::DATETIME),
-        &_ => todo!("Not supported datatype for this migrations version"),
-    }
+        rust_type::UUID => String::from(&format!("{} NOT NULL", mysql_type::BINARY_16)),
+        rust_type::OPT_UUID => String::from(mysql_type::BINARY_16),
+        rust_type::DECIMAL => format!(
+            "{} NOT NULL",
+            decimal_syntax(mysql_type::DECIMAL, field, Some(DEFAULT_DECIMAL_PRECISION))
+        ),
+        rust_type::OPT_DECIMAL => {
+            decimal_syntax(mysql_type::DECIMAL, field, Some(DEFAULT_DECIMAL_PRECISION))
+        }
+        rust_type::BYTES => format!("{} NOT NULL", mysql_type::LONGBLOB),
+        rust_type::OPT_BYTES => String::from(mysql_type::LONGBLOB),
+        ty if (is_json(ty) || is_array(ty)) && !ty.starts_with("Option<") => {
+            format!("{} NOT NULL", mysql_type::JSON)
+        }
+        ty if is_json(ty) || is_array(ty) => String::from(mysql_type::JSON),
+        ty => match enum_datatype(ty, mysql_type::TEXT, mysql_type::INTEGER, false) {
+            Some(datatype) if field.is_nullable() => datatype,
+            Some(datatype) => format!("{datatype} NOT NULL"),
+            None => custom_datatype(
+                field,
+                |custom| custom.mysql_type.clone(),
+                to_mysql_syntax,
+                true,
+            )
+            .unwrap_or_else(|| Err(UnsupportedTypeError::new(field)))?,
+        },
+    })
 }
 
 #[cfg(feature = "postgres")]
-pub fn to_postgres_alter_syntax(field: &CanyonRegisterEntityField) -> String {
+pub fn to_postgres_alter_syntax(
+    field: &CanyonRegisterEntityField,
+) -> Result<String, UnsupportedTypeError> {
     let mut rust_type_clean = field.field_type.replace(' ', "");
     let rs_type_is_optional = field.field_type.to_uppercase().starts_with("OPTION");
 
@@ -170,19 +508,10 @@ pub fn to_postgres_alter_syntax(field: &CanyonRegisterEntityField) -> String {
             .to_string();
     }
 
-    match rust_type_clean.as_str() {
-        rust_type::I8 | rust_type::U8 | rust_type::OPT_I8 | rust_type::OPT_U8 => {
-            String::from(postgresql_type::INT_8)
-        }
-        rust_type::I16 | rust_type::U16 | rust_type::OPT_I16 | rust_type::OPT_U16 => {
-            String::from(postgresql_type::SMALL_INT)
-        }
-        rust_type::I32 | rust_type::U32 | rust_type::OPT_I32 | rust_type::OPT_U32 => {
-            String::from(postgresql_type::INTEGER)
-        }
-        rust_type::I64 | rust_type::U64 | rust_type::OPT_I64 | rust_type::OPT_U64 => {
-            String::from(postgresql_type::BIGINT)
-        }
+    Ok(match rust_type_clean.as_str() {
+        rust_type::I8 | rust_type::U8 | rust_type::I16 => String::from(postgresql_type::SMALL_INT),
+        rust_type::U16 | rust_type::I32 => String::from(postgresql_type::INTEGER),
+        rust_type::U32 | rust_type::I64 | rust_type::U64 => String::from(postgresql_type::BIGINT),
         rust_type::STRING | rust_type::OPT_STRING => String::from(postgresql_type::TEXT),
         rust_type::BOOL | rust_type::OPT_BOOL => String::from(postgresql_type::BOOLEAN),
         rust_type::NAIVE_DATE | rust_type::OPT_NAIVE_DATE => String::from(postgresql_type::DATE),
@@ -190,12 +519,31 @@ pub fn to_postgres_alter_syntax(field: &CanyonRegisterEntityField) -> String {
         rust_type::NAIVE_DATE_TIME | rust_type::OPT_NAIVE_DATE_TIME => {
             String::from(postgresql_type::DATETIME)
         }
-        &_ => todo!("Not supported datatype for this migrations version"),
-    }
+        rust_type::UUID | rust_type::OPT_UUID => String::from(postgresql_type::UUID),
+        rust_type::DECIMAL | rust_type::OPT_DECIMAL => {
+            decimal_syntax(postgresql_type::NUMERIC, field, None)
+        }
+        rust_type::BYTES | rust_type::OPT_BYTES => String::from(postgresql_type::BYTEA),
+        ty if is_json(ty) => String::from(postgresql_type::JSONB),
+        ty if is_array(ty) => postgres_array_type(ty),
+        ty => enum_datatype(ty, postgresql_type::TEXT, postgresql_type::INTEGER, true)
+            .map(Ok)
+            .or_else(|| {
+                custom_datatype(
+                    field,
+                    |custom| custom.postgres_type.clone(),
+                    to_postgres_alter_syntax,
+                    false,
+                )
+            })
+            .unwrap_or_else(|| Err(UnsupportedTypeError::new(field)))?,
+    })
 }
 
 #[cfg(feature = "mssql")]
-pub fn to_sqlserver_alter_syntax(field: &CanyonRegisterEntityField) -> String {
+pub fn to_sqlserver_alter_syntax(
+    field: &CanyonRegisterEntityField,
+) -> Result<String, UnsupportedTypeError> {
     let mut rust_type_clean = field.field_type.replace(' ', "");
     let rs_type_is_optional = field.field_type.to_uppercase().starts_with("OPTION");
 
@@ -209,19 +557,11 @@ pub fn to_sqlserver_alter_syntax(field: &CanyonRegisterEntityField) -> String {
             .to_string();
     }
 
-    match rust_type_clean.as_str() {
-        rust_type::I8 | rust_type::U8 | rust_type::OPT_I8 | rust_type::OPT_U8 => {
-            String::from(sqlserver_type::TINY_INT)
-        }
-        rust_type::I16 | rust_type::U16 | rust_type::OPT_I16 | rust_type::OPT_U16 => {
-            String::from(sqlserver_type::SMALL_INT)
-        }
-        rust_type::I32 | rust_type::U32 | rust_type::OPT_I32 | rust_type::OPT_U32 => {
-            String::from(sqlserver_type::INT)
-        }
-        rust_type::I64 | rust_type::U64 | rust_type::OPT_I64 | rust_type::OPT_U64 => {
-            String::from(sqlserver_type::BIGINT)
-        }
+    Ok(match rust_type_clean.as_str() {
+        rust_type::U8 => String::from(sqlserver_type::TINY_INT),
+        rust_type::I8 | rust_type::I16 => String::from(sqlserver_type::SMALL_INT),
+        rust_type::U16 | rust_type::I32 => String::from(sqlserver_type::INT),
+        rust_type::U32 | rust_type::I64 | rust_type::U64 => String::from(sqlserver_type::BIGINT),
         rust_type::STRING | rust_type::OPT_STRING => String::from(sqlserver_type::NVARCHAR),
         rust_type::BOOL | rust_type::OPT_BOOL => String::from(sqlserver_type::BIT),
         rust_type::NAIVE_DATE | rust_type::OPT_NAIVE_DATE => String::from(sqlserver_type::DATE),
@@ -229,13 +569,31 @@ pub fn to_sqlserver_alter_syntax(field: &CanyonRegisterEntityField) -> String {
         rust_type::NAIVE_DATE_TIME | rust_type::OPT_NAIVE_DATE_TIME => {
             String::from(sqlserver_type::DATETIME)
         }
-        &_ => todo!("Not supported datatype for this migrations version"),
-    }
+        rust_type::UUID | rust_type::OPT_UUID => String::from(sqlserver_type::UNIQUEIDENTIFIER),
+        rust_type::DECIMAL | rust_type::OPT_DECIMAL => {
+            decimal_syntax(sqlserver_type::DECIMAL, field, Some(DEFAULT_DECIMAL_PRECISION))
+        }
+        rust_type::BYTES | rust_type::OPT_BYTES => String::from(sqlserver_type::VARBINARY),
+        ty if is_json(ty) || is_array(ty) => String::from(sqlserver_type::NVARCHAR),
+        ty => enum_datatype(ty, sqlserver_type::NVARCHAR, sqlserver_type::INT, false)
+            .map(Ok)
+            .or_else(|| {
+                custom_datatype(
+                    field,
+                    |custom| custom.sqlserver_type.clone(),
+                    to_sqlserver_alter_syntax,
+                    false,
+                )
+            })
+            .unwrap_or_else(|| Err(UnsupportedTypeError::new(field)))?,
+    })
 }
 
 /// Return the MySQL datatype for altering a column for a given Rust type
 #[cfg(feature = "mysql")]
-pub fn to_mysql_alter_syntax(field: &CanyonRegisterEntityField) -> String {
+pub fn to_mysql_alter_syntax(
+    field: &CanyonRegisterEntityField,
+) -> Result<String, UnsupportedTypeError> {
     let mut rust_type_clean = field.field_type.replace(' ', "");
     let rs_type_is_optional = field.field_type.to_uppercase().starts_with("OPTION");
 
@@ -249,26 +607,99 @@ pub fn to_mysql_alter_syntax(field: &CanyonRegisterEntityField) -> String {
             .to_string();
     }
 
-    match rust_type_clean.as_str() {
-        rust_type::I8 | rust_type::U8 | rust_type::OPT_I8 | rust_type::OPT_U8 => {
-            String::from(mysql_type::INT_8)
-        }
-        rust_type::I16 | rust_type::U16 | rust_type::OPT_I16 | rust_type::OPT_U16 => {
-            String::from(mysql_type::SMALL_INT)
-        }
-        rust_type::I32 | rust_type::U32 | rust_type::OPT_I32 | rust_type::OPT_U32 => {
-            String::from(mysql_type::INTEGER)
-        }
-        rust_type::I64 | rust_type::U64 | rust_type::OPT_I64 | rust_type::OPT_U64 => {
-            String::from(mysql_type::BIGINT)
-        }
+    Ok(match rust_type_clean.as_str() {
+        rust_type::I8 => String::from(mysql_type::INT_8),
+        rust_type::U8 => String::from(mysql_type::INT_8_UNSIGNED),
+        rust_type::I16 => String::from(mysql_type::SMALL_INT),
+        rust_type::U16 => String::from(mysql_type::SMALL_INT_UNSIGNED),
+        rust_type::I32 => String::from(mysql_type::INTEGER),
+        rust_type::U32 => String::from(mysql_type::INTEGER_UNSIGNED),
+        rust_type::I64 => String::from(mysql_type::BIGINT),
+        rust_type::U64 => String::from(mysql_type::BIGINT_UNSIGNED),
         rust_type::STRING | rust_type::OPT_STRING => String::from(mysql_type::TEXT),
         rust_type::BOOL | rust_type::OPT_BOOL => String::from(mysql_type::BOOLEAN),
         rust_type::NAIVE_DATE | rust_type::OPT_NAIVE_DATE => String::from(mysql_type::DATE),
         rust_type::NAIVE_TIME | rust_type::OPT_NAIVE_TIME => String::from(mysql_type::TIME),
+        rust_type::NAIVE_DATE_TIME | rust_type::OPT_NAIVE_DATE_TIME
+            if field.is_managed_timestamp() =>
+        {
+            String::from(mysql_type::DATETIME_MICROS)
+        }
         rust_type::NAIVE_DATE_TIME | rust_type::OPT_NAIVE_DATE_TIME => {
             String::from(mysql_type::DATETIME)
         }
-        &_ => todo!("Not supported datatype for this migrations version"),
+        rust_type::UUID | rust_type::OPT_UUID => String::from(mysql_type::BINARY_16),
+        rust_type::DECIMAL | rust_type::OPT_DECIMAL => {
+            decimal_syntax(mysql_type::DECIMAL, field, Some(DEFAULT_DECIMAL_PRECISION))
+        }
+        rust_type::BYTES | rust_type::OPT_BYTES => String::from(mysql_type::LONGBLOB),
+        ty if is_json(ty) || is_array(ty) => String::from(mysql_type::JSON),
+        ty => enum_datatype(ty, mysql_type::TEXT, mysql_type::INTEGER, false)
+            .map(Ok)
+            .or_else(|| {
+                custom_datatype(
+                    field,
+                    |custom| custom.mysql_type.clone(),
+                    to_mysql_alter_syntax,
+                    false,
+                )
+            })
+            .unwrap_or_else(|| Err(UnsupportedTypeError::new(field)))?,
+    })
+}
+
+#[cfg(test)]
+mod transforms_tests {
+    use super::*;
+
+    /// The fields of the types without a datatype are reported as an error, that
+    /// names their type and the way to register it
+    #[test]
+    fn unsupported_types_are_reported() {
+        let field = CanyonRegisterEntityField {
+            field_name: String::from("amount"),
+            field_type: String::from("Money"),
+            annotations: vec![],
+        };
+        let error = UnsupportedTypeError {
+            field_name: String::from("amount"),
+            field_type: String::from("Money"),
+        };
+
+        #[cfg(feature = "postgres")]
+        {
+            assert_eq!(to_postgres_syntax(&field), Err(error.clone()));
+            assert_eq!(to_postgres_alter_syntax(&field), Err(error.clone()));
+        }
+        #[cfg(feature = "mssql")]
+        {
+            assert_eq!(to_sqlserver_syntax(&field), Err(error.clone()));
+            assert_eq!(to_sqlserver_alter_syntax(&field), Err(error.clone()));
+        }
+        #[cfg(feature = "mysql")]
+        {
+            assert_eq!(to_mysql_syntax(&field), Err(error.clone()));
+            assert_eq!(to_mysql_alter_syntax(&field), Err(error.clone()));
+        }
+        assert!(error.to_string().contains("`Money`"));
+        assert!(error.to_string().contains("#[canyon_type]"));
     }
-}
\ No newline at end of file
+
+    /// The columns of the moments managed by Canyon keeps their microseconds on MySQL,
+    /// whose plain `datetime` discards the fractional seconds
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn mysql_managed_timestamps_keeps_the_microseconds() {
+        let field = CanyonRegisterEntityField {
+            field_name: String::from("updated_at"),
+            field_type: String::from("NaiveDateTime"),
+            annotations: vec![String::from("Annotation: UpdatedAt")],
+        };
+
+        assert_eq!(
+            to_mysql_syntax(&field).unwrap(),
+            "datetime(6) NOT NULL DEFAULT (UTC_TIMESTAMP(6))"
+        );
+        assert_eq!(to_mysql_alter_syntax(&field).unwrap(), "datetime(6)");
+    }
+}
@@ -42,3 +42,123 @@ CREATE TABLE public.team (
 	home_league			INT,
 	FOREIGN KEY (home_league) REFERENCES league(id)
 );
+
+CREATE TABLE public.broadcast (
+    id					BINARY(16) PRIMARY KEY,
+	league				INT,
+	channel				TEXT NOT NULL,
+	stream_key			BINARY(16),
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.sponsor (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	league				INT,
+	name				TEXT NOT NULL,
+	amount				DECIMAL(19,4) NOT NULL,
+	bonus				DECIMAL(19,4),
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.league_settings (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	league				INT,
+	preferences			JSON NOT NULL,
+	extra				JSON,
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.attachment (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	league				INT,
+	file_name			TEXT NOT NULL,
+	content				LONGBLOB NOT NULL,
+	thumbnail			LONGBLOB,
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.ticket (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	tournament			INT,
+	tier				TEXT NOT NULL,
+	status				INT NOT NULL,
+	previous_status		INT,
+	holder_email		VARCHAR(320) NOT NULL,
+	price				DECIMAL(19,4),
+	FOREIGN KEY (tournament) REFERENCES tournament(id)
+);
+
+CREATE TABLE public.roster (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	team				TEXT NOT NULL,
+	players				JSON NOT NULL,
+	jersey_numbers		JSON NOT NULL,
+	substitutes			JSON
+);
+
+CREATE TABLE public.player_stats (
+    id					INT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+	level				TINYINT UNSIGNED NOT NULL,
+	rank_delta			TINYINT NOT NULL,
+	wins				SMALLINT UNSIGNED NOT NULL,
+	kills				INT UNSIGNED NOT NULL,
+	views				BIGINT UNSIGNED NOT NULL,
+	streak				SMALLINT UNSIGNED
+);
+
+CREATE TABLE public.league_season (
+    league				INT NOT NULL,
+	season				INT NOT NULL,
+	title				TEXT NOT NULL,
+	PRIMARY KEY (league, season),
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.season_standing (
+    league				INT NOT NULL,
+	season				INT NOT NULL,
+	team				VARCHAR(64) NOT NULL,
+	points				INT NOT NULL,
+	PRIMARY KEY (league, season, team),
+	FOREIGN KEY (league, season) REFERENCES league_season(league, season)
+);
+
+CREATE TABLE public.announcement (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	league				INT,
+	title				TEXT NOT NULL,
+	deleted_at			DATETIME,
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.bracket (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	tournament			INT,
+	stage				TEXT NOT NULL,
+	version				INT NOT NULL DEFAULT 1,
+	FOREIGN KEY (tournament) REFERENCES tournament(id)
+);
+
+CREATE TABLE public.article (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	league				INT,
+	title				TEXT NOT NULL,
+	created_at			DATETIME(6) NOT NULL DEFAULT (UTC_TIMESTAMP(6)),
+	updated_at			DATETIME(6) NOT NULL DEFAULT (UTC_TIMESTAMP(6)),
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.caster (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	league				INT,
+	nickname			TEXT NOT NULL,
+	FOREIGN KEY (league) REFERENCES league(id)
+);
+
+CREATE TABLE public.registration (
+    id					INT AUTO_INCREMENT PRIMARY KEY,
+	tournament			INT,
+	team_slug			TEXT NOT NULL,
+	seed				INT NOT NULL,
+	FOREIGN KEY (tournament) REFERENCES tournament(id)
+);
@@ -38,6 +38,117 @@ CREATE TABLE public.team (
 	home_league			INTEGER REFERENCES league(id)
 );
 
+CREATE TABLE public.broadcast (
+    id					UUID PRIMARY KEY,
+	league				INTEGER REFERENCES league(id),
+	channel				TEXT NOT NULL,
+	stream_key			UUID
+);
+
+CREATE TABLE public.sponsor (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	league				INTEGER REFERENCES league(id),
+	name				TEXT NOT NULL,
+	amount				NUMERIC(19,4) NOT NULL,
+	bonus				NUMERIC(19,4)
+);
+
+CREATE TABLE public.league_settings (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	league				INTEGER REFERENCES league(id),
+	preferences			JSONB NOT NULL,
+	extra				JSONB
+);
+
+CREATE TABLE public.attachment (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	league				INTEGER REFERENCES league(id),
+	file_name			TEXT NOT NULL,
+	content				BYTEA NOT NULL,
+	thumbnail			BYTEA
+);
+
+CREATE TYPE ticket_tier AS ENUM ('general', 'premium', 'back_stage');
+
+CREATE TABLE public.ticket (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	tournament			INTEGER REFERENCES tournament(id),
+	tier				ticket_tier NOT NULL,
+	status				INTEGER NOT NULL,
+	previous_status		INTEGER,
+	holder_email		TEXT NOT NULL,
+	price				NUMERIC(19,4)
+);
+
+CREATE TABLE public.roster (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	team				TEXT NOT NULL,
+	players				TEXT[] NOT NULL,
+	jersey_numbers		INTEGER[] NOT NULL,
+	substitutes			TEXT[]
+);
+
+CREATE TABLE public.player_stats (
+    id					BIGINT PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	level				SMALLINT NOT NULL,
+	rank_delta			SMALLINT NOT NULL,
+	wins				INTEGER NOT NULL,
+	kills				BIGINT NOT NULL,
+	views				BIGINT NOT NULL,
+	streak				INTEGER
+);
+
+CREATE TABLE public.league_season (
+    league				INTEGER NOT NULL REFERENCES league(id),
+	season				INTEGER NOT NULL,
+	title				TEXT NOT NULL,
+	PRIMARY KEY (league, season)
+);
+
+CREATE TABLE public.season_standing (
+    league				INTEGER NOT NULL,
+	season				INTEGER NOT NULL,
+	team				TEXT NOT NULL,
+	points				INTEGER NOT NULL,
+	PRIMARY KEY (league, season, team),
+	FOREIGN KEY (league, season) REFERENCES league_season(league, season)
+);
+
+CREATE TABLE public.announcement (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	league				INTEGER REFERENCES league(id),
+	title				TEXT NOT NULL,
+	deleted_at			TIMESTAMP
+);
+
+CREATE TABLE public.bracket (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	tournament			INTEGER REFERENCES tournament(id),
+	stage				TEXT NOT NULL,
+	version				INTEGER NOT NULL DEFAULT 1
+);
+
+CREATE TABLE public.article (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	league				INTEGER REFERENCES league(id),
+	title				TEXT NOT NULL,
+	created_at			TIMESTAMP NOT NULL DEFAULT (now() AT TIME ZONE 'utc'),
+	updated_at			TIMESTAMP NOT NULL DEFAULT (now() AT TIME ZONE 'utc')
+);
+
+CREATE TABLE public.caster (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	league				INTEGER REFERENCES league(id),
+	nickname			TEXT NOT NULL
+);
+
+CREATE TABLE public.registration (
+    id					INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+	tournament			INTEGER REFERENCES tournament(id),
+	team_slug			TEXT NOT NULL,
+	seed				INTEGER NOT NULL
+);
+
 -- For now, we use for out CI process the default data for postgres instances
 
 -- ALTER TABLE public.league OWNER TO triforce;
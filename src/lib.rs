@@ -28,6 +28,9 @@ pub mod macros {
 /// connection module serves to reexport the public elements of the `canyon_connection` crate,
 /// exposing them through the public API
 pub mod connection {
+    pub use canyon_connection::statement_cache::StatementCacheStats;
+    pub use canyon_connection::statement_cache_stats;
+
     #[cfg(feature = "postgres")]
     pub use canyon_connection::canyon_database_connector::DatabaseConnection::Postgres;
 
@@ -41,19 +44,35 @@ pub mod connection {
 /// Crud module serves to reexport the public elements of the `canyon_crud` crate,
 /// exposing them through the public API
 pub mod crud {
+    pub use canyon_crud::batch;
+    pub use canyon_crud::blob;
     pub use canyon_crud::bounds;
     pub use canyon_crud::crud::*;
+    pub use canyon_crud::enums;
+    pub use canyon_crud::hooks::CanyonHooks;
     pub use canyon_crud::mapper::*;
+    pub use canyon_crud::metrics;
     pub use canyon_crud::rows::CanyonRows;
+    pub use canyon_crud::slow_query;
+    pub use canyon_crud::types;
+    pub use canyon_crud::validation;
     pub use canyon_crud::DatabaseType;
 }
 
 /// Re-exports the query elements from the `crud`crate
 pub mod query {
+    pub use canyon_crud::params;
+    pub use canyon_crud::query_elements::named_params::NamedParams;
     pub use canyon_crud::query_elements::operators;
+    pub use canyon_crud::query_elements::query_plan::{PlanNode, QueryPlan};
     pub use canyon_crud::query_elements::{query::*, query_builder::*};
 }
 
+/// Metrics of the statements launched against the datasources
+pub mod metrics {
+    pub use canyon_crud::metrics::*;
+}
+
 /// Reexport the available database clients within Canyon
 pub mod db_clients {
     #[cfg(feature = "mysql")]
@@ -79,3 +98,22 @@ pub mod date_time {
         DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc,
     };
 }
+
+/// Module for reexport the `uuid` crate, which type can be used as a primary key or as any column
+#[cfg(feature = "uuid")]
+pub mod uuid {
+    pub use canyon_crud::uuid::Uuid;
+}
+
+/// Module for reexport the `rust_decimal` crate, which type maps the exact numeric columns
+#[cfg(feature = "decimal")]
+pub mod decimal {
+    pub use canyon_crud::rust_decimal::Decimal;
+}
+
+/// Module for reexport the JSON types that can be used as the columns that holds JSON documents
+#[cfg(feature = "json")]
+pub mod json {
+    pub use canyon_crud::json::Json;
+    pub use canyon_crud::serde_json::{json, Value};
+}
@@ -92,6 +92,155 @@ BEGIN
         home_league		    INT REFERENCES league(id)
     );
 END;
+
+IF OBJECT_ID(N'[dbo].[broadcast]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.broadcast (
+        id					UNIQUEIDENTIFIER PRIMARY KEY,
+        league				INT REFERENCES league(id),
+        channel				NVARCHAR(250) NOT NULL,
+        stream_key			UNIQUEIDENTIFIER
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[sponsor]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.sponsor (
+        id					INT PRIMARY KEY IDENTITY,
+        league				INT REFERENCES league(id),
+        name				NVARCHAR(250) NOT NULL,
+        amount				DECIMAL(19,4) NOT NULL,
+        bonus				DECIMAL(19,4)
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[league_settings]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.league_settings (
+        id					INT PRIMARY KEY IDENTITY,
+        league				INT REFERENCES league(id),
+        preferences			NVARCHAR(MAX) NOT NULL,
+        extra				NVARCHAR(MAX)
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[attachment]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.attachment (
+        id					INT PRIMARY KEY IDENTITY,
+        league				INT REFERENCES league(id),
+        file_name			NVARCHAR(250) NOT NULL,
+        content				VARBINARY(MAX) NOT NULL,
+        thumbnail			VARBINARY(MAX)
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[ticket]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.ticket (
+        id					INT PRIMARY KEY IDENTITY,
+        tournament			INT REFERENCES tournament(id),
+        tier				NVARCHAR(250) NOT NULL,
+        status				INT NOT NULL,
+        previous_status		INT,
+        holder_email		NVARCHAR(320) NOT NULL,
+        price				DECIMAL(19,4)
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[roster]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.roster (
+        id					INT PRIMARY KEY IDENTITY,
+        team				NVARCHAR(250) NOT NULL,
+        players				NVARCHAR(MAX) NOT NULL,
+        jersey_numbers		NVARCHAR(MAX) NOT NULL,
+        substitutes			NVARCHAR(MAX)
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[player_stats]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.player_stats (
+        id					BIGINT PRIMARY KEY IDENTITY,
+        level				TINYINT NOT NULL,
+        rank_delta			SMALLINT NOT NULL,
+        wins				INT NOT NULL,
+        kills				BIGINT NOT NULL,
+        views				BIGINT NOT NULL,
+        streak				INT
+    );
+END;
+IF OBJECT_ID(N'[dbo].[league_season]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.league_season (
+        league				INT NOT NULL REFERENCES league(id),
+        season				INT NOT NULL,
+        title				NVARCHAR(MAX) NOT NULL,
+        PRIMARY KEY (league, season)
+    );
+END;
+IF OBJECT_ID(N'[dbo].[season_standing]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.season_standing (
+        league				INT NOT NULL,
+        season				INT NOT NULL,
+        team				NVARCHAR(64) NOT NULL,
+        points				INT NOT NULL,
+        PRIMARY KEY (league, season, team),
+        FOREIGN KEY (league, season) REFERENCES league_season(league, season)
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[announcement]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.announcement (
+        id					INT PRIMARY KEY IDENTITY,
+        league				INT REFERENCES league(id),
+        title				NVARCHAR(250) NOT NULL,
+        deleted_at			DATETIME2
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[bracket]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.bracket (
+        id					INT PRIMARY KEY IDENTITY,
+        tournament			INT REFERENCES tournament(id),
+        stage				NVARCHAR(250) NOT NULL,
+        version				INT NOT NULL DEFAULT 1
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[article]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.article (
+        id					INT PRIMARY KEY IDENTITY,
+        league				INT REFERENCES league(id),
+        title				NVARCHAR(250) NOT NULL,
+        created_at			DATETIME2 NOT NULL DEFAULT SYSUTCDATETIME(),
+        updated_at			DATETIME2 NOT NULL DEFAULT SYSUTCDATETIME()
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[caster]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.caster (
+        id					INT PRIMARY KEY IDENTITY,
+        league				INT REFERENCES league(id),
+        nickname			NVARCHAR(250) NOT NULL
+    );
+END;
+
+IF OBJECT_ID(N'[dbo].[registration]', N'U') IS NULL
+BEGIN
+    CREATE TABLE dbo.registration (
+        id					INT PRIMARY KEY IDENTITY,
+        tournament			INT REFERENCES tournament(id),
+        team_slug			NVARCHAR(250) NOT NULL,
+        seed				INT NOT NULL
+    );
+END;
 ";
 
 #[cfg(feature = "mssql")]
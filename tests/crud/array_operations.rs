@@ -0,0 +1,213 @@
+//! Integration tests for the entities with array columns, native on `PostgreSQL` and
+//! stored as JSON arrays on `SqlServer` and `MySQL`
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{operators::ArrayOp, ops::QueryBuilder},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::roster::*;
+
+/// The arrays are bound as parameters and read back from the rows, and the query
+/// builder can filter by their elements, which follows the arrays after they are updated.
+///
+/// The arrays are the native `TEXT[]` and `INTEGER[]` types of Postgres
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_array_operation() {
+    let mut new_roster = Roster {
+        id: Default::default(),
+        team: "T1".to_string(),
+        players: vec!["Faker".to_string(), "Gumayusi".to_string()],
+        jersey_numbers: vec![7, 10],
+        substitutes: Some(vec!["Poby".to_string()]),
+    };
+    new_roster.insert().await.expect("Failed insert operation");
+
+    let inserted = Roster::find_by_pk(&new_roster.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_roster);
+
+    let numbers = vec![10];
+    let overlapping = vec![1, 7];
+    let by_elements = Roster::select_query()
+        .where_array(RosterField::players, ArrayOp::Any, &"Faker")
+        .and_array(RosterField::jersey_numbers, ArrayOp::Contains, &numbers)
+        .and_array(RosterField::jersey_numbers, ArrayOp::Overlaps, &overlapping)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_elements.contains(&new_roster));
+
+    let with_keria = Roster::select_query()
+        .where_array(RosterField::players, ArrayOp::Any, &"Keria")
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!with_keria.contains(&new_roster));
+
+    new_roster.players.push("Keria".to_string());
+    new_roster.jersey_numbers.clear();
+    new_roster.substitutes = None;
+    new_roster
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let updated = Roster::find_by_pk(&new_roster.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_roster);
+
+    let with_keria = Roster::select_query()
+        .where_array(RosterField::players, ArrayOp::Any, &"Keria")
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(with_keria.contains(&new_roster));
+
+    new_roster
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The arrays are JSON documents stored as `NVARCHAR(MAX)`, which elements are
+/// searched with `OPENJSON`
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_array_operation_datasource_mssql() {
+    let mut new_roster = Roster {
+        id: Default::default(),
+        team: "T1".to_string(),
+        players: vec!["Faker".to_string(), "Gumayusi".to_string()],
+        jersey_numbers: vec![7, 10],
+        substitutes: Some(vec!["Poby".to_string()]),
+    };
+    new_roster
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Roster::find_by_pk_datasource(&new_roster.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_roster);
+
+    let numbers = vec![10];
+    let overlapping = vec![1, 7];
+    let by_elements = Roster::select_query_datasource(SQL_SERVER_DS)
+        .where_array(RosterField::players, ArrayOp::Any, &"Faker")
+        .and_array(RosterField::jersey_numbers, ArrayOp::Contains, &numbers)
+        .and_array(RosterField::jersey_numbers, ArrayOp::Overlaps, &overlapping)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_elements.contains(&new_roster));
+
+    let with_keria = Roster::select_query_datasource(SQL_SERVER_DS)
+        .where_array(RosterField::players, ArrayOp::Any, &"Keria")
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!with_keria.contains(&new_roster));
+
+    new_roster.players.push("Keria".to_string());
+    new_roster.jersey_numbers.clear();
+    new_roster.substitutes = None;
+    new_roster
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Roster::find_by_pk_datasource(&new_roster.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_roster);
+
+    let with_keria = Roster::select_query_datasource(SQL_SERVER_DS)
+        .where_array(RosterField::players, ArrayOp::Any, &"Keria")
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(with_keria.contains(&new_roster));
+
+    new_roster
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The arrays are stored with the `JSON` type of MySQL, which elements are searched
+/// with `JSON_TABLE`, `JSON_CONTAINS` and `JSON_OVERLAPS`
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_array_operation_datasource_mysql() {
+    let mut new_roster = Roster {
+        id: Default::default(),
+        team: "T1".to_string(),
+        players: vec!["Faker".to_string(), "Gumayusi".to_string()],
+        jersey_numbers: vec![7, 10],
+        substitutes: Some(vec!["Poby".to_string()]),
+    };
+    new_roster
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Roster::find_by_pk_datasource(&new_roster.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_roster);
+
+    let numbers = vec![10];
+    let overlapping = vec![1, 7];
+    let by_elements = Roster::select_query_datasource(MYSQL_DS)
+        .where_array(RosterField::players, ArrayOp::Any, &"Faker")
+        .and_array(RosterField::jersey_numbers, ArrayOp::Contains, &numbers)
+        .and_array(RosterField::jersey_numbers, ArrayOp::Overlaps, &overlapping)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_elements.contains(&new_roster));
+
+    let with_keria = Roster::select_query_datasource(MYSQL_DS)
+        .where_array(RosterField::players, ArrayOp::Any, &"Keria")
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!with_keria.contains(&new_roster));
+
+    new_roster.players.push("Keria".to_string());
+    new_roster.jersey_numbers.clear();
+    new_roster.substitutes = None;
+    new_roster
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Roster::find_by_pk_datasource(&new_roster.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_roster);
+
+    let with_keria = Roster::select_query_datasource(MYSQL_DS)
+        .where_array(RosterField::players, ArrayOp::Any, &"Keria")
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(with_keria.contains(&new_roster));
+
+    new_roster
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
@@ -0,0 +1,270 @@
+//! Integration tests for the entities with binary columns, mapped to `Vec<u8>`, and for
+//! the streaming access to their content
+use canyon_sql::crud::CrudOperations;
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::attachment::*;
+
+/// The chunk size used on the tests, smaller than the content streamed, so it's
+/// written and read back in several chunks
+#[cfg(any(feature = "postgres", feature = "mssql", feature = "mysql"))]
+const CHUNK_SIZE: usize = 4096;
+
+/// A content larger than [`CHUNK_SIZE`], with every possible byte value
+#[cfg(any(feature = "postgres", feature = "mssql", feature = "mysql"))]
+fn large_content() -> Vec<u8> {
+    (0..=255u8).cycle().take(CHUNK_SIZE * 2 + 1000).collect()
+}
+
+/// The binary values are bound as parameters and read back from the rows, and the
+/// content of the binary columns can be streamed in chunks.
+///
+/// The content is stored as `BYTEA`, and read back in chunks with `substring`
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_blob_operation() {
+    let mut new_attachment = Attachment {
+        id: Default::default(),
+        league: 1,
+        file_name: "rules.pdf".to_string(),
+        content: b"%PDF-1.7 \x00\x01\x02\xff".to_vec(),
+        thumbnail: Some(vec![0x89, 0x50, 0x4e, 0x47]),
+    };
+    new_attachment
+        .insert()
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Attachment::find_by_pk(&new_attachment.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_attachment);
+
+    new_attachment.thumbnail = None;
+    new_attachment
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let thumbnail = new_attachment
+        .blob(AttachmentField::thumbnail)
+        .expect("No blob for the attachment");
+    assert_eq!(thumbnail.length().await.expect("Request error"), None);
+
+    let content = large_content();
+    let blob = new_attachment
+        .blob(AttachmentField::content)
+        .expect("No blob for the attachment");
+    let written = blob
+        .write_from(&mut content.as_slice(), CHUNK_SIZE)
+        .await
+        .expect("Failed to stream the content into the column");
+    assert_eq!(written, content.len() as u64);
+    assert_eq!(
+        blob.length().await.expect("Request error"),
+        Some(content.len() as u64)
+    );
+
+    let mut read = Vec::new();
+    blob.read_to(&mut read, CHUNK_SIZE)
+        .await
+        .expect("Failed to stream the content from the column");
+    assert_eq!(read, content);
+    let empty_chunks = blob
+        .read_to(&mut Vec::new(), 0)
+        .await
+        .expect_err("The chunks of zero bytes must be rejected");
+    assert_eq!(
+        empty_chunks
+            .downcast_ref::<std::io::Error>()
+            .expect("Not an io error")
+            .kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        blob.read_chunk(CHUNK_SIZE as u64, 3)
+            .await
+            .expect("Request error"),
+        content[CHUNK_SIZE..CHUNK_SIZE + 3]
+    );
+
+    let updated = Attachment::find_by_pk(&new_attachment.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.content, content);
+    assert_eq!(updated.thumbnail, None);
+
+    new_attachment
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The content is stored as `VARBINARY(MAX)`, which chunks are appended in place with `.WRITE`
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_blob_operation_datasource_mssql() {
+    let mut new_attachment = Attachment {
+        id: Default::default(),
+        league: 1,
+        file_name: "rules.pdf".to_string(),
+        content: b"%PDF-1.7 \x00\x01\x02\xff".to_vec(),
+        thumbnail: Some(vec![0x89, 0x50, 0x4e, 0x47]),
+    };
+    new_attachment
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Attachment::find_by_pk_datasource(&new_attachment.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_attachment);
+
+    new_attachment.thumbnail = None;
+    new_attachment
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let thumbnail = new_attachment
+        .blob_datasource(AttachmentField::thumbnail, SQL_SERVER_DS)
+        .expect("No blob for the attachment");
+    assert_eq!(thumbnail.length().await.expect("Request error"), None);
+
+    let content = large_content();
+    let blob = new_attachment
+        .blob_datasource(AttachmentField::content, SQL_SERVER_DS)
+        .expect("No blob for the attachment");
+    let written = blob
+        .write_from(&mut content.as_slice(), CHUNK_SIZE)
+        .await
+        .expect("Failed to stream the content into the column");
+    assert_eq!(written, content.len() as u64);
+    assert_eq!(
+        blob.length().await.expect("Request error"),
+        Some(content.len() as u64)
+    );
+
+    let mut read = Vec::new();
+    blob.read_to(&mut read, CHUNK_SIZE)
+        .await
+        .expect("Failed to stream the content from the column");
+    assert_eq!(read, content);
+    let empty_chunks = blob
+        .read_to(&mut Vec::new(), 0)
+        .await
+        .expect_err("The chunks of zero bytes must be rejected");
+    assert_eq!(
+        empty_chunks
+            .downcast_ref::<std::io::Error>()
+            .expect("Not an io error")
+            .kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        blob.read_chunk(CHUNK_SIZE as u64, 3)
+            .await
+            .expect("Request error"),
+        content[CHUNK_SIZE..CHUNK_SIZE + 3]
+    );
+
+    let updated = Attachment::find_by_pk_datasource(&new_attachment.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.content, content);
+    assert_eq!(updated.thumbnail, None);
+
+    new_attachment
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The content is stored as `LONGBLOB`, which chunks are appended with `CONCAT`
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_blob_operation_datasource_mysql() {
+    let mut new_attachment = Attachment {
+        id: Default::default(),
+        league: 1,
+        file_name: "rules.pdf".to_string(),
+        content: b"%PDF-1.7 \x00\x01\x02\xff".to_vec(),
+        thumbnail: Some(vec![0x89, 0x50, 0x4e, 0x47]),
+    };
+    new_attachment
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Attachment::find_by_pk_datasource(&new_attachment.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_attachment);
+
+    new_attachment.thumbnail = None;
+    new_attachment
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let thumbnail = new_attachment
+        .blob_datasource(AttachmentField::thumbnail, MYSQL_DS)
+        .expect("No blob for the attachment");
+    assert_eq!(thumbnail.length().await.expect("Request error"), None);
+
+    let content = large_content();
+    let blob = new_attachment
+        .blob_datasource(AttachmentField::content, MYSQL_DS)
+        .expect("No blob for the attachment");
+    let written = blob
+        .write_from(&mut content.as_slice(), CHUNK_SIZE)
+        .await
+        .expect("Failed to stream the content into the column");
+    assert_eq!(written, content.len() as u64);
+    assert_eq!(
+        blob.length().await.expect("Request error"),
+        Some(content.len() as u64)
+    );
+
+    let mut read = Vec::new();
+    blob.read_to(&mut read, CHUNK_SIZE)
+        .await
+        .expect("Failed to stream the content from the column");
+    assert_eq!(read, content);
+    let empty_chunks = blob
+        .read_to(&mut Vec::new(), 0)
+        .await
+        .expect_err("The chunks of zero bytes must be rejected");
+    assert_eq!(
+        empty_chunks
+            .downcast_ref::<std::io::Error>()
+            .expect("Not an io error")
+            .kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        blob.read_chunk(CHUNK_SIZE as u64, 3)
+            .await
+            .expect("Request error"),
+        content[CHUNK_SIZE..CHUNK_SIZE + 3]
+    );
+
+    let updated = Attachment::find_by_pk_datasource(&new_attachment.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.content, content);
+    assert_eq!(updated.thumbnail, None);
+
+    new_attachment
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
@@ -0,0 +1,293 @@
+//! Integration tests for the entities identified by a composite primary key, and
+//! for the composite foreign keys that references them
+use canyon_sql::crud::CrudOperations;
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league_season::*;
+use crate::tests_models::season_standing::*;
+
+/// Builds the standing of a team on the given season
+fn new_standing(season: &LeagueSeason, team: &str, points: i32) -> SeasonStanding {
+    SeasonStanding {
+        league: season.league,
+        season: season.season,
+        team: team.to_string(),
+        points,
+    }
+}
+
+/// Inserts, finds, searches by the composite foreign key, updates and deletes
+/// the entities identified by a composite primary key
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_composite_key_operation() {
+    let mut season = LeagueSeason {
+        league: 3,
+        season: 2024,
+        title: String::from("Spring split"),
+    };
+    season.insert().await.expect("Failed insert operation");
+
+    let found = LeagueSeason::find_by_pk(&(season.league, season.season))
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(found, season);
+    // Every column of the primary key must be given
+    assert!(LeagueSeason::find_by_pk(&season.league).await.is_err());
+
+    let parent = season
+        .search_league()
+        .await
+        .expect("Result variant of the query is err")
+        .expect("No parent found for the season");
+    assert_eq!(parent.id, season.league);
+
+    let mut standings = [
+        new_standing(&season, "T1", 9),
+        new_standing(&season, "G2", 6),
+    ];
+    let [first, second] = &mut standings;
+    SeasonStanding::multi_insert(&mut [first, second])
+        .await
+        .expect("Failed the multi insert operation");
+
+    let mut children = SeasonStanding::search_league_season_childrens(&season)
+        .await
+        .expect("Result variant of the query is err");
+    children.sort_by(|a, b| a.team.cmp(&b.team));
+    assert_eq!(children, vec![standings[1].clone(), standings[0].clone()]);
+
+    let parent_season = standings[0]
+        .search_league_season()
+        .await
+        .expect("Result variant of the query is err");
+    assert_eq!(parent_season, Some(season.clone()));
+
+    standings[0].points = 12;
+    standings[0]
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let updated = SeasonStanding::find_by_pk(&(season.league, season.season, "T1".to_string()))
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, standings[0]);
+
+    let keys = [
+        (season.league, season.season, "T1".to_string()),
+        (season.league, season.season, "G2".to_string()),
+    ];
+    let by_key = SeasonStanding::find_by_pks_map(&keys)
+        .await
+        .expect("Failed the find_by_pks_map operation");
+    assert_eq!(by_key.len(), 2);
+    assert_eq!(by_key[&keys[0]], standings[0]);
+
+    season.title = String::from("Summer split");
+    season.update().await.expect("Failed the update operation");
+
+    for standing in standings.iter() {
+        standing
+            .delete()
+            .await
+            .expect("Failed the delete operation");
+    }
+    season.delete().await.expect("Failed the delete operation");
+    assert_eq!(
+        LeagueSeason::find_by_pk(&(season.league, season.season))
+            .await
+            .expect("Request error"),
+        None
+    );
+}
+
+/// Same as the above test, but with the specified datasource.
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_composite_key_operation_datasource_mssql() {
+    let mut season = LeagueSeason {
+        league: 4,
+        season: 2024,
+        title: String::from("Spring split"),
+    };
+    season
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let found = LeagueSeason::find_by_pk_datasource(&(season.league, season.season), SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(found, season);
+    // Every column of the primary key must be given
+    assert!(
+        LeagueSeason::find_by_pk_datasource(&season.league, SQL_SERVER_DS)
+            .await
+            .is_err()
+    );
+
+    let parent = season
+        .search_league_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Result variant of the query is err")
+        .expect("No parent found for the season");
+    assert_eq!(parent.id, season.league);
+
+    let mut standings = [
+        new_standing(&season, "T1", 9),
+        new_standing(&season, "G2", 6),
+    ];
+    let [first, second] = &mut standings;
+    SeasonStanding::multi_insert_datasource(&mut [first, second], SQL_SERVER_DS)
+        .await
+        .expect("Failed the multi insert operation");
+
+    let mut children =
+        SeasonStanding::search_league_season_childrens_datasource(&season, SQL_SERVER_DS)
+            .await
+            .expect("Result variant of the query is err");
+    children.sort_by(|a, b| a.team.cmp(&b.team));
+    assert_eq!(children, vec![standings[1].clone(), standings[0].clone()]);
+
+    let parent_season = standings[0]
+        .search_league_season_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Result variant of the query is err");
+    assert_eq!(parent_season, Some(season.clone()));
+
+    standings[0].points = 12;
+    standings[0]
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = SeasonStanding::find_by_pk_datasource(
+        &(season.league, season.season, "T1".to_string()),
+        SQL_SERVER_DS,
+    )
+    .await
+    .expect("Request error")
+    .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, standings[0]);
+
+    season.title = String::from("Summer split");
+    season
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+
+    for standing in standings.iter() {
+        standing
+            .delete_datasource(SQL_SERVER_DS)
+            .await
+            .expect("Failed the delete operation");
+    }
+    season
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+    assert_eq!(
+        LeagueSeason::find_by_pk_datasource(&(season.league, season.season), SQL_SERVER_DS)
+            .await
+            .expect("Request error"),
+        None
+    );
+}
+
+/// Same as the above test, but with the specified datasource.
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_composite_key_operation_datasource_mysql() {
+    let mut season = LeagueSeason {
+        league: 5,
+        season: 2024,
+        title: String::from("Spring split"),
+    };
+    season
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let found = LeagueSeason::find_by_pk_datasource(&(season.league, season.season), MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(found, season);
+    // Every column of the primary key must be given
+    assert!(
+        LeagueSeason::find_by_pk_datasource(&season.league, MYSQL_DS)
+            .await
+            .is_err()
+    );
+
+    let parent = season
+        .search_league_datasource(MYSQL_DS)
+        .await
+        .expect("Result variant of the query is err")
+        .expect("No parent found for the season");
+    assert_eq!(parent.id, season.league);
+
+    let mut standings = [
+        new_standing(&season, "T1", 9),
+        new_standing(&season, "G2", 6),
+    ];
+    let [first, second] = &mut standings;
+    SeasonStanding::multi_insert_datasource(&mut [first, second], MYSQL_DS)
+        .await
+        .expect("Failed the multi insert operation");
+
+    let mut children = SeasonStanding::search_league_season_childrens_datasource(&season, MYSQL_DS)
+        .await
+        .expect("Result variant of the query is err");
+    children.sort_by(|a, b| a.team.cmp(&b.team));
+    assert_eq!(children, vec![standings[1].clone(), standings[0].clone()]);
+
+    let parent_season = standings[0]
+        .search_league_season_datasource(MYSQL_DS)
+        .await
+        .expect("Result variant of the query is err");
+    assert_eq!(parent_season, Some(season.clone()));
+
+    standings[0].points = 12;
+    standings[0]
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = SeasonStanding::find_by_pk_datasource(
+        &(season.league, season.season, "T1".to_string()),
+        MYSQL_DS,
+    )
+    .await
+    .expect("Request error")
+    .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, standings[0]);
+
+    season.title = String::from("Summer split");
+    season
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+
+    for standing in standings.iter() {
+        standing
+            .delete_datasource(MYSQL_DS)
+            .await
+            .expect("Failed the delete operation");
+    }
+    season
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+    assert_eq!(
+        LeagueSeason::find_by_pk_datasource(&(season.league, season.season), MYSQL_DS)
+            .await
+            .expect("Request error"),
+        None
+    );
+}
@@ -0,0 +1,227 @@
+//! Integration tests for the entities with fields typed with user defined types,
+//! that implements `CanyonType` over one of the types supported by Canyon
+use canyon_sql::{
+    crud::CrudOperations,
+    decimal::Decimal,
+    query::{operators::Comp, ops::QueryBuilder},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::ticket::*;
+
+/// The custom types are bound as parameters, and read back from the rows, through
+/// their representation, optional or not. The emails are stored as `TEXT`, and the
+/// prices with the `NUMERIC(19,4)` declared for Postgres by the custom type
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_custom_type_operation() {
+    let mut new_ticket = Ticket {
+        tier: TicketTier::General,
+        status: TicketStatus::Reserved,
+        holder_email: Email::new("Peanut@HLE.gg"),
+        price: Some(Money(Decimal::new(4999, 2))),
+        ..new_ticket()
+    };
+    new_ticket.insert().await.expect("Failed insert operation");
+
+    let inserted = Ticket::find_by_pk(&new_ticket.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted.holder_email, Email::new("peanut@hle.gg"));
+    assert_eq!(inserted.price, Some(Money(Decimal::new(4999, 2))));
+
+    new_ticket.price = None;
+    new_ticket
+        .update()
+        .await
+        .expect("Failed the update operation");
+
+    let by_holder = Ticket::select_query()
+        .r#where(
+            TicketFieldValue::holder_email(&Email::new("Peanut@HLE.gg")),
+            Comp::Eq,
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_holder.contains(&new_ticket));
+    assert!(by_holder.iter().all(|ticket| ticket.price.is_none()));
+
+    new_ticket
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The emails are stored with the `NVARCHAR(320)` declared for SqlServer by the custom type
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_custom_type_operation_datasource_mssql() {
+    let mut new_ticket = Ticket {
+        tier: TicketTier::General,
+        status: TicketStatus::Reserved,
+        holder_email: Email::new("Peanut@HLE.gg"),
+        price: Some(Money(Decimal::new(4999, 2))),
+        ..new_ticket()
+    };
+    new_ticket
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Ticket::find_by_pk_datasource(&new_ticket.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted.holder_email, Email::new("peanut@hle.gg"));
+    assert_eq!(inserted.price, Some(Money(Decimal::new(4999, 2))));
+
+    new_ticket.price = None;
+    new_ticket
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+
+    let by_holder = Ticket::select_query_datasource(SQL_SERVER_DS)
+        .r#where(
+            TicketFieldValue::holder_email(&Email::new("Peanut@HLE.gg")),
+            Comp::Eq,
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_holder.contains(&new_ticket));
+    assert!(by_holder.iter().all(|ticket| ticket.price.is_none()));
+
+    new_ticket
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The emails are stored with the `VARCHAR(320)` declared for MySQL by the custom type
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_custom_type_operation_datasource_mysql() {
+    let mut new_ticket = Ticket {
+        tier: TicketTier::General,
+        status: TicketStatus::Reserved,
+        holder_email: Email::new("Peanut@HLE.gg"),
+        price: Some(Money(Decimal::new(4999, 2))),
+        ..new_ticket()
+    };
+    new_ticket
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Ticket::find_by_pk_datasource(&new_ticket.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted.holder_email, Email::new("peanut@hle.gg"));
+    assert_eq!(inserted.price, Some(Money(Decimal::new(4999, 2))));
+
+    new_ticket.price = None;
+    new_ticket
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+
+    let by_holder = Ticket::select_query_datasource(MYSQL_DS)
+        .r#where(
+            TicketFieldValue::holder_email(&Email::new("Peanut@HLE.gg")),
+            Comp::Eq,
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_holder.contains(&new_ticket));
+    assert!(by_holder.iter().all(|ticket| ticket.price.is_none()));
+
+    new_ticket
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The representations rejected by the conversion of a custom type fails the mapping
+/// of the rows that holds them, instead of panicking
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_invalid_custom_type_operation() {
+    let mut new_ticket = Ticket {
+        holder_email: Email::new("not-an-email"),
+        ..new_ticket()
+    };
+    new_ticket.insert().await.expect("Failed insert operation");
+
+    let error = Ticket::find_by_pk(&new_ticket.id)
+        .await
+        .expect_err("An invalid email was mapped");
+    assert!(error
+        .to_string()
+        .contains("`not-an-email` isn't a valid email"));
+
+    new_ticket
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// Same as the above test, but with the specified datasource.
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_invalid_custom_type_operation_datasource_mssql() {
+    let mut new_ticket = Ticket {
+        holder_email: Email::new("not-an-email"),
+        ..new_ticket()
+    };
+    new_ticket
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let error = Ticket::find_by_pk_datasource(&new_ticket.id, SQL_SERVER_DS)
+        .await
+        .expect_err("An invalid email was mapped");
+    assert!(error
+        .to_string()
+        .contains("`not-an-email` isn't a valid email"));
+
+    new_ticket
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// Same as the above test, but with the specified datasource.
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_invalid_custom_type_operation_datasource_mysql() {
+    let mut new_ticket = Ticket {
+        holder_email: Email::new("not-an-email"),
+        ..new_ticket()
+    };
+    new_ticket
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let error = Ticket::find_by_pk_datasource(&new_ticket.id, MYSQL_DS)
+        .await
+        .expect_err("An invalid email was mapped");
+    assert!(error
+        .to_string()
+        .contains("`not-an-email` isn't a valid email"));
+
+    new_ticket
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
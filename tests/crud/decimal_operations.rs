@@ -0,0 +1,210 @@
+//! Integration tests for the entities with exact numeric columns, mapped to `Decimal`
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{operators::Comp, ops::QueryBuilder},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::sponsor::*;
+
+/// The exact numeric values are bound as parameters and read back from the rows without
+/// losing any digit, unlike with the floating point types. The ones with more digits than
+/// the scale of their column are rounded by the database.
+///
+/// The decimals travels in the binary format of the Postgres `NUMERIC`
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_decimal_operation() {
+    let mut new_sponsor = Sponsor {
+        id: Default::default(),
+        league: 1,
+        name: "Some league sponsor".to_string(),
+        amount: decimal("1500000.1000"),
+        bonus: Some(decimal("0.2000")),
+    };
+    new_sponsor.insert().await.expect("Failed insert operation");
+
+    let inserted = Sponsor::find_by_pk(&new_sponsor.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_sponsor);
+    assert_eq!(
+        inserted.amount + inserted.bonus.unwrap(),
+        decimal("1500000.3")
+    );
+
+    let over_the_amount = Sponsor::select_query()
+        .r#where(
+            SponsorFieldValue::amount(&decimal("1500000.0999")),
+            Comp::Gt,
+        )
+        .and(SponsorFieldValue::id(&new_sponsor.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(over_the_amount, vec![new_sponsor.clone()]);
+
+    new_sponsor.bonus = Some(decimal("0.12345"));
+    new_sponsor
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let rounded = Sponsor::find_by_pk(&new_sponsor.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(rounded.bonus, Some(decimal("0.1235")));
+
+    new_sponsor.bonus = None;
+    new_sponsor
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let updated = Sponsor::find_by_pk(&new_sponsor.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.bonus, None);
+
+    new_sponsor
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The decimals travels as the TDS `NUMERIC`, which keeps the scale of the bound value
+/// until SqlServer fits it into the column
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_decimal_operation_datasource_mssql() {
+    let mut new_sponsor = Sponsor {
+        id: Default::default(),
+        league: 1,
+        name: "Some league sponsor".to_string(),
+        amount: decimal("1500000.1000"),
+        bonus: Some(decimal("0.2000")),
+    };
+    new_sponsor
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Sponsor::find_by_pk_datasource(&new_sponsor.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_sponsor);
+    assert_eq!(
+        inserted.amount + inserted.bonus.unwrap(),
+        decimal("1500000.3")
+    );
+
+    let over_the_amount = Sponsor::select_query_datasource(SQL_SERVER_DS)
+        .r#where(
+            SponsorFieldValue::amount(&decimal("1500000.0999")),
+            Comp::Gt,
+        )
+        .and(SponsorFieldValue::id(&new_sponsor.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(over_the_amount, vec![new_sponsor.clone()]);
+
+    new_sponsor.bonus = Some(decimal("0.12345"));
+    new_sponsor
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let rounded = Sponsor::find_by_pk_datasource(&new_sponsor.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(rounded.bonus, Some(decimal("0.1235")));
+
+    new_sponsor.bonus = None;
+    new_sponsor
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Sponsor::find_by_pk_datasource(&new_sponsor.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.bonus, None);
+
+    new_sponsor
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The decimals travels as text on MySQL, so the parsing of the rows must keep
+/// the trailing zeros of the scale
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_decimal_operation_datasource_mysql() {
+    let mut new_sponsor = Sponsor {
+        id: Default::default(),
+        league: 1,
+        name: "Some league sponsor".to_string(),
+        amount: decimal("1500000.1000"),
+        bonus: Some(decimal("0.2000")),
+    };
+    new_sponsor
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Sponsor::find_by_pk_datasource(&new_sponsor.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_sponsor);
+    assert_eq!(
+        inserted.amount + inserted.bonus.unwrap(),
+        decimal("1500000.3")
+    );
+
+    let over_the_amount = Sponsor::select_query_datasource(MYSQL_DS)
+        .r#where(
+            SponsorFieldValue::amount(&decimal("1500000.0999")),
+            Comp::Gt,
+        )
+        .and(SponsorFieldValue::id(&new_sponsor.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(over_the_amount, vec![new_sponsor.clone()]);
+
+    new_sponsor.bonus = Some(decimal("0.12345"));
+    new_sponsor
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let rounded = Sponsor::find_by_pk_datasource(&new_sponsor.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(rounded.bonus, Some(decimal("0.1235")));
+
+    new_sponsor.bonus = None;
+    new_sponsor
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Sponsor::find_by_pk_datasource(&new_sponsor.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.bonus, None);
+
+    new_sponsor
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
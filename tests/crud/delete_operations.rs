@@ -157,3 +157,36 @@ fn test_crud_delete_datasource_mysql_method_operation() {
         None
     );
 }
+
+/// Deleting an instance that it's not on the database anymore does not
+/// silently succeed, but returns a [`std::io::ErrorKind::NotFound`] error
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_delete_not_found_operation() {
+    let mut new_league: League = League {
+        id: Default::default(),
+        ext_id: 7892635306594_i64,
+        slug: "some-new-league".to_string(),
+        name: "Some New League".to_string(),
+        region: "Bahía de cochinos".to_string(),
+        image_url: "https://nobodyspectsandimage.io".to_string(),
+    };
+
+    new_league.insert().await.expect("Failed insert operation");
+    new_league
+        .delete()
+        .await
+        .expect("Failed to delete the operation");
+
+    let not_found_err = new_league
+        .delete()
+        .await
+        .expect_err("Deleting an already deleted record must fail");
+    assert_eq!(
+        not_found_err
+            .downcast_ref::<std::io::Error>()
+            .expect("Not an io error")
+            .kind(),
+        std::io::ErrorKind::NotFound
+    );
+}
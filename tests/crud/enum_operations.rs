@@ -0,0 +1,151 @@
+//! Integration tests for the entities with fields typed with the fieldless enums derived
+//! with `CanyonEnum`, stored as text, as integers and as native enumerated types
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{operators::Comp, ops::QueryBuilder},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::player::*;
+use crate::tests_models::ticket::*;
+
+/// The enums are bound as parameters, and read back from the rows, through their
+/// labels or their discriminants, optional or not.
+///
+/// The tiers are stored with the native `ticket_tier` enumerated type of Postgres,
+/// which the labels are bound as without any cast
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_enum_operation() {
+    let mut new_ticket = new_ticket();
+    new_ticket.insert().await.expect("Failed insert operation");
+
+    let inserted = Ticket::find_by_pk(&new_ticket.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_ticket);
+
+    new_ticket.previous_status = Some(new_ticket.status);
+    new_ticket.status = TicketStatus::Refunded;
+    new_ticket
+        .update()
+        .await
+        .expect("Failed the update operation");
+
+    let by_tier_and_status = Ticket::select_query()
+        .r#where(TicketFieldValue::tier(&TicketTier::BackStage), Comp::Eq)
+        .and(TicketFieldValue::status(&TicketStatus::Refunded), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_tier_and_status.contains(&new_ticket));
+
+    new_ticket
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+
+    let supports = Player::select_query()
+        .r#where(PlayerFieldValue::role(&Role::Support), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!supports.is_empty());
+    assert!(supports.iter().all(|player| player.role == Role::Support));
+}
+
+/// SqlServer has no enumerated types, so it stores the labels of the enums declared
+/// as native ones as text
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_enum_operation_datasource_mssql() {
+    let mut new_ticket = new_ticket();
+    new_ticket
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Ticket::find_by_pk_datasource(&new_ticket.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_ticket);
+
+    new_ticket.previous_status = Some(new_ticket.status);
+    new_ticket.status = TicketStatus::Refunded;
+    new_ticket
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+
+    let by_tier_and_status = Ticket::select_query_datasource(SQL_SERVER_DS)
+        .r#where(TicketFieldValue::tier(&TicketTier::BackStage), Comp::Eq)
+        .and(TicketFieldValue::status(&TicketStatus::Refunded), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_tier_and_status.contains(&new_ticket));
+
+    new_ticket
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+
+    let supports = Player::select_query_datasource(SQL_SERVER_DS)
+        .r#where(PlayerFieldValue::role(&Role::Support), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!supports.is_empty());
+    assert!(supports.iter().all(|player| player.role == Role::Support));
+}
+
+/// The tiers are stored as text on MySQL, instead of with its column level `ENUM`
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_enum_operation_datasource_mysql() {
+    let mut new_ticket = new_ticket();
+    new_ticket
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = Ticket::find_by_pk_datasource(&new_ticket.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_ticket);
+
+    new_ticket.previous_status = Some(new_ticket.status);
+    new_ticket.status = TicketStatus::Refunded;
+    new_ticket
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+
+    let by_tier_and_status = Ticket::select_query_datasource(MYSQL_DS)
+        .r#where(TicketFieldValue::tier(&TicketTier::BackStage), Comp::Eq)
+        .and(TicketFieldValue::status(&TicketStatus::Refunded), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_tier_and_status.contains(&new_ticket));
+
+    new_ticket
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+
+    let supports = Player::select_query_datasource(MYSQL_DS)
+        .r#where(PlayerFieldValue::role(&Role::Support), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!supports.is_empty());
+    assert!(supports.iter().all(|player| player.role == Role::Support));
+}
@@ -0,0 +1,126 @@
+//! Integration tests for the execution plans of the statements generated
+//! by the query builders
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{operators::Comp, ops::QueryBuilder},
+};
+
+use crate::tests_models::league::*;
+
+/// The plan of a query isn't analyzed unless it's requested, so the
+/// actual rows produced by its nodes aren't available
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_explain_with_querybuilder() {
+    let plan = League::select_query()
+        .r#where(LeagueFieldValue::id(&1), Comp::Eq)
+        .explain()
+        .await
+        .expect("Error retrieving the plan of the query");
+
+    assert!(plan.reads("league"));
+    assert!(plan.root.cost.is_some());
+    assert!(plan.root.estimated_rows.is_some());
+    assert!(plan.nodes().all(|node| node.actual_rows.is_none()));
+    assert!(plan.raw.contains("Node Type"));
+}
+
+/// The analyzed statements are executed, but their changes are rolled back
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_explain_analyze_with_querybuilder() {
+    let before = League::find_by_pk(&1)
+        .await
+        .expect("Request error")
+        .expect("The league with id 1 exists");
+
+    let set = [(LeagueField::slug, "explained")];
+    let plan = League::update_query()
+        .set(&set)
+        .r#where(LeagueFieldValue::id(&1), Comp::Eq)
+        .explain_analyze()
+        .await
+        .expect("Error retrieving the plan of the update");
+
+    assert_eq!(plan.root.node_type, "ModifyTable");
+    assert!(plan.reads("league"));
+    assert!(plan.root.actual_rows.is_some());
+
+    let after = League::find_by_pk(&1).await.expect("Request error");
+    assert_eq!(after, Some(before));
+}
+
+/// Same as the explain test, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_explain_with_querybuilder_datasource_mssql() {
+    let plan = League::select_query_datasource(SQL_SERVER_DS)
+        .r#where(LeagueFieldValue::id(&1), Comp::Eq)
+        .explain()
+        .await
+        .expect("Error retrieving the plan of the query");
+
+    assert!(plan.reads("league"));
+    assert!(plan
+        .nodes()
+        .any(|node| node.node_type == "Clustered Index Seek"));
+    assert!(plan.root.actual_rows.is_none());
+}
+
+/// Same as the explain analyze test, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_explain_analyze_with_querybuilder_datasource_mssql() {
+    let before = League::find_by_pk_datasource(&1, SQL_SERVER_DS)
+        .await
+        .expect("Request error");
+
+    let set = [(LeagueField::slug, "explained")];
+    let plan = League::update_query_datasource(SQL_SERVER_DS)
+        .set(&set)
+        .r#where(LeagueFieldValue::id(&1), Comp::Eq)
+        .explain_analyze()
+        .await
+        .expect("Error retrieving the plan of the update");
+
+    assert!(plan.reads("league"));
+    assert!(plan.root.actual_rows.is_some());
+
+    let after = League::find_by_pk_datasource(&1, SQL_SERVER_DS)
+        .await
+        .expect("Request error");
+    assert_eq!(after, before);
+}
+
+/// Same as the explain test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_explain_with_querybuilder_datasource_mysql() {
+    let plan = League::select_query_datasource(MYSQL_DS)
+        .r#where(LeagueFieldValue::id(&1), Comp::Eq)
+        .explain()
+        .await
+        .expect("Error retrieving the plan of the query");
+
+    assert!(plan.uses_index("PRIMARY"));
+    assert!(plan.root.cost.is_some());
+}
+
+/// Same as the explain analyze test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_explain_analyze_with_querybuilder_datasource_mysql() {
+    let plan = League::select_query_datasource(MYSQL_DS)
+        .r#where(LeagueFieldValue::id(&1), Comp::Gt)
+        .explain_analyze()
+        .await
+        .expect("Error retrieving the plan of the query");
+
+    assert!(plan.reads("league"));
+    assert!(plan.nodes().any(|node| node.actual_rows.is_some()));
+}
@@ -0,0 +1,50 @@
+//! Integration tests for the lifecycle hooks of the entities declared
+//! with the `hooks` argument of the `canyon_entity` macro
+use canyon_sql::crud::CrudOperations;
+
+use crate::tests_models::caster::*;
+
+/// The CRUD operations over an entity declared with `#[canyon_entity(hooks)]` calls
+/// the hooks implemented for it, aborting the operation when a hook fails. The hooks
+/// runs before any statement is sent, so they behave the same on every database
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_hooks_operation() {
+    let mut new_caster = Caster {
+        id: Default::default(),
+        league: 1,
+        nickname: "  Some-Hooked-Caster ".to_string(),
+    };
+    new_caster.insert().await.expect("Failed insert operation");
+
+    // The `before_insert` hook normalizes the nickname before it's stored
+    assert_eq!(new_caster.nickname, "some-hooked-caster");
+    let inserted = Caster::find_by_pk(&new_caster.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_caster);
+
+    // The `before_update` and `before_delete` hooks aborts the operations
+    let mut without_nickname = inserted.clone();
+    without_nickname.nickname = String::new();
+    without_nickname
+        .update()
+        .await
+        .expect_err("The before_update hook must abort the update");
+    without_nickname
+        .delete()
+        .await
+        .expect_err("The before_delete hook must abort the delete");
+    assert_eq!(
+        Caster::find_by_pk(&new_caster.id)
+            .await
+            .expect("Request error"),
+        Some(inserted)
+    );
+
+    new_caster
+        .delete()
+        .await
+        .expect("Failed to delete the record");
+}
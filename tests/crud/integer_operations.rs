@@ -0,0 +1,195 @@
+//! Integration tests for the entities with the `i8` and the unsigned integer fields,
+//! including an unsigned primary key
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{operators::Comp, ops::QueryBuilder},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::player_stats::*;
+
+/// The integers are bound as parameters, read back from the rows and compared on the
+/// filters of the query builder.
+///
+/// Postgres has no unsigned types, so the fields are stored on the next wider signed
+/// column, and the `u64` values out of the range of a `bigint` are rejected, keeping
+/// the stored one
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_integer_operation() {
+    let mut new_stats = PlayerStats {
+        id: Default::default(),
+        level: 255,
+        rank_delta: -128,
+        wins: 65_535,
+        kills: 4_000_000_000,
+        views: 9_000_000_000_000_000_000,
+        streak: Some(12),
+    };
+    new_stats.insert().await.expect("Failed insert operation");
+
+    let inserted = PlayerStats::find_by_pk(&new_stats.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_stats);
+
+    let by_level = PlayerStats::select_query()
+        .r#where(PlayerStatsFieldValue::level(&200_u8), Comp::Gt)
+        .and(PlayerStatsFieldValue::kills(&3_000_000_000_u32), Comp::GtEq)
+        .and(PlayerStatsFieldValue::id(&new_stats.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(by_level, vec![new_stats.clone()]);
+
+    new_stats.streak = None;
+    new_stats
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let updated = PlayerStats::find_by_pk(&new_stats.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_stats);
+
+    let stored_views = new_stats.views;
+    new_stats.views = u64::MAX;
+    assert!(new_stats.update().await.is_err());
+    let kept = PlayerStats::find_by_pk(&new_stats.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(kept.views, stored_views);
+
+    new_stats
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// SqlServer has no unsigned types but `TINYINT`, so the remaining fields are stored
+/// on the next wider signed column
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_integer_operation_datasource_mssql() {
+    let mut new_stats = PlayerStats {
+        id: Default::default(),
+        level: 255,
+        rank_delta: -128,
+        wins: 65_535,
+        kills: 4_000_000_000,
+        views: 9_000_000_000_000_000_000,
+        streak: Some(12),
+    };
+    new_stats
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = PlayerStats::find_by_pk_datasource(&new_stats.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_stats);
+
+    let by_level = PlayerStats::select_query_datasource(SQL_SERVER_DS)
+        .r#where(PlayerStatsFieldValue::level(&200_u8), Comp::Gt)
+        .and(PlayerStatsFieldValue::kills(&3_000_000_000_u32), Comp::GtEq)
+        .and(PlayerStatsFieldValue::id(&new_stats.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(by_level, vec![new_stats.clone()]);
+
+    new_stats.streak = None;
+    new_stats
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = PlayerStats::find_by_pk_datasource(&new_stats.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_stats);
+
+    let stored_views = new_stats.views;
+    new_stats.views = u64::MAX;
+    assert!(new_stats.update_datasource(SQL_SERVER_DS).await.is_err());
+    let kept = PlayerStats::find_by_pk_datasource(&new_stats.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(kept.views, stored_views);
+
+    new_stats
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// MySQL has unsigned columns, so it stores the whole range of the `u64` values
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_integer_operation_datasource_mysql() {
+    let mut new_stats = PlayerStats {
+        id: Default::default(),
+        level: 255,
+        rank_delta: -128,
+        wins: 65_535,
+        kills: 4_000_000_000,
+        views: 9_000_000_000_000_000_000,
+        streak: Some(12),
+    };
+    new_stats
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = PlayerStats::find_by_pk_datasource(&new_stats.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_stats);
+
+    let by_level = PlayerStats::select_query_datasource(MYSQL_DS)
+        .r#where(PlayerStatsFieldValue::level(&200_u8), Comp::Gt)
+        .and(PlayerStatsFieldValue::kills(&3_000_000_000_u32), Comp::GtEq)
+        .and(PlayerStatsFieldValue::id(&new_stats.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(by_level, vec![new_stats.clone()]);
+
+    new_stats.streak = None;
+    new_stats
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = PlayerStats::find_by_pk_datasource(&new_stats.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_stats);
+
+    new_stats.views = u64::MAX;
+    new_stats
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = PlayerStats::find_by_pk_datasource(&new_stats.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.views, u64::MAX);
+
+    new_stats
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
@@ -0,0 +1,301 @@
+//! Integration tests for the entities with columns that stores JSON documents, typed
+//! with the `Json<T>` wrapper or free as a `serde_json::Value`
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{
+        operators::{Comp, JsonOp},
+        ops::QueryBuilder,
+    },
+};
+
+#[cfg(any(feature = "postgres", feature = "mssql", feature = "mysql"))]
+use canyon_sql::json::{json, Json};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league_settings::*;
+
+/// The JSON documents are bound as parameters and read back from the rows, and the query
+/// builder can filter by the values found on their paths.
+///
+/// The documents are stored as `JSONB`, and their paths are read with `jsonb_path_query_first`
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_json_operation() {
+    let mut new_settings = LeagueSettings {
+        id: Default::default(),
+        league: 1,
+        preferences: Json(Preferences {
+            theme: "dark".to_string(),
+            max_teams: 10,
+            languages: vec!["en".to_string(), "ko".to_string()],
+        }),
+        extra: Some(json!({ "region": "EU", "limits": { "roster": 7 } })),
+    };
+    new_settings
+        .insert()
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = LeagueSettings::find_by_pk(&new_settings.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_settings);
+
+    let by_theme = LeagueSettings::select_query()
+        .where_json(
+            LeagueSettingsField::preferences,
+            &JsonOp::Path("$.theme", Comp::Eq),
+            &"dark",
+        )
+        .and_json(
+            LeagueSettingsField::extra,
+            &JsonOp::Path("$.limits.roster", Comp::Eq),
+            &"7",
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_theme.contains(&new_settings));
+
+    new_settings.preferences.theme = "light".to_string();
+    new_settings.extra = None;
+    new_settings
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let updated = LeagueSettings::find_by_pk(&new_settings.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_settings);
+
+    new_settings
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The documents that holds a fragment are found with the containment operator, but
+/// not the ones where the fragment only matches partially.
+///
+/// The containment of Postgres is the `@>` operator of the `JSONB` documents
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_json_containment_operation() {
+    let mut new_settings = LeagueSettings {
+        id: Default::default(),
+        league: 1,
+        preferences: Json(Preferences {
+            theme: "dark".to_string(),
+            max_teams: 10,
+            languages: vec!["en".to_string(), "ko".to_string()],
+        }),
+        extra: Some(json!({ "region": "EU", "limits": { "roster": 7 } })),
+    };
+    new_settings
+        .insert()
+        .await
+        .expect("Failed insert operation");
+
+    let region = json!({ "region": "EU" });
+    let by_region = LeagueSettings::select_query()
+        .where_json(LeagueSettingsField::extra, &JsonOp::Contains, &region)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_region.contains(&new_settings));
+
+    let other_limits = json!({ "region": "EU", "limits": { "roster": 5 } });
+    let by_other_limits = LeagueSettings::select_query()
+        .where_json(LeagueSettingsField::extra, &JsonOp::Contains, &other_limits)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!by_other_limits.contains(&new_settings));
+
+    new_settings
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The documents are stored as `NVARCHAR(MAX)`, and their paths are read with `JSON_VALUE`.
+/// SqlServer has no containment operator for them
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_json_operation_datasource_mssql() {
+    let mut new_settings = LeagueSettings {
+        id: Default::default(),
+        league: 1,
+        preferences: Json(Preferences {
+            theme: "dark".to_string(),
+            max_teams: 10,
+            languages: vec!["en".to_string(), "ko".to_string()],
+        }),
+        extra: Some(json!({ "region": "EU", "limits": { "roster": 7 } })),
+    };
+    new_settings
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = LeagueSettings::find_by_pk_datasource(&new_settings.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_settings);
+
+    let by_theme = LeagueSettings::select_query_datasource(SQL_SERVER_DS)
+        .where_json(
+            LeagueSettingsField::preferences,
+            &JsonOp::Path("$.theme", Comp::Eq),
+            &"dark",
+        )
+        .and_json(
+            LeagueSettingsField::extra,
+            &JsonOp::Path("$.limits.roster", Comp::Eq),
+            &"7",
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_theme.contains(&new_settings));
+
+    new_settings.preferences.theme = "light".to_string();
+    new_settings.extra = None;
+    new_settings
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = LeagueSettings::find_by_pk_datasource(&new_settings.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_settings);
+
+    new_settings
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The containment has no equivalent on SqlServer, so the statements that use it fails
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_json_containment_operation_datasource_mssql() {
+    let by_region = LeagueSettings::select_query_datasource(SQL_SERVER_DS)
+        .where_json(
+            LeagueSettingsField::extra,
+            &JsonOp::Contains,
+            &"{\"region\": \"EU\"}",
+        )
+        .query()
+        .await;
+    assert!(by_region.is_err());
+}
+
+/// The documents are stored with the `JSON` type of MySQL, which normalizes them
+/// on insert, so they must be equal to the inserted ones after their parsing
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_json_operation_datasource_mysql() {
+    let mut new_settings = LeagueSettings {
+        id: Default::default(),
+        league: 1,
+        preferences: Json(Preferences {
+            theme: "dark".to_string(),
+            max_teams: 10,
+            languages: vec!["en".to_string(), "ko".to_string()],
+        }),
+        extra: Some(json!({ "region": "EU", "limits": { "roster": 7 } })),
+    };
+    new_settings
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let inserted = LeagueSettings::find_by_pk_datasource(&new_settings.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_settings);
+
+    let by_theme = LeagueSettings::select_query_datasource(MYSQL_DS)
+        .where_json(
+            LeagueSettingsField::preferences,
+            &JsonOp::Path("$.theme", Comp::Eq),
+            &"dark",
+        )
+        .and_json(
+            LeagueSettingsField::extra,
+            &JsonOp::Path("$.limits.roster", Comp::Eq),
+            &"7",
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_theme.contains(&new_settings));
+
+    new_settings.preferences.theme = "light".to_string();
+    new_settings.extra = None;
+    new_settings
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = LeagueSettings::find_by_pk_datasource(&new_settings.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated, new_settings);
+
+    new_settings
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
+
+/// The containment of MySQL is the `JSON_CONTAINS` function
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_json_containment_operation_datasource_mysql() {
+    let mut new_settings = LeagueSettings {
+        id: Default::default(),
+        league: 1,
+        preferences: Json(Preferences {
+            theme: "dark".to_string(),
+            max_teams: 10,
+            languages: vec!["en".to_string(), "ko".to_string()],
+        }),
+        extra: Some(json!({ "region": "EU", "limits": { "roster": 7 } })),
+    };
+    new_settings
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let region = json!({ "region": "EU" });
+    let by_region = LeagueSettings::select_query_datasource(MYSQL_DS)
+        .where_json(LeagueSettingsField::extra, &JsonOp::Contains, &region)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(by_region.contains(&new_settings));
+
+    let other_limits = json!({ "region": "EU", "limits": { "roster": 5 } });
+    let by_other_limits = LeagueSettings::select_query_datasource(MYSQL_DS)
+        .where_json(LeagueSettingsField::extra, &JsonOp::Contains, &other_limits)
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert!(!by_other_limits.contains(&new_settings));
+
+    new_settings
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+}
@@ -0,0 +1,90 @@
+//! Integration tests for the metrics of the statements launched by Canyon
+use canyon_sql::{
+    crud::{CrudOperations, Transaction},
+    metrics::{snapshot, Metrics, OperationKey},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league::*;
+
+/// The metrics of the `find_by_pk` operation of `League` on the given datasource
+fn find_by_pk_metrics(datasource: &str) -> Metrics {
+    snapshot()
+        .operations
+        .get(&OperationKey {
+            datasource: datasource.to_string(),
+            entity: "League".to_string(),
+            operation: "find_by_pk".to_string(),
+        })
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// The statements of the CRUD operations are accounted to the datasource and to the
+/// operation. The other tests may be running too, so only the growth is checked
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_metrics_operation() {
+    let before = find_by_pk_metrics(PSQL_DS);
+    League::find_by_pk(&1).await.expect("Request error");
+    let after = find_by_pk_metrics(PSQL_DS);
+
+    assert!(after.queries > before.queries);
+    assert!(after.rows > before.rows);
+    assert!(after.latency.count > before.latency.count);
+    assert!(after.connection_wait.count > before.connection_wait.count);
+    assert!(snapshot().datasources[PSQL_DS].queries >= after.queries);
+}
+
+/// The failed statements are accounted by the class of their error
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_metrics_error_operation() {
+    let syntax_errors = |metrics: Option<&Metrics>| {
+        metrics
+            .and_then(|metrics| metrics.errors.get("sqlstate:42").copied())
+            .unwrap_or_default()
+    };
+    let before = syntax_errors(snapshot().datasources.get(PSQL_DS));
+
+    League::query("SELECT * FROM a_table_that_does_not_exist", [], PSQL_DS)
+        .await
+        .err()
+        .expect("The table doesn't exist");
+
+    assert!(syntax_errors(snapshot().datasources.get(PSQL_DS)) > before);
+}
+
+/// Same as the metrics test, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_metrics_datasource_mssql_operation() {
+    let before = find_by_pk_metrics(SQL_SERVER_DS);
+    League::find_by_pk_datasource(&27, SQL_SERVER_DS)
+        .await
+        .expect("Request error");
+    let after = find_by_pk_metrics(SQL_SERVER_DS);
+
+    assert!(after.queries > before.queries);
+    assert!(after.latency.count > before.latency.count);
+}
+
+/// Same as the metrics test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_metrics_datasource_mysql_operation() {
+    let before = find_by_pk_metrics(MYSQL_DS);
+    League::find_by_pk_datasource(&27, MYSQL_DS)
+        .await
+        .expect("Request error");
+    let after = find_by_pk_metrics(MYSQL_DS);
+
+    assert!(after.queries > before.queries);
+    assert!(after.latency.count > before.latency.count);
+}
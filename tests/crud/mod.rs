@@ -1,10 +1,29 @@
 #![allow(unused_imports)]
 
+pub mod array_operations;
+pub mod blob_operations;
+pub mod composite_key_operations;
+pub mod custom_type_operations;
+pub mod decimal_operations;
 pub mod delete_operations;
+pub mod enum_operations;
+pub mod explain_operations;
 pub mod foreign_key_operations;
+pub mod hook_operations;
 #[cfg(feature = "mssql")]
 pub mod init_mssql;
 pub mod insert_operations;
+pub mod integer_operations;
+pub mod json_operations;
+pub mod metrics_operations;
+pub mod named_params_operations;
 pub mod querybuilder_operations;
+pub mod raw_query_operations;
 pub mod select_operations;
+pub mod slow_query_operations;
+pub mod soft_delete_operations;
+pub mod statement_cache_operations;
+pub mod timestamp_operations;
 pub mod update_operations;
+pub mod uuid_operations;
+pub mod validation_operations;
@@ -0,0 +1,79 @@
+//! Integration tests for the raw SQL statements launched with `:name` placeholders,
+//! through the `query_named` and `execute_named` functions of the `Transaction` trait
+use canyon_sql::{crud::Transaction, query::params};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league::*;
+
+/// The named placeholders are bound to their values, reusing the same value for every
+/// appearance of a name, and ignoring the ones written inside literals and comments
+const NAMED_STMT: &str = "SELECT * FROM league \
+    WHERE (id = :id OR slug = :slug OR (id = :id AND slug <> :slug)) AND name <> ':id' -- :missing\n\
+    ORDER BY id";
+
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_query_named_operation() {
+    let leagues: Vec<League> =
+        League::query_named(NAMED_STMT, params! { "id" => &1_i32, "slug" => &"lla" }, "")
+            .await
+            .expect("Request error")
+            .into_results::<League>()
+            .expect("Failed to map the rows");
+
+    assert_eq!(leagues.iter().map(|l| l.id).collect::<Vec<_>>(), vec![1, 3]);
+}
+
+/// A named placeholder without value is refused before reaching the database
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_query_named_missing_param_operation() {
+    let err = League::query_named(NAMED_STMT, params! { "id" => &1_i32 }, "")
+        .await
+        .err()
+        .expect("A named parameter without value must fail");
+    assert_eq!(
+        err.downcast_ref::<std::io::Error>()
+            .expect("Not an io error")
+            .kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+/// Same as the named query test, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_query_named_datasource_mssql_operation() {
+    let leagues: Vec<League> = League::query_named(
+        NAMED_STMT,
+        params! { "id" => &1_i32, "slug" => &"lla" },
+        SQL_SERVER_DS,
+    )
+    .await
+    .expect("Request error")
+    .into_results::<League>()
+    .expect("Failed to map the rows");
+
+    assert_eq!(leagues.iter().map(|l| l.id).collect::<Vec<_>>(), vec![1, 3]);
+}
+
+/// Same as the named query test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_query_named_datasource_mysql_operation() {
+    let leagues: Vec<League> = League::query_named(
+        NAMED_STMT,
+        params! { "id" => &1_i32, "slug" => &"lla" },
+        MYSQL_DS,
+    )
+    .await
+    .expect("Request error")
+    .into_results::<League>()
+    .expect("Failed to map the rows");
+
+    assert_eq!(leagues.iter().map(|l| l.id).collect::<Vec<_>>(), vec![1, 3]);
+}
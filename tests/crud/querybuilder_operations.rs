@@ -40,6 +40,53 @@ fn test_generated_sql_by_the_select_querybuilder() {
     )
 }
 
+/// Renders the SQL statement generated by the QueryBuilder with the values of
+/// its parameters written as literals of the targeted database
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_debug_sql_of_the_select_querybuilder() {
+    let mut select = League::select_query();
+    select
+        .r#where(LeagueFieldValue::id(&7), Comp::Gt)
+        .and(LeagueFieldValue::name(&"KOREA's"), Comp::Eq);
+
+    assert_eq!(format!("{:?}", select.params()), r#"[7, "KOREA's"]"#);
+    assert_eq!(
+        select.to_debug_sql(),
+        "SELECT * FROM league WHERE id > 7 AND name = 'KOREA''s'"
+    );
+}
+
+/// Same as the above debug SQL test, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_debug_sql_of_the_select_querybuilder_datasource_mssql() {
+    let mut select = League::select_query_datasource(SQL_SERVER_DS);
+    select
+        .r#where(LeagueFieldValue::id(&7), Comp::Gt)
+        .and(LeagueFieldValue::name(&"KOREA's"), Comp::Eq);
+
+    assert_eq!(
+        select.to_debug_sql(),
+        "SELECT * FROM league WHERE id > 7 AND name = N'KOREA''s'"
+    );
+}
+
+/// Same as the above debug SQL test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_debug_sql_of_the_select_querybuilder_datasource_mysql() {
+    let mut select = League::select_query_datasource(MYSQL_DS);
+    select
+        .r#where(LeagueFieldValue::id(&7), Comp::Gt)
+        .and(LeagueFieldValue::name(&"KOREA\\'s"), Comp::Eq);
+
+    assert_eq!(
+        select.to_debug_sql(),
+        "SELECT * FROM league WHERE id > 7 AND name = 'KOREA\\\\''s'"
+    );
+}
+
 /// Builds a new SQL statement for retrieves entities of the `T` type, filtered
 /// with the parameters that modifies the base SQL to SELECT * FROM <entity>
 #[cfg(feature = "postgres")]
@@ -376,6 +423,216 @@ fn test_crud_delete_with_querybuilder_datasource_mysql() {
         .is_empty());
 }
 
+/// Updates entries with the QueryBuilder, retrieving the updated rows
+/// in the same round trip
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_with_querybuilder_returning() {
+    let updated_players = Player::update_query()
+        .set(&[(PlayerField::summoner_name, "Updated with returning")])
+        .r#where(PlayerFieldValue::id(&20), Comp::Gt)
+        .and(PlayerFieldValue::id(&23), Comp::Lt)
+        .returning()
+        .query()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    assert_eq!(updated_players.len(), 2);
+    updated_players
+        .iter()
+        .for_each(|player| assert_eq!(player.summoner_name, "Updated with returning"));
+}
+
+/// Same as above, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_with_querybuilder_returning_datasource_mssql() {
+    let updated_players = Player::update_query_datasource(SQL_SERVER_DS)
+        .set(&[(PlayerField::summoner_name, "Updated with returning")])
+        .r#where(PlayerFieldValue::id(&20), Comp::Gt)
+        .and(PlayerFieldValue::id(&23), Comp::Lt)
+        .returning()
+        .query()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    assert_eq!(updated_players.len(), 2);
+    updated_players
+        .iter()
+        .for_each(|player| assert_eq!(player.summoner_name, "Updated with returning"));
+}
+
+/// Same as above, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_with_querybuilder_returning_datasource_mysql() {
+    let updated_players = Player::update_query_datasource(MYSQL_DS)
+        .set(&[(PlayerField::summoner_name, "Updated with returning")])
+        .r#where(PlayerFieldValue::id(&20), Comp::Gt)
+        .and(PlayerFieldValue::id(&23), Comp::Lt)
+        .returning()
+        .query()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    assert_eq!(updated_players.len(), 2);
+    updated_players
+        .iter()
+        .for_each(|player| assert_eq!(player.summoner_name, "Updated with returning"));
+}
+
+/// MySQL gives back the updated rows by their primary keys, so the ones that
+/// stop matching the filters after the update are retrieved too
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_with_querybuilder_returning_unmatched_datasource_mysql() {
+    Player::update_query_datasource(MYSQL_DS)
+        .set(&[(PlayerField::summoner_name, "Before returning")])
+        .r#where(PlayerFieldValue::id(&60), Comp::Gt)
+        .and(PlayerFieldValue::id(&63), Comp::Lt)
+        .execute()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    let updated_players = Player::update_query_datasource(MYSQL_DS)
+        .set(&[(PlayerField::summoner_name, "After returning")])
+        .r#where(
+            PlayerFieldValue::summoner_name(&"Before returning"),
+            Comp::Eq,
+        )
+        .returning()
+        .query()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    assert_eq!(updated_players.len(), 2);
+    updated_players
+        .iter()
+        .for_each(|player| assert_eq!(player.summoner_name, "After returning"));
+}
+
+/// Updates entries with the QueryBuilder, retrieving only the number
+/// of updated rows
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_with_querybuilder_execute() {
+    let updated_rows = Player::update_query()
+        .set(&[(PlayerField::first_name, "Updated with execute")])
+        .r#where(PlayerFieldValue::id(&30), Comp::Gt)
+        .and(PlayerFieldValue::id(&34), Comp::Lt)
+        .execute()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    assert_eq!(updated_rows, 3);
+}
+
+/// Same as above, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_with_querybuilder_execute_datasource_mssql() {
+    let updated_rows = Player::update_query_datasource(SQL_SERVER_DS)
+        .set(&[(PlayerField::first_name, "Updated with execute")])
+        .r#where(PlayerFieldValue::id(&30), Comp::Gt)
+        .and(PlayerFieldValue::id(&34), Comp::Lt)
+        .execute()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    assert_eq!(updated_rows, 3);
+}
+
+/// Same as above, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_with_querybuilder_execute_datasource_mysql() {
+    let updated_rows = Player::update_query_datasource(MYSQL_DS)
+        .set(&[(PlayerField::first_name, "Updated with execute")])
+        .r#where(PlayerFieldValue::id(&30), Comp::Gt)
+        .and(PlayerFieldValue::id(&34), Comp::Lt)
+        .execute()
+        .await
+        .expect("Failed to update records with the querybuilder");
+
+    assert_eq!(updated_rows, 3);
+}
+
+/// Deleting entries out of the range of the existing ones affects no rows
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_delete_with_querybuilder_execute() {
+    let deleted_rows = Player::delete_query()
+        .r#where(PlayerFieldValue::id(&1000), Comp::Gt)
+        .execute()
+        .await
+        .expect("Error connecting with the database on the delete operation");
+
+    assert_eq!(deleted_rows, 0);
+}
+
+/// Deletes entries with the QueryBuilder, retrieving the deleted rows
+///
+/// Note if the database is persisted, the rows may be already deleted, so
+/// we only check that every retrieved row is in the filtered range
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_delete_with_querybuilder_returning() {
+    let deleted_players = Player::delete_query()
+        .r#where(PlayerFieldValue::id(&95), Comp::Gt)
+        .and(PlayerFieldValue::id(&98), Comp::Lt)
+        .returning()
+        .query()
+        .await
+        .expect("Error connecting with the database on the delete operation");
+
+    assert!(deleted_players.len() <= 2);
+    deleted_players
+        .iter()
+        .for_each(|player| assert!(player.id > 95 && player.id < 98));
+    assert!(Player::select_query()
+        .r#where(PlayerFieldValue::id(&96), Comp::Eq)
+        .query()
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+/// Same as above, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_delete_with_querybuilder_returning_datasource_mssql() {
+    let deleted_players = Player::delete_query_datasource(SQL_SERVER_DS)
+        .r#where(PlayerFieldValue::id(&95), Comp::Gt)
+        .and(PlayerFieldValue::id(&98), Comp::Lt)
+        .returning()
+        .query()
+        .await
+        .expect("Error connecting with the database on the delete operation");
+
+    assert!(deleted_players.len() <= 2);
+    deleted_players
+        .iter()
+        .for_each(|player| assert!(player.id > 95 && player.id < 98));
+}
+
+/// Same as above, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_delete_with_querybuilder_returning_datasource_mysql() {
+    let deleted_players = Player::delete_query_datasource(MYSQL_DS)
+        .r#where(PlayerFieldValue::id(&95), Comp::Gt)
+        .and(PlayerFieldValue::id(&98), Comp::Lt)
+        .returning()
+        .query()
+        .await
+        .expect("Error connecting with the database on the delete operation");
+
+    assert!(deleted_players.len() <= 2);
+    deleted_players
+        .iter()
+        .for_each(|player| assert!(player.id > 95 && player.id < 98));
+}
+
 /// Tests for the generated SQL query after use the
 /// WHERE clause
 #[canyon_sql::macros::canyon_tokio_test]
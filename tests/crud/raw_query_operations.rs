@@ -0,0 +1,56 @@
+//! Integration tests for the raw SQL statements launched through the `Transaction` trait,
+//! that are adapted to the syntax of every database before being sent
+use canyon_sql::crud::{bounds::QueryParameter, Transaction};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league::*;
+
+/// The placeholders and the quotes written inside the literals and the comments
+/// of the statement must reach the database untouched
+const RAW_STMT: &str = "SELECT * FROM league \
+    WHERE id = $1 AND name <> 'it''s $1, \"quoted\" and `more`' -- $2\n\
+    ORDER BY id";
+
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_raw_query_with_literals_operation() {
+    let leagues: Vec<League> = League::query(RAW_STMT, [&1_i32 as &dyn QueryParameter<'_>], "")
+        .await
+        .expect("Request error")
+        .into_results::<League>()
+        .expect("Failed to map the rows");
+
+    assert_eq!(leagues.iter().map(|l| l.id).collect::<Vec<_>>(), vec![1]);
+}
+
+/// Same as the raw query test, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_raw_query_with_literals_datasource_mssql_operation() {
+    let leagues: Vec<League> =
+        League::query(RAW_STMT, [&1_i32 as &dyn QueryParameter<'_>], SQL_SERVER_DS)
+            .await
+            .expect("Request error")
+            .into_results::<League>()
+            .expect("Failed to map the rows");
+
+    assert_eq!(leagues.iter().map(|l| l.id).collect::<Vec<_>>(), vec![1]);
+}
+
+/// Same as the raw query test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_raw_query_with_literals_datasource_mysql_operation() {
+    let leagues: Vec<League> =
+        League::query(RAW_STMT, [&1_i32 as &dyn QueryParameter<'_>], MYSQL_DS)
+            .await
+            .expect("Request error")
+            .into_results::<League>()
+            .expect("Failed to map the rows");
+
+    assert_eq!(leagues.iter().map(|l| l.id).collect::<Vec<_>>(), vec![1]);
+}
@@ -141,6 +141,78 @@ fn test_crud_find_by_pk_datasource_mysql() {
     );
 }
 
+/// Tests the behaviour of a SELECT * FROM {table_name} WHERE <pk> IN (<pk_values>), that looks
+/// for many primary keys with a single statement, leaving out the values that aren't found
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_by_pks() {
+    let ids = [1, 27, 30, -1];
+    let mut leagues = League::find_by_pks(&ids)
+        .await
+        .expect("Failed the find_by_pks operation");
+    leagues.sort_by_key(|league| league.id);
+    assert_eq!(
+        leagues.iter().map(|league| league.id).collect::<Vec<_>>(),
+        vec![1, 27, 30]
+    );
+
+    let leagues_by_id = League::find_by_pks_map(&ids)
+        .await
+        .expect("Failed the find_by_pks_map operation");
+    assert_eq!(leagues_by_id.len(), 3);
+    assert_eq!(leagues_by_id[&27].slug, "college_championship");
+
+    assert!(League::find_by_pks::<i32>(&[]).await.unwrap().is_empty());
+}
+
+/// Same as the above test, but with the specified datasource. The keys exceeds the limit of
+/// parameters of SqlServer, so they're looked up with more than one statement
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_by_pks_datasource_mssql() {
+    let ids = (1..=2_500).collect::<Vec<i32>>();
+    let leagues = League::find_by_pks_datasource(&ids, SQL_SERVER_DS)
+        .await
+        .expect("Failed the find_by_pks operation");
+    let all_leagues = League::find_all_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the find_all operation");
+    assert_eq!(
+        leagues.len(),
+        all_leagues
+            .iter()
+            .filter(|league| (1..=2_500).contains(&league.id))
+            .count()
+    );
+
+    let leagues_by_id = League::find_by_pks_map_datasource(&[27, -1], SQL_SERVER_DS)
+        .await
+        .expect("Failed the find_by_pks_map operation");
+    assert_eq!(leagues_by_id.len(), 1);
+    assert_eq!(leagues_by_id[&27].slug, "college_championship");
+}
+
+/// Same as the above test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_by_pks_datasource_mysql() {
+    let ids = [1, 27, 30, -1];
+    let mut leagues = League::find_by_pks_datasource(&ids, MYSQL_DS)
+        .await
+        .expect("Failed the find_by_pks operation");
+    leagues.sort_by_key(|league| league.id);
+    assert_eq!(
+        leagues.iter().map(|league| league.id).collect::<Vec<_>>(),
+        vec![1, 27, 30]
+    );
+
+    let leagues_by_id = League::find_by_pks_map_datasource(&ids, MYSQL_DS)
+        .await
+        .expect("Failed the find_by_pks_map operation");
+    assert_eq!(leagues_by_id.len(), 3);
+    assert_eq!(leagues_by_id[&27].slug, "college_championship");
+}
+
 /// Counts how many rows contains an entity on the target database.
 #[cfg(feature = "postgres")]
 #[canyon_sql::macros::canyon_tokio_test]
@@ -0,0 +1,94 @@
+//! Integration tests for the report of the statements that exceed the
+//! `slow_query_ms` threshold of their datasource
+use std::sync::Mutex;
+
+use canyon_sql::crud::{
+    slow_query::{set_slow_query_handler, SlowQuery},
+    Transaction,
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league::*;
+
+/// The slow statements reported to the handler. Every test looks only for its
+/// own statement, given that the handler is shared by all of them
+static SLOW_QUERIES: Mutex<Vec<SlowQuery>> = Mutex::new(Vec::new());
+
+fn reported(sql: &str) -> Option<SlowQuery> {
+    set_slow_query_handler(|slow_query| SLOW_QUERIES.lock().unwrap().push(slow_query.clone()));
+    SLOW_QUERIES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|slow_query| slow_query.sql == sql)
+        .cloned()
+}
+
+/// A statement longer than the threshold is reported along with its plan
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_slow_query_operation() {
+    const SLOW_STMT: &str = "SELECT pg_sleep(1.1)";
+    reported(SLOW_STMT);
+
+    League::query(SLOW_STMT, [], "")
+        .await
+        .expect("Request error");
+
+    let slow_query = reported(SLOW_STMT).expect("The statement must be reported");
+    assert!(slow_query.duration.as_millis() >= 1000);
+    assert!(slow_query
+        .plan
+        .expect("The statement must be explained")
+        .contains("\"Plan\""));
+}
+
+/// The statements faster than the threshold aren't reported
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_fast_query_operation() {
+    const FAST_STMT: &str = "SELECT * FROM league WHERE id = $1";
+    League::query(FAST_STMT, [&1_i32 as _], "")
+        .await
+        .expect("Request error");
+
+    assert_eq!(reported(FAST_STMT), None);
+}
+
+/// Same as the slow query test, but with the specified datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_slow_query_datasource_mssql_operation() {
+    const SLOW_STMT: &str = "WAITFOR DELAY '00:00:01.100'";
+    reported(SLOW_STMT);
+
+    League::execute(SLOW_STMT, [], SQL_SERVER_DS)
+        .await
+        .expect("Request error");
+
+    let slow_query = reported(SLOW_STMT).expect("The statement must be reported");
+    assert!(slow_query.duration.as_millis() >= 1000);
+}
+
+/// Same as the slow query test, but with the specified datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_slow_query_datasource_mysql_operation() {
+    const SLOW_STMT: &str = "SELECT SLEEP(1.1)";
+    reported(SLOW_STMT);
+
+    League::query(SLOW_STMT, [], MYSQL_DS)
+        .await
+        .expect("Request error");
+
+    let slow_query = reported(SLOW_STMT).expect("The statement must be reported");
+    assert!(slow_query.duration.as_millis() >= 1000);
+    assert!(slow_query
+        .plan
+        .expect("The statement must be explained")
+        .contains("query_block"));
+}
@@ -0,0 +1,374 @@
+//! Integration tests for the CRUD operations available in `Canyon` over the entities
+//! declared with the `soft_delete` argument of the `canyon_entity` macro
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{operators::Comp, ops::QueryBuilder},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::announcement::*;
+
+/// Deleting an instance of an entity declared with the `soft_delete` argument
+/// just marks the row as deleted, so it can be restored later, or removed
+/// for real with `force_delete`.
+///
+/// The rows are marked as deleted with the `TIMESTAMP` of Postgres
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_soft_delete_method_operation() {
+    let mut new_announcement = Announcement {
+        id: Default::default(),
+        league: 1,
+        title: "Some new announcement".to_string(),
+    };
+    new_announcement
+        .insert()
+        .await
+        .expect("Failed insert operation");
+
+    new_announcement
+        .delete()
+        .await
+        .expect("Failed to delete the operation");
+
+    // The deleted row is not found anymore by the generated queries
+    assert_eq!(
+        Announcement::find_by_pk(&new_announcement.id)
+            .await
+            .expect("Request error"),
+        None
+    );
+    assert!(!Announcement::find_all()
+        .await
+        .expect("Request error")
+        .contains(&new_announcement));
+
+    // But it's still on the table
+    let deleted: Vec<Announcement> = Announcement::select_query()
+        .only_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert_eq!(deleted, vec![new_announcement.clone()]);
+
+    new_announcement
+        .restore()
+        .await
+        .expect("Failed to restore the deleted record");
+    assert_eq!(
+        Announcement::find_by_pk(&new_announcement.id)
+            .await
+            .expect("Request error"),
+        Some(new_announcement.clone())
+    );
+
+    new_announcement
+        .force_delete()
+        .await
+        .expect("Failed to force the delete of the record");
+    let with_deleted: Vec<Announcement> = Announcement::select_query()
+        .with_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert!(with_deleted.is_empty());
+}
+
+/// The `delete_query` of an entity declared with the `soft_delete` argument
+/// also just marks the matching rows as deleted, and leaves untouched the ones
+/// already marked. It's an `UPDATE` with the filters of the `DELETE`
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_soft_delete_querybuilder_operation() {
+    let mut new_announcement = Announcement {
+        id: Default::default(),
+        league: 1,
+        title: "Some new announcement".to_string(),
+    };
+    new_announcement
+        .insert()
+        .await
+        .expect("Failed insert operation");
+
+    let deleted_rows = Announcement::delete_query()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed to delete the record with the querybuilder");
+    assert_eq!(deleted_rows, 1);
+
+    let deleted_rows = Announcement::delete_query()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed to delete the record with the querybuilder");
+    assert_eq!(deleted_rows, 0);
+
+    let deleted: Vec<Announcement> = Announcement::select_query()
+        .only_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert_eq!(deleted, vec![new_announcement.clone()]);
+
+    new_announcement
+        .force_delete()
+        .await
+        .expect("Failed to force the delete of the record");
+}
+
+/// Deleting twice an instance of a soft deletable entity returns a
+/// [`std::io::ErrorKind::NotFound`] error, as any other delete
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_soft_delete_already_deleted_operation() {
+    let mut new_announcement = Announcement {
+        id: Default::default(),
+        league: 1,
+        title: "Some new announcement".to_string(),
+    };
+    new_announcement
+        .insert()
+        .await
+        .expect("Failed insert operation");
+    new_announcement
+        .delete()
+        .await
+        .expect("Failed to delete the operation");
+
+    let not_found_err = new_announcement
+        .delete()
+        .await
+        .expect_err("Deleting an already deleted record must fail");
+    assert_eq!(
+        not_found_err
+            .downcast_ref::<std::io::Error>()
+            .expect("Not an io error")
+            .kind(),
+        std::io::ErrorKind::NotFound
+    );
+
+    new_announcement
+        .force_delete()
+        .await
+        .expect("Failed to force the delete of the record");
+}
+
+/// The rows are marked as deleted with the `DATETIME2` of SqlServer
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_soft_delete_datasource_mssql_method_operation() {
+    let mut new_announcement = Announcement {
+        id: Default::default(),
+        league: 1,
+        title: "Some new announcement".to_string(),
+    };
+    new_announcement
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    new_announcement
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed to delete the operation");
+
+    // The deleted row is not found anymore by the generated queries
+    assert_eq!(
+        Announcement::find_by_pk_datasource(&new_announcement.id, SQL_SERVER_DS)
+            .await
+            .expect("Request error"),
+        None
+    );
+    assert!(!Announcement::find_all_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .contains(&new_announcement));
+
+    // But it's still on the table
+    let deleted: Vec<Announcement> = Announcement::select_query_datasource(SQL_SERVER_DS)
+        .only_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert_eq!(deleted, vec![new_announcement.clone()]);
+
+    new_announcement
+        .restore_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed to restore the deleted record");
+    assert_eq!(
+        Announcement::find_by_pk_datasource(&new_announcement.id, SQL_SERVER_DS)
+            .await
+            .expect("Request error"),
+        Some(new_announcement.clone())
+    );
+
+    new_announcement
+        .force_delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed to force the delete of the record");
+    let with_deleted: Vec<Announcement> = Announcement::select_query_datasource(SQL_SERVER_DS)
+        .with_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert!(with_deleted.is_empty());
+}
+
+/// The soft delete through the querybuilder, with the `@P` placeholders of SqlServer
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_soft_delete_querybuilder_datasource_mssql_operation() {
+    let mut new_announcement = Announcement {
+        id: Default::default(),
+        league: 1,
+        title: "Some new announcement".to_string(),
+    };
+    new_announcement
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let deleted_rows = Announcement::delete_query_datasource(SQL_SERVER_DS)
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed to delete the record with the querybuilder");
+    assert_eq!(deleted_rows, 1);
+
+    let deleted_rows = Announcement::delete_query_datasource(SQL_SERVER_DS)
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed to delete the record with the querybuilder");
+    assert_eq!(deleted_rows, 0);
+
+    let deleted: Vec<Announcement> = Announcement::select_query_datasource(SQL_SERVER_DS)
+        .only_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert_eq!(deleted, vec![new_announcement.clone()]);
+
+    new_announcement
+        .force_delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed to force the delete of the record");
+}
+
+/// The rows are marked as deleted with the `DATETIME` of MySQL
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_soft_delete_datasource_mysql_method_operation() {
+    let mut new_announcement = Announcement {
+        id: Default::default(),
+        league: 1,
+        title: "Some new announcement".to_string(),
+    };
+    new_announcement
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    new_announcement
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed to delete the operation");
+
+    // The deleted row is not found anymore by the generated queries
+    assert_eq!(
+        Announcement::find_by_pk_datasource(&new_announcement.id, MYSQL_DS)
+            .await
+            .expect("Request error"),
+        None
+    );
+    assert!(!Announcement::find_all_datasource(MYSQL_DS)
+        .await
+        .expect("Request error")
+        .contains(&new_announcement));
+
+    // But it's still on the table
+    let deleted: Vec<Announcement> = Announcement::select_query_datasource(MYSQL_DS)
+        .only_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert_eq!(deleted, vec![new_announcement.clone()]);
+
+    new_announcement
+        .restore_datasource(MYSQL_DS)
+        .await
+        .expect("Failed to restore the deleted record");
+    assert_eq!(
+        Announcement::find_by_pk_datasource(&new_announcement.id, MYSQL_DS)
+            .await
+            .expect("Request error"),
+        Some(new_announcement.clone())
+    );
+
+    new_announcement
+        .force_delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed to force the delete of the record");
+    let with_deleted: Vec<Announcement> = Announcement::select_query_datasource(MYSQL_DS)
+        .with_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert!(with_deleted.is_empty());
+}
+
+/// The soft delete through the querybuilder, with the `?` placeholders of MySQL
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_soft_delete_querybuilder_datasource_mysql_operation() {
+    let mut new_announcement = Announcement {
+        id: Default::default(),
+        league: 1,
+        title: "Some new announcement".to_string(),
+    };
+    new_announcement
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    let deleted_rows = Announcement::delete_query_datasource(MYSQL_DS)
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed to delete the record with the querybuilder");
+    assert_eq!(deleted_rows, 1);
+
+    let deleted_rows = Announcement::delete_query_datasource(MYSQL_DS)
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed to delete the record with the querybuilder");
+    assert_eq!(deleted_rows, 0);
+
+    let deleted: Vec<Announcement> = Announcement::select_query_datasource(MYSQL_DS)
+        .only_deleted()
+        .r#where(AnnouncementFieldValue::id(&new_announcement.id), Comp::Eq)
+        .query()
+        .await
+        .expect("Request error");
+    assert_eq!(deleted, vec![new_announcement.clone()]);
+
+    new_announcement
+        .force_delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed to force the delete of the record");
+}
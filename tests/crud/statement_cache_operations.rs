@@ -0,0 +1,61 @@
+//! Integration tests for the prepared statements cache of the connections
+use canyon_sql::{connection::statement_cache_stats, crud::CrudOperations};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league::*;
+
+/// Launching the same generated statement again reuses its prepared statement
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_statement_cache_operation() {
+    League::find_by_pk(&1).await.expect("Request error");
+    let before = statement_cache_stats("")
+        .await
+        .expect("PostgreSQL connections cache their statements");
+
+    League::find_by_pk(&2).await.expect("Request error");
+    let after = statement_cache_stats("")
+        .await
+        .expect("PostgreSQL connections cache their statements");
+
+    // The other tests may be sharing the connection, so only this lookup is guaranteed
+    assert!(after.hits > before.hits);
+    assert!(after.size <= after.capacity);
+}
+
+/// SqlServer connections doesn't cache any statement
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_statement_cache_datasource_mssql_operation() {
+    League::find_by_pk_datasource(&1, SQL_SERVER_DS)
+        .await
+        .expect("Request error");
+    assert_eq!(statement_cache_stats(SQL_SERVER_DS).await, None);
+}
+
+/// Every connection of the MySQL pool caches its own statements, so the lookup
+/// may miss when the pool hands over another connection
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_statement_cache_datasource_mysql_operation() {
+    League::find_by_pk_datasource(&1, MYSQL_DS)
+        .await
+        .expect("Request error");
+    let before = statement_cache_stats(MYSQL_DS)
+        .await
+        .expect("MySQL connections cache their statements");
+
+    League::find_by_pk_datasource(&2, MYSQL_DS)
+        .await
+        .expect("Request error");
+    let after = statement_cache_stats(MYSQL_DS)
+        .await
+        .expect("MySQL connections cache their statements");
+
+    assert!(after.hits + after.misses > before.hits + before.misses);
+    assert!(after.size <= after.capacity);
+}
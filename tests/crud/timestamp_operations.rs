@@ -0,0 +1,217 @@
+//! Integration tests for the moments managed by `Canyon` over the fields
+//! annotated with `#[created_at]` and `#[updated_at]`
+use canyon_sql::{
+    crud::CrudOperations,
+    date_time::{NaiveDateTime, Utc},
+    query::{operators::Comp, ops::QueryBuilder},
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::article::*;
+
+/// Checks that a moment was taken in UTC during the current test, whatever the
+/// time zone of the database is
+#[cfg(any(feature = "postgres", feature = "mssql", feature = "mysql"))]
+fn assert_recent_utc(moment: NaiveDateTime) {
+    let elapsed = Utc::now().naive_utc() - moment;
+    assert!(
+        elapsed.num_seconds().abs() < 60,
+        "{moment} isn't the current moment in UTC"
+    );
+}
+
+/// The `insert` and `update` operations over an entity with fields annotated with
+/// `#[created_at]` or `#[updated_at]` sets them to the current time, as does the
+/// update querybuilder with the current time of the database.
+///
+/// The querybuilder of Postgres takes the `CURRENT_TIMESTAMP` at the UTC time zone,
+/// since the moments are stored as a `TIMESTAMP` without time zone
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_timestamps_operation() {
+    let mut new_article = Article {
+        id: Default::default(),
+        league: 1,
+        title: "Some timestamped article".to_string(),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+    };
+    new_article.insert().await.expect("Failed insert operation");
+
+    // The insert fills the managed moments, writing them back into the instance
+    assert_recent_utc(new_article.created_at);
+    assert_recent_utc(new_article.updated_at);
+    let inserted = Article::find_by_pk(&new_article.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_article);
+
+    // The update operation only bumps the `#[updated_at]` column
+    let mut updt_candidate = inserted.clone();
+    updt_candidate.title = "Some updated timestamped article".to_string();
+    updt_candidate
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let updated = Article::find_by_pk(&new_article.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.created_at, inserted.created_at);
+    assert!(updated.updated_at >= inserted.updated_at);
+    // The instance takes the new moment of the update
+    assert_eq!(updt_candidate, updated);
+
+    // And so does the querybuilder, even if it isn't in the `SET` clause, with
+    // the current moment of the database in UTC
+    Article::update_query()
+        .set(&[(ArticleField::title, "Some timestamped article")])
+        .r#where(ArticleFieldValue::id(&new_article.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed the update operation");
+    let updated_by_querybuilder = Article::find_by_pk(&new_article.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated_by_querybuilder.created_at, inserted.created_at);
+    assert_ne!(updated_by_querybuilder.updated_at, updated.updated_at);
+    assert_recent_utc(updated_by_querybuilder.updated_at);
+
+    new_article
+        .delete()
+        .await
+        .expect("Failed to delete the record");
+}
+
+/// The querybuilder of SqlServer takes the `SYSUTCDATETIME()` for the `DATETIME2` moments
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_timestamps_datasource_mssql_operation() {
+    let mut new_article = Article {
+        id: Default::default(),
+        league: 1,
+        title: "Some timestamped article".to_string(),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+    };
+    new_article
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+
+    // The insert fills the managed moments, writing them back into the instance
+    assert_recent_utc(new_article.created_at);
+    assert_recent_utc(new_article.updated_at);
+    let inserted = Article::find_by_pk_datasource(&new_article.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_article);
+
+    // The update operation only bumps the `#[updated_at]` column
+    let mut updt_candidate = inserted.clone();
+    updt_candidate.title = "Some updated timestamped article".to_string();
+    updt_candidate
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Article::find_by_pk_datasource(&new_article.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.created_at, inserted.created_at);
+    assert!(updated.updated_at >= inserted.updated_at);
+    // The instance takes the new moment of the update
+    assert_eq!(updt_candidate, updated);
+
+    // And so does the querybuilder, even if it isn't in the `SET` clause, with
+    // the current moment of the database in UTC
+    Article::update_query_datasource(SQL_SERVER_DS)
+        .set(&[(ArticleField::title, "Some timestamped article")])
+        .r#where(ArticleFieldValue::id(&new_article.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed the update operation");
+    let updated_by_querybuilder = Article::find_by_pk_datasource(&new_article.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated_by_querybuilder.created_at, inserted.created_at);
+    assert_ne!(updated_by_querybuilder.updated_at, updated.updated_at);
+    assert_recent_utc(updated_by_querybuilder.updated_at);
+
+    new_article
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed to delete the record");
+}
+
+/// The querybuilder of MySQL takes the `UTC_TIMESTAMP(6)`, with the microseconds
+/// of the `DATETIME(6)` moments
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_timestamps_datasource_mysql_operation() {
+    let mut new_article = Article {
+        id: Default::default(),
+        league: 1,
+        title: "Some timestamped article".to_string(),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+    };
+    new_article
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+
+    // The insert fills the managed moments, writing them back into the instance
+    assert_recent_utc(new_article.created_at);
+    assert_recent_utc(new_article.updated_at);
+    let inserted = Article::find_by_pk_datasource(&new_article.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_article);
+
+    // The update operation only bumps the `#[updated_at]` column
+    let mut updt_candidate = inserted.clone();
+    updt_candidate.title = "Some updated timestamped article".to_string();
+    updt_candidate
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Article::find_by_pk_datasource(&new_article.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.created_at, inserted.created_at);
+    assert!(updated.updated_at >= inserted.updated_at);
+    // The instance takes the new moment of the update
+    assert_eq!(updt_candidate, updated);
+
+    // And so does the querybuilder, even if it isn't in the `SET` clause, with
+    // the current moment of the database in UTC
+    Article::update_query_datasource(MYSQL_DS)
+        .set(&[(ArticleField::title, "Some timestamped article")])
+        .r#where(ArticleFieldValue::id(&new_article.id), Comp::Eq)
+        .execute()
+        .await
+        .expect("Failed the update operation");
+    let updated_by_querybuilder = Article::find_by_pk_datasource(&new_article.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated_by_querybuilder.created_at, inserted.created_at);
+    assert_ne!(updated_by_querybuilder.updated_at, updated.updated_at);
+    assert_recent_utc(updated_by_querybuilder.updated_at);
+
+    new_article
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed to delete the record");
+}
@@ -1,7 +1,8 @@
+use crate::tests_models::bracket::*;
 use crate::tests_models::league::*;
 // Integration tests for the CRUD operations available in `Canyon` that
 /// generates and executes *UPDATE* statements
-use canyon_sql::crud::CrudOperations;
+use canyon_sql::crud::{CrudOperations, VersionConflictError};
 
 #[cfg(feature = "mysql")]
 use crate::constants::MYSQL_DS;
@@ -140,3 +141,167 @@ fn test_crud_update_datasource_mysql_method_operation() {
         .await
         .expect("Failed to restablish the initial value update operation");
 }
+
+/// The `update` operation over an entity with a `#[version]` field only succeeds
+/// if the instance holds the current version of the record, so the changes made by
+/// someone else can't be overwritten
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_versioned_entity_operation() {
+    let mut new_bracket = Bracket {
+        id: Default::default(),
+        tournament: 1,
+        stage: "Some versioned stage".to_string(),
+        version: Default::default(),
+    };
+    new_bracket.insert().await.expect("Failed insert operation");
+    // The insert operation initializes the version of the record
+    assert_eq!(new_bracket.version, 1);
+
+    // Two copies of the same record, as if they were retrieved by different clients
+    let mut first_copy = Bracket::find_by_pk(&new_bracket.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    let mut stale_copy = first_copy.clone();
+
+    first_copy.stage = "Some updated versioned stage".to_string();
+    first_copy
+        .update()
+        .await
+        .expect("Failed the update operation");
+
+    let updated = Bracket::find_by_pk(&new_bracket.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.stage, first_copy.stage);
+    assert_eq!(updated.version, 2);
+    // The updated instance follows the version of the record, so it can be updated again
+    assert_eq!(first_copy.version, 2);
+
+    // The other copy is outdated, so its changes must be rejected
+    stale_copy.stage = "Some lost update".to_string();
+    let conflict_err = stale_copy
+        .update()
+        .await
+        .expect_err("Updating an outdated instance must fail");
+    assert!(conflict_err
+        .downcast_ref::<VersionConflictError>()
+        .is_some());
+
+    new_bracket
+        .delete()
+        .await
+        .expect("Failed to delete the record");
+}
+
+/// Same as the above test, but with the specified datasource.
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_versioned_entity_datasource_mssql_operation() {
+    let mut new_bracket = Bracket {
+        id: Default::default(),
+        tournament: 1,
+        stage: "Some versioned stage".to_string(),
+        version: Default::default(),
+    };
+    new_bracket
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+    // The insert operation initializes the version of the record
+    assert_eq!(new_bracket.version, 1);
+
+    // Two copies of the same record, as if they were retrieved by different clients
+    let mut first_copy = Bracket::find_by_pk_datasource(&new_bracket.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    let mut stale_copy = first_copy.clone();
+
+    first_copy.stage = "Some updated versioned stage".to_string();
+    first_copy
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+
+    let updated = Bracket::find_by_pk_datasource(&new_bracket.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.stage, first_copy.stage);
+    assert_eq!(updated.version, 2);
+    // The updated instance follows the version of the record, so it can be updated again
+    assert_eq!(first_copy.version, 2);
+
+    // The other copy is outdated, so its changes must be rejected
+    stale_copy.stage = "Some lost update".to_string();
+    let conflict_err = stale_copy
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect_err("Updating an outdated instance must fail");
+    assert!(conflict_err
+        .downcast_ref::<VersionConflictError>()
+        .is_some());
+
+    new_bracket
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed to delete the record");
+}
+
+/// Same as the above test, but with the specified datasource.
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_versioned_entity_datasource_mysql_operation() {
+    let mut new_bracket = Bracket {
+        id: Default::default(),
+        tournament: 1,
+        stage: "Some versioned stage".to_string(),
+        version: Default::default(),
+    };
+    new_bracket
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+    // The insert operation initializes the version of the record
+    assert_eq!(new_bracket.version, 1);
+
+    // Two copies of the same record, as if they were retrieved by different clients
+    let mut first_copy = Bracket::find_by_pk_datasource(&new_bracket.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    let mut stale_copy = first_copy.clone();
+
+    first_copy.stage = "Some updated versioned stage".to_string();
+    first_copy
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+
+    let updated = Bracket::find_by_pk_datasource(&new_bracket.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.stage, first_copy.stage);
+    assert_eq!(updated.version, 2);
+    // The updated instance follows the version of the record, so it can be updated again
+    assert_eq!(first_copy.version, 2);
+
+    // The other copy is outdated, so its changes must be rejected
+    stale_copy.stage = "Some lost update".to_string();
+    let conflict_err = stale_copy
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect_err("Updating an outdated instance must fail");
+    assert!(conflict_err
+        .downcast_ref::<VersionConflictError>()
+        .is_some());
+
+    new_bracket
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed to delete the record");
+}
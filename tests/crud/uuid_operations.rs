@@ -0,0 +1,222 @@
+//! Integration tests for the entities with UUID columns, and with primary keys
+//! generated by Canyon with `#[primary_key(generate = "...")]`
+use canyon_sql::{
+    crud::CrudOperations,
+    query::{operators::Comp, ops::QueryBuilder},
+    uuid::Uuid,
+};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::broadcast::*;
+
+/// The `insert` operation generates a UUID v7 for the primary key, and the UUIDs are
+/// bound as parameters and read back from the rows, as the native `UUID` type of Postgres
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_uuid_operation() {
+    let mut new_broadcast = Broadcast {
+        id: Uuid::nil(),
+        league: 1,
+        channel: "lec-official".to_string(),
+        stream_key: Some(Uuid::new_v4()),
+    };
+    new_broadcast
+        .insert()
+        .await
+        .expect("Failed insert operation");
+    assert!(!new_broadcast.id.is_nil());
+    assert_eq!(new_broadcast.id.get_version_num(), 7);
+
+    let inserted = Broadcast::find_by_pk(&new_broadcast.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_broadcast);
+
+    let by_stream_key = Broadcast::select_query()
+        .r#where(
+            BroadcastFieldValue::stream_key(&new_broadcast.stream_key),
+            Comp::Eq,
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(by_stream_key, vec![new_broadcast.clone()]);
+
+    new_broadcast.stream_key = None;
+    new_broadcast
+        .update()
+        .await
+        .expect("Failed the update operation");
+    let updated = Broadcast::find_by_pk(&new_broadcast.id)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.stream_key, None);
+
+    new_broadcast
+        .delete()
+        .await
+        .expect("Failed the delete operation");
+    assert_eq!(
+        Broadcast::find_by_pk(&new_broadcast.id)
+            .await
+            .expect("Request error"),
+        None
+    );
+}
+
+/// The UUIDs travels as `UNIQUEIDENTIFIER`, which SqlServer stores with its own byte
+/// order, so they must come back unchanged from the driver
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_uuid_operation_datasource_mssql() {
+    let mut new_broadcast = Broadcast {
+        id: Uuid::nil(),
+        league: 1,
+        channel: "lec-official".to_string(),
+        stream_key: Some(Uuid::new_v4()),
+    };
+    new_broadcast
+        .insert_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed insert operation");
+    assert!(!new_broadcast.id.is_nil());
+    assert_eq!(new_broadcast.id.get_version_num(), 7);
+
+    let inserted = Broadcast::find_by_pk_datasource(&new_broadcast.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_broadcast);
+
+    let by_stream_key = Broadcast::select_query_datasource(SQL_SERVER_DS)
+        .r#where(
+            BroadcastFieldValue::stream_key(&new_broadcast.stream_key),
+            Comp::Eq,
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(by_stream_key, vec![new_broadcast.clone()]);
+
+    new_broadcast.stream_key = None;
+    new_broadcast
+        .update_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Broadcast::find_by_pk_datasource(&new_broadcast.id, SQL_SERVER_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.stream_key, None);
+
+    new_broadcast
+        .delete_datasource(SQL_SERVER_DS)
+        .await
+        .expect("Failed the delete operation");
+    assert_eq!(
+        Broadcast::find_by_pk_datasource(&new_broadcast.id, SQL_SERVER_DS)
+            .await
+            .expect("Request error"),
+        None
+    );
+}
+
+/// MySQL lacks a UUID type, so they travels as the 16 bytes of a `BINARY(16)` column
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_uuid_operation_datasource_mysql() {
+    let mut new_broadcast = Broadcast {
+        id: Uuid::nil(),
+        league: 1,
+        channel: "lec-official".to_string(),
+        stream_key: Some(Uuid::new_v4()),
+    };
+    new_broadcast
+        .insert_datasource(MYSQL_DS)
+        .await
+        .expect("Failed insert operation");
+    assert!(!new_broadcast.id.is_nil());
+    assert_eq!(new_broadcast.id.get_version_num(), 7);
+
+    let inserted = Broadcast::find_by_pk_datasource(&new_broadcast.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(inserted, new_broadcast);
+
+    let by_stream_key = Broadcast::select_query_datasource(MYSQL_DS)
+        .r#where(
+            BroadcastFieldValue::stream_key(&new_broadcast.stream_key),
+            Comp::Eq,
+        )
+        .query()
+        .await
+        .expect("Failed the select operation");
+    assert_eq!(by_stream_key, vec![new_broadcast.clone()]);
+
+    new_broadcast.stream_key = None;
+    new_broadcast
+        .update_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the update operation");
+    let updated = Broadcast::find_by_pk_datasource(&new_broadcast.id, MYSQL_DS)
+        .await
+        .expect("Request error")
+        .expect("No entity found for the primary key value passed in");
+    assert_eq!(updated.stream_key, None);
+
+    new_broadcast
+        .delete_datasource(MYSQL_DS)
+        .await
+        .expect("Failed the delete operation");
+    assert_eq!(
+        Broadcast::find_by_pk_datasource(&new_broadcast.id, MYSQL_DS)
+            .await
+            .expect("Request error"),
+        None
+    );
+}
+
+/// The primary keys that already holds a UUID aren't replaced by the generated ones,
+/// neither when multiple instances are inserted at once
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_multi_insert_uuid_operation() {
+    let given_key = Uuid::new_v4();
+    let mut with_key = Broadcast {
+        id: given_key,
+        league: 1,
+        channel: "lec-official".to_string(),
+        stream_key: Some(Uuid::new_v4()),
+    };
+    let mut without_key = Broadcast {
+        id: Uuid::nil(),
+        league: 1,
+        channel: "lec-official".to_string(),
+        stream_key: Some(Uuid::new_v4()),
+    };
+
+    Broadcast::multi_insert(&mut [&mut with_key, &mut without_key])
+        .await
+        .expect("Failed multi insert operation");
+    assert_eq!(with_key.id, given_key);
+    assert_eq!(without_key.id.get_version_num(), 7);
+
+    for broadcast in [with_key, without_key] {
+        let inserted = Broadcast::find_by_pk(&broadcast.id)
+            .await
+            .expect("Request error")
+            .expect("No entity found for the primary key value passed in");
+        assert_eq!(inserted, broadcast);
+        broadcast
+            .delete()
+            .await
+            .expect("Failed the delete operation");
+    }
+}
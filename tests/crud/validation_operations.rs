@@ -0,0 +1,98 @@
+//! Integration tests for the validations declared with the `#[validate(...)]`
+//! annotation over the fields of an entity
+use canyon_sql::crud::{validation::ValidationError, CrudOperations};
+
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+
+use crate::tests_models::registration::*;
+
+/// Retrieves the [`ValidationError`] of a failed operation
+#[cfg(feature = "postgres")]
+fn validation_error(err: Box<dyn std::error::Error + Sync + Send>) -> ValidationError {
+    err.downcast_ref::<ValidationError>()
+        .expect("Not a validation error")
+        .clone()
+}
+
+/// The insert of an entity that doesn't pass its validations is refused before
+/// reaching the database, listing every failed field, and every failed rule of them.
+/// The validations runs before any statement is sent, so they behave the same on
+/// every database
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_insert_validation_operation() {
+    let mut invalid_registration = Registration {
+        id: Default::default(),
+        tournament: 1,
+        team_slug: "some-validated-team".to_string(),
+        seed: 1,
+    };
+    invalid_registration.seed = 0;
+    invalid_registration.team_slug = "some validated team!".to_string();
+
+    let err = validation_error(
+        invalid_registration
+            .insert()
+            .await
+            .expect_err("The insert of an invalid entity must fail"),
+    );
+    assert_eq!(err.errors.len(), 2);
+    assert_eq!(err.field_errors("seed").count(), 1);
+    assert_eq!(err.field_errors("team_slug").count(), 1);
+    // The primary key is only assigned by the database after a successful insert
+    assert_eq!(invalid_registration.id, 0);
+
+    let mut without_slug = Registration {
+        id: Default::default(),
+        tournament: 1,
+        team_slug: "some-validated-team".to_string(),
+        seed: 1,
+    };
+    without_slug.team_slug = String::new();
+    let err = validation_error(
+        without_slug
+            .insert_datasource(PSQL_DS)
+            .await
+            .expect_err("The insert of an invalid entity must fail"),
+    );
+    // An empty team slug is too short, and doesn't match the pattern either
+    assert_eq!(err.field_errors("team_slug").count(), 2);
+    assert_eq!(without_slug.id, 0);
+}
+
+/// The update of an entity that doesn't pass its validations leaves the record untouched
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_update_validation_operation() {
+    let mut new_registration = Registration {
+        id: Default::default(),
+        tournament: 1,
+        team_slug: "some-validated-team".to_string(),
+        seed: 1,
+    };
+    new_registration
+        .insert()
+        .await
+        .expect("Failed insert operation");
+
+    let mut invalid_registration = new_registration.clone();
+    invalid_registration.team_slug = "a".repeat(65);
+    let err = validation_error(
+        invalid_registration
+            .update()
+            .await
+            .expect_err("The update of an invalid entity must fail"),
+    );
+    assert_eq!(
+        err.field_errors("team_slug").collect::<Vec<_>>(),
+        vec!["must have a length of at most 64"]
+    );
+
+    assert_eq!(
+        Registration::find_by_pk(&new_registration.id)
+            .await
+            .expect("Request error"),
+        Some(new_registration)
+    );
+}
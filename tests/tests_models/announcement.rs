@@ -0,0 +1,12 @@
+use canyon_sql::macros::*;
+
+/// Data model that represents the announcements of the leagues, which rows are
+/// just marked as deleted when they're withdrawn
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity(soft_delete = "deleted_at")]
+pub struct Announcement {
+    #[primary_key]
+    pub id: i32,
+    pub league: i32,
+    pub title: String,
+}
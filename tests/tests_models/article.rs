@@ -0,0 +1,16 @@
+use canyon_sql::{date_time::NaiveDateTime, macros::*};
+
+/// Data model that represents the news articles of the leagues, which moments
+/// of creation and last modification are managed by Canyon
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Article {
+    #[primary_key]
+    pub id: i32,
+    pub league: i32,
+    pub title: String,
+    #[created_at]
+    pub created_at: NaiveDateTime,
+    #[updated_at]
+    pub updated_at: NaiveDateTime,
+}
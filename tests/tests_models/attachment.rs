@@ -0,0 +1,14 @@
+use canyon_sql::macros::*;
+
+/// Data model that represents the files attached to the leagues, which content
+/// is stored in binary columns
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Attachment {
+    #[primary_key]
+    pub id: i32,
+    pub league: i32,
+    pub file_name: String,
+    pub content: Vec<u8>,
+    pub thumbnail: Option<Vec<u8>>,
+}
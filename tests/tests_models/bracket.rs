@@ -0,0 +1,14 @@
+use canyon_sql::macros::*;
+
+/// Data model that represents the stages of the tournaments, which concurrent
+/// modifications are detected through the version of the records
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Bracket {
+    #[primary_key]
+    pub id: i32,
+    pub tournament: i32,
+    pub stage: String,
+    #[version]
+    pub version: i32,
+}
@@ -0,0 +1,13 @@
+use canyon_sql::{macros::*, uuid::Uuid};
+
+/// Data model that represents the broadcasts of the leagues, which primary key
+/// is a UUID generated by Canyon when the record is inserted
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Broadcast {
+    #[primary_key(generate = "uuid_v7")]
+    pub id: Uuid,
+    pub league: i32,
+    pub channel: String,
+    pub stream_key: Option<Uuid>,
+}
@@ -0,0 +1,39 @@
+use canyon_sql::{crud::CanyonHooks, macros::*};
+
+/// Data model that represents the casters of the leagues, which lifecycle
+/// is observed by the hooks implemented for it
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity(hooks)]
+pub struct Caster {
+    #[primary_key]
+    pub id: i32,
+    pub league: i32,
+    pub nickname: String,
+}
+
+/// Normalizes the nickname of the new casters, and refuses to modify
+/// or delete the records of the casters without nickname
+#[async_trait]
+impl CanyonHooks for Caster {
+    async fn before_insert(&mut self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        self.nickname = self.nickname.trim().to_lowercase();
+        Ok(())
+    }
+
+    async fn before_update(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        self.check_nickname()
+    }
+
+    async fn before_delete(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        self.check_nickname()
+    }
+}
+
+impl Caster {
+    fn check_nickname(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        if self.nickname.is_empty() {
+            return Err("A caster must have a nickname".into());
+        }
+        Ok(())
+    }
+}
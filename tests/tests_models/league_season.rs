@@ -0,0 +1,15 @@
+use crate::tests_models::league::League;
+use canyon_sql::macros::*;
+
+/// Data model that represents a season of a league, identified by the
+/// composite primary key made by the league and the year of the season
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, ForeignKeyable, Eq, PartialEq)]
+#[canyon_entity]
+pub struct LeagueSeason {
+    #[primary_key]
+    #[foreign_key(table = "league", column = "id")]
+    pub league: i32,
+    #[primary_key]
+    pub season: i32,
+    pub title: String,
+}
@@ -0,0 +1,25 @@
+use canyon_sql::{
+    json::{Json, Value},
+    macros::*,
+};
+use serde::{Deserialize, Serialize};
+
+/// The typed settings of a league, stored as a JSON document
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Preferences {
+    pub theme: String,
+    pub max_teams: i32,
+    pub languages: Vec<String>,
+}
+
+/// Data model that represents the flexible settings of the leagues, with
+/// a typed document and a free one
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct LeagueSettings {
+    #[primary_key]
+    pub id: i32,
+    pub league: i32,
+    pub preferences: Json<Preferences>,
+    pub extra: Option<Value>,
+}
@@ -1,3 +1,17 @@
+pub mod announcement;
+pub mod article;
+pub mod attachment;
+pub mod bracket;
+pub mod broadcast;
+pub mod caster;
 pub mod league;
+pub mod league_season;
+pub mod league_settings;
 pub mod player;
+pub mod player_stats;
+pub mod registration;
+pub mod roster;
+pub mod season_standing;
+pub mod sponsor;
+pub mod ticket;
 pub mod tournament;
@@ -1,5 +1,17 @@
 use canyon_sql::macros::*;
 
+/// The position of a player in the game, stored as text with the lowercase name of
+/// the variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CanyonEnum)]
+#[canyon(rename_all = "lowercase")]
+pub enum Role {
+    Top,
+    Jungle,
+    Mid,
+    Bottom,
+    Support,
+}
+
 #[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
 #[canyon_entity]
 /// Data model that represents a database entity for Players.
@@ -20,5 +32,5 @@ pub struct Player {
     last_name: String,
     summoner_name: String,
     image_url: Option<String>,
-    role: String,
+    role: Role,
 }
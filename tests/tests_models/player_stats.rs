@@ -0,0 +1,16 @@
+use canyon_sql::macros::*;
+
+/// Data model that represents the statistics of the players, with the integer
+/// types that are widened or stored as unsigned columns by the databases
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct PlayerStats {
+    #[primary_key]
+    pub id: u32,
+    pub level: u8,
+    pub rank_delta: i8,
+    pub wins: u16,
+    pub kills: u32,
+    pub views: u64,
+    pub streak: Option<u16>,
+}
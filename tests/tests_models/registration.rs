@@ -0,0 +1,15 @@
+use canyon_sql::macros::*;
+
+/// Data model that represents the teams registered on the tournaments,
+/// which values are validated before reaching the database
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Registration {
+    #[primary_key]
+    pub id: i32,
+    pub tournament: i32,
+    #[validate(length(min = 1, max = 64), regex = "^[a-z0-9_-]+$")]
+    pub team_slug: String,
+    #[validate(range(min = 1))]
+    pub seed: i32,
+}
@@ -0,0 +1,14 @@
+use canyon_sql::macros::*;
+
+/// Data model that represents the roster of a team, with the players and their
+/// jersey numbers stored as arrays
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Roster {
+    #[primary_key]
+    pub id: i32,
+    pub team: String,
+    pub players: Vec<String>,
+    pub jersey_numbers: Vec<i32>,
+    pub substitutes: Option<Vec<String>>,
+}
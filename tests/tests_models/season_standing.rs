@@ -0,0 +1,27 @@
+use crate::tests_models::league_season::LeagueSeason;
+use canyon_sql::macros::*;
+
+/// Data model that represents the points of a team on a season of a league,
+/// which references its [`LeagueSeason`] with a composite foreign key, made of
+/// the fields that shares the `constraint` name
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct SeasonStanding {
+    #[primary_key]
+    #[foreign_key(
+        table = "league_season",
+        column = "league",
+        constraint = "season_standing_season_fkey"
+    )]
+    pub league: i32,
+    #[primary_key]
+    #[foreign_key(
+        table = "league_season",
+        column = "season",
+        constraint = "season_standing_season_fkey"
+    )]
+    pub season: i32,
+    #[primary_key]
+    pub team: String,
+    pub points: i32,
+}
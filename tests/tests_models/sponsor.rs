@@ -0,0 +1,21 @@
+use canyon_sql::{decimal::Decimal, macros::*};
+
+/// Data model that represents the sponsorship contracts of the leagues,
+/// which amounts are stored as exact numeric values
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Sponsor {
+    #[primary_key]
+    pub id: i32,
+    pub league: i32,
+    pub name: String,
+    #[column(precision = 19, scale = 4)]
+    pub amount: Decimal,
+    #[column(precision = 19, scale = 4)]
+    pub bonus: Option<Decimal>,
+}
+
+/// Parses the exact representation of a decimal number
+pub fn decimal(value: &str) -> Decimal {
+    value.parse().expect("Not a valid decimal number")
+}
@@ -0,0 +1,97 @@
+use canyon_sql::{crud::types::CanyonType, decimal::Decimal, macros::*};
+use std::error::Error;
+
+/// The tier of a ticket, stored as a native enumerated type on PostgreSQL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CanyonEnum)]
+#[canyon(storage = "native", rename_all = "snake_case")]
+pub enum TicketTier {
+    General,
+    Premium,
+    BackStage,
+}
+
+/// The status of a ticket, stored as its discriminant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CanyonEnum)]
+#[canyon(storage = "integer")]
+pub enum TicketStatus {
+    Reserved = 1,
+    Paid = 2,
+    Refunded = 10,
+}
+
+/// The email of the holder of a ticket, stored as text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Email(String);
+
+impl Email {
+    pub fn new(email: &str) -> Self {
+        Self(email.to_lowercase())
+    }
+}
+
+#[canyon_type]
+impl CanyonType for Email {
+    type Repr = String;
+    const SQLSERVER_TYPE: Option<&'static str> = Some("NVARCHAR(320)");
+    const MYSQL_TYPE: Option<&'static str> = Some("VARCHAR(320)");
+
+    fn as_repr(&self) -> &Self::Repr {
+        &self.0
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        if repr.contains('@') {
+            Ok(Self(repr))
+        } else {
+            Err(format!("`{repr}` isn't a valid email").into())
+        }
+    }
+}
+
+/// An amount of money, stored with a fixed scale of four decimal digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Money(pub Decimal);
+
+#[canyon_type]
+impl CanyonType for Money {
+    type Repr = Decimal;
+    const POSTGRES_TYPE: Option<&'static str> = Some("NUMERIC(19,4)");
+    const SQLSERVER_TYPE: Option<&'static str> = Some("DECIMAL(19,4)");
+    const MYSQL_TYPE: Option<&'static str> = Some("DECIMAL(19,4)");
+
+    fn as_repr(&self) -> &Self::Repr {
+        &self.0
+    }
+
+    fn from_repr(repr: Self::Repr) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self(repr.normalize()))
+    }
+}
+
+/// Data model that represents the tickets sold for the tournaments, which fields
+/// are enums stored with every one of the available strategies, and custom types
+#[derive(Debug, Clone, Fields, CanyonCrud, CanyonMapper, Eq, PartialEq)]
+#[canyon_entity]
+pub struct Ticket {
+    #[primary_key]
+    pub id: i32,
+    pub tournament: i32,
+    pub tier: TicketTier,
+    pub status: TicketStatus,
+    pub previous_status: Option<TicketStatus>,
+    pub holder_email: Email,
+    pub price: Option<Money>,
+}
+
+/// A paid back stage ticket of the first tournament, without price
+pub fn new_ticket() -> Ticket {
+    Ticket {
+        id: Default::default(),
+        tournament: 1,
+        tier: TicketTier::BackStage,
+        status: TicketStatus::Paid,
+        previous_status: None,
+        holder_email: Email::new("Faker@T1.gg"),
+        price: None,
+    }
+}